@@ -0,0 +1,109 @@
+//! Random values for property-based testing.
+//!
+//! With the `proptest` feature, [`BigInt`] and [`GaussInt`] implement
+//! `proptest::arbitrary::Arbitrary`. The parameter, [`MaxDigits`], bounds
+//! the number of decimal digits of each integer; failing cases shrink toward
+//! fewer digits and toward zero.
+//!
+//! ```
+//! use gauss_int::arbitrary::MaxDigits;
+//! use gauss_int::GaussInt;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn norm_is_multiplicative(
+//!         a in any::<GaussInt>(),
+//!         b in any_with::<GaussInt>(MaxDigits(5)),
+//!     ) {
+//!         prop_assert_eq!((&a * &b).norm(), &a.norm() * &b.norm());
+//!     }
+//! }
+//! norm_is_multiplicative();
+//! ```
+
+use crate::{BigInt, GaussInt};
+use proptest::arbitrary::Arbitrary;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+
+/// Maximum number of decimal digits per generated integer; 40 by default,
+/// as used by `any::<BigInt>()` and `any::<GaussInt>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxDigits(pub usize);
+
+impl Default for MaxDigits {
+    fn default() -> Self {
+        MaxDigits(40)
+    }
+}
+
+/// Integers with at most `max_digits` decimal digits, of either sign.
+pub fn big_int_strategy(max_digits: usize) -> impl Strategy<Value = BigInt> + Clone {
+    (any::<bool>(), vec(0u8..10, 0..=max_digits)).prop_map(|(negative, digits)| {
+        if digits.is_empty() {
+            return BigInt::ZERO;
+        }
+        let text: String = digits.iter().map(|&d| char::from(b'0' + d)).collect();
+        let n = BigInt::from_string(&text).expect("decimal digits");
+        if negative {
+            -n
+        } else {
+            n
+        }
+    })
+}
+
+/// Gaussian integers whose components have at most `max_digits` digits.
+pub fn gauss_int_strategy(max_digits: usize) -> impl Strategy<Value = GaussInt> + Clone {
+    (big_int_strategy(max_digits), big_int_strategy(max_digits))
+        .prop_map(|(real, imag)| GaussInt::new(real, imag))
+}
+
+impl Arbitrary for BigInt {
+    type Parameters = MaxDigits;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(MaxDigits(max_digits): MaxDigits) -> Self::Strategy {
+        big_int_strategy(max_digits).boxed()
+    }
+}
+
+impl Arbitrary for GaussInt {
+    type Parameters = MaxDigits;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(MaxDigits(max_digits): MaxDigits) -> Self::Strategy {
+        gauss_int_strategy(max_digits).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_digit_limit(
+            n in any_with::<BigInt>(MaxDigits(7)),
+            z in any_with::<GaussInt>(MaxDigits(3)),
+        ) {
+            prop_assert!(n.abs().to_string().len() <= 7);
+            prop_assert!(z.real().abs() < BigInt::new(1000));
+            prop_assert!(z.imag().abs() < BigInt::new(1000));
+        }
+    }
+
+    #[test]
+    fn test_default_parameters() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        let mut runner = TestRunner::deterministic();
+        let strategy = any::<BigInt>();
+        let nonzero = (0..20)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .any(|n| n != BigInt::ZERO);
+        assert!(nonzero);
+    }
+}
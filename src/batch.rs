@@ -0,0 +1,236 @@
+//! Reductions over slices of Gaussian integers.
+//!
+//! These accumulate into a single running value with the in-place operators
+//! instead of building a fresh `GaussInt` per element, which matters for
+//! long slices.
+//!
+//! With the `rayon` feature, the `par_*` functions spread batch work over
+//! the rayon thread pool.
+
+use crate::{BigInt, GaussInt};
+use num_traits::{One, Zero};
+
+/// Returns the sum of all elements; zero for an empty slice.
+pub fn sum_slice(xs: &[GaussInt]) -> GaussInt {
+    let mut acc = GaussInt::zero();
+    for x in xs {
+        acc += x;
+    }
+    acc
+}
+
+/// Returns the product of all elements; one for an empty slice.
+pub fn product_slice(xs: &[GaussInt]) -> GaussInt {
+    let mut acc = GaussInt::one();
+    for x in xs {
+        acc *= x;
+    }
+    acc
+}
+
+/// Returns `Σ xs[k]·ys[k]`, without conjugation.
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::batch::dot_product;
+///
+/// let xs = [GaussInt::from_i64(1, 2), GaussInt::from_i64(0, 1)];
+/// let ys = [GaussInt::from_i64(3, 0), GaussInt::from_i64(0, 1)];
+/// // (3 + 6i) + (-1)
+/// assert_eq!(dot_product(&xs, &ys), GaussInt::from_i64(2, 6));
+/// ```
+pub fn dot_product(xs: &[GaussInt], ys: &[GaussInt]) -> GaussInt {
+    assert_eq!(xs.len(), ys.len(), "dimension mismatch");
+    let mut real = BigInt::zero();
+    let mut imag = BigInt::zero();
+    for (x, y) in xs.iter().zip(ys) {
+        real += &(x.real() * y.real());
+        real -= &(x.imag() * y.imag());
+        imag += &(x.real() * y.imag());
+        imag += &(x.imag() * y.real());
+    }
+    GaussInt::new(real, imag)
+}
+
+/// Evaluates `Σ coeffs[k]·x^k` by Horner's rule, multiplying and adding
+/// into one accumulator. Coefficients are lowest degree first, as in
+/// [`Polynomial`](crate::polynomial::Polynomial).
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::batch::evaluate_polynomial;
+///
+/// // 1 + x² vanishes at i
+/// let coeffs = [GaussInt::from_i64(1, 0), GaussInt::from_i64(0, 0), GaussInt::from_i64(1, 0)];
+/// assert_eq!(evaluate_polynomial(&coeffs, &GaussInt::from_i64(0, 1)), GaussInt::from_i64(0, 0));
+/// ```
+pub fn evaluate_polynomial(coeffs: &[GaussInt], x: &GaussInt) -> GaussInt {
+    let mut acc = GaussInt::zero();
+    for c in coeffs.iter().rev() {
+        acc *= x;
+        acc += c;
+    }
+    acc
+}
+
+/// The `BigInt` version of [`evaluate_polynomial`].
+pub fn evaluate_polynomial_big_int(coeffs: &[BigInt], x: &BigInt) -> BigInt {
+    let mut acc = BigInt::zero();
+    for c in coeffs.iter().rev() {
+        acc *= x;
+        acc += c;
+    }
+    acc
+}
+
+/// Returns the product of all elements, multiplying in a balanced tree
+/// across threads; one for an empty slice.
+#[cfg(feature = "rayon")]
+pub fn par_product(xs: &[BigInt]) -> BigInt {
+    use num_traits::One;
+    use rayon::prelude::*;
+    xs.par_iter().cloned().reduce(BigInt::one, |a, b| a * b)
+}
+
+/// Returns `[xs[k] + ys[k]]`, computed in parallel.
+///
+/// Panics if the slices have different lengths.
+#[cfg(feature = "rayon")]
+pub fn par_add(xs: &[GaussInt], ys: &[GaussInt]) -> Vec<GaussInt> {
+    par_zip_with(xs, ys, |x, y| x + y)
+}
+
+/// Returns `[xs[k] - ys[k]]`, computed in parallel.
+///
+/// Panics if the slices have different lengths.
+#[cfg(feature = "rayon")]
+pub fn par_sub(xs: &[GaussInt], ys: &[GaussInt]) -> Vec<GaussInt> {
+    par_zip_with(xs, ys, |x, y| x - y)
+}
+
+/// Returns `[xs[k]·ys[k]]`, computed in parallel.
+///
+/// Panics if the slices have different lengths.
+#[cfg(feature = "rayon")]
+pub fn par_mul(xs: &[GaussInt], ys: &[GaussInt]) -> Vec<GaussInt> {
+    par_zip_with(xs, ys, |x, y| x * y)
+}
+
+/// Returns `[b^exp for b in bases]`, computed in parallel.
+#[cfg(feature = "rayon")]
+pub fn par_pow(bases: &[GaussInt], exp: u32) -> Vec<GaussInt> {
+    use rayon::prelude::*;
+    bases.par_iter().map(|b| b.pow_u32(exp)).collect()
+}
+
+#[cfg(feature = "rayon")]
+fn par_zip_with(
+    xs: &[GaussInt],
+    ys: &[GaussInt],
+    op: fn(&GaussInt, &GaussInt) -> GaussInt,
+) -> Vec<GaussInt> {
+    use rayon::prelude::*;
+    assert_eq!(xs.len(), ys.len(), "dimension mismatch");
+    xs.par_iter().zip(ys).map(|(x, y)| op(x, y)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<GaussInt> {
+        (1..=12)
+            .map(|k| GaussInt::from_i64(k * 7 % 11 - 5, k * 5 % 13 - 6))
+            .collect()
+    }
+
+    #[test]
+    fn test_sum_and_product_slice() {
+        let xs = sample();
+        let sum = xs.iter().fold(GaussInt::zero(), |acc, x| &acc + x);
+        let product = xs.iter().fold(GaussInt::one(), |acc, x| &acc * x);
+        assert_eq!(sum_slice(&xs), sum);
+        assert_eq!(product_slice(&xs), product);
+        assert_eq!(sum_slice(&[]), GaussInt::zero());
+        assert_eq!(product_slice(&[]), GaussInt::one());
+    }
+
+    #[test]
+    fn test_dot_product() {
+        let xs = sample();
+        let ys: Vec<GaussInt> = xs.iter().map(GaussInt::conjugate).collect();
+        let expected = xs
+            .iter()
+            .zip(&ys)
+            .fold(GaussInt::zero(), |acc, (x, y)| &acc + &(x * y));
+        assert_eq!(dot_product(&xs, &ys), expected);
+        // x·conj(x) sums the norms
+        let norms = xs.iter().fold(BigInt::zero(), |acc, x| &acc + &x.norm());
+        assert_eq!(dot_product(&xs, &ys), GaussInt::new(norms, BigInt::zero()));
+        assert_eq!(dot_product(&[], &[]), GaussInt::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn test_dot_product_length_mismatch() {
+        dot_product(&sample(), &sample()[1..]);
+    }
+
+    #[test]
+    fn test_evaluate_polynomial() {
+        let coeffs = sample();
+        let x = GaussInt::from_i64(2, -1);
+        let expected = coeffs
+            .iter()
+            .enumerate()
+            .fold(GaussInt::zero(), |acc, (k, c)| {
+                &acc + &(c * &x.pow_u32(k as u32))
+            });
+        assert_eq!(evaluate_polynomial(&coeffs, &x), expected);
+        assert_eq!(evaluate_polynomial(&[], &x), GaussInt::zero());
+        assert_eq!(evaluate_polynomial(&coeffs, &GaussInt::zero()), coeffs[0]);
+
+        let ints: Vec<BigInt> = [5, 0, -3, 2].iter().map(|&c| BigInt::new(c)).collect();
+        // 5 - 3·9 + 2·27 = 32
+        assert_eq!(
+            evaluate_polynomial_big_int(&ints, &BigInt::new(3)),
+            BigInt::new(32)
+        );
+        assert_eq!(
+            evaluate_polynomial_big_int(&[], &BigInt::new(3)),
+            BigInt::zero()
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_batches_match_sequential() {
+        let xs = sample();
+        let ys: Vec<GaussInt> = xs.iter().map(|x| x.mul_i()).collect();
+        let zip = |op: fn(&GaussInt, &GaussInt) -> GaussInt| -> Vec<GaussInt> {
+            xs.iter().zip(&ys).map(|(x, y)| op(x, y)).collect()
+        };
+        assert_eq!(par_add(&xs, &ys), zip(|x, y| x + y));
+        assert_eq!(par_sub(&xs, &ys), zip(|x, y| x - y));
+        assert_eq!(par_mul(&xs, &ys), zip(|x, y| x * y));
+        let cubes: Vec<GaussInt> = xs.iter().map(|x| x.pow_u32(3)).collect();
+        assert_eq!(par_pow(&xs, 3), cubes);
+
+        let ints: Vec<BigInt> = (1..=40).map(BigInt::new).collect();
+        assert_eq!(par_product(&ints), BigInt::new(40).factorial().unwrap());
+        assert_eq!(par_product(&[]), BigInt::one());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn test_parallel_length_mismatch() {
+        par_mul(&sample(), &sample()[2..]);
+    }
+}
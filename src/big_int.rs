@@ -1,9 +1,15 @@
-use num_bigint::{BigInt as NumBigInt, Sign};
+use crate::error::{Error, ParseBigIntError, ParseGaussIntError};
+use num_bigint::{BigInt as NumBigInt, BigUint, Sign};
 use num_integer::Integer;
-use num_traits::{One, Signed, Zero};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt;
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::io;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitOr, BitXor, Div, Mul, MulAssign, Neg, Not, Rem, Shl, Shr, Sub,
+    SubAssign,
+};
 
 /// A wrapper around `num_bigint::BigInt` providing additional mathematical operations.
 ///
@@ -11,6 +17,10 @@ use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 /// including basic arithmetic, modular arithmetic, prime number operations,
 /// and binary manipulations.
 ///
+/// Values that fit in an `i64` are stored inline, and arithmetic between
+/// them runs on machine integers; only results outside that range are
+/// promoted to a heap-allocated `num_bigint::BigInt`.
+///
 /// # Examples
 ///
 /// ```
@@ -22,12 +32,29 @@ use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BigInt {
-    inner: NumBigInt,
+    repr: Repr,
+}
+
+/// Storage for [`BigInt`].
+///
+/// Invariant: `Large` only holds values outside the `i64` range, so every
+/// value has exactly one representation and the derived equality is exact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Repr {
+    Small(i64),
+    Large(NumBigInt),
 }
 
 impl BigInt {
+    pub const ZERO: BigInt = BigInt::new(0);
+    pub const ONE: BigInt = BigInt::new(1);
+    pub const TWO: BigInt = BigInt::new(2);
+
     /// Creates a new `BigInt` from an `i64` value.
     ///
+    /// This is a `const fn`, so values that fit in an `i64` can be used in
+    /// constants.
+    ///
     /// # Examples
     ///
     /// ```
@@ -35,16 +62,22 @@ impl BigInt {
     ///
     /// let n = BigInt::new(42);
     /// assert_eq!(n.to_string(), "42");
+    ///
+    /// const LIMIT: BigInt = BigInt::new(1 << 40);
+    /// assert!(n < LIMIT);
+    /// assert_eq!(&BigInt::TWO * &BigInt::ONE, BigInt::new(2));
     /// ```
-    pub fn new(value: i64) -> Self {
+    pub const fn new(value: i64) -> Self {
         BigInt {
-            inner: NumBigInt::from(value),
+            repr: Repr::Small(value),
         }
     }
 
-    /// Parses a `BigInt` from a decimal string.
+    /// Parses a `BigInt` from a decimal string, or from hexadecimal, octal
+    /// or binary after a `0x`, `0o` or `0b` prefix.
     ///
-    /// Returns `None` if the string is not a valid decimal number.
+    /// Returns `None` if the string is not a valid number; see
+    /// [`try_from_string`](Self::try_from_string) for the exact syntax.
     ///
     /// # Examples
     ///
@@ -58,7 +91,39 @@ impl BigInt {
     /// assert!(invalid.is_none());
     /// ```
     pub fn from_string(s: &str) -> Option<Self> {
-        NumBigInt::parse_bytes(s.as_bytes(), 10).map(|n| BigInt { inner: n })
+        BigInt::try_from_string(s).ok()
+    }
+
+    /// Like [`from_string`](Self::from_string), but reports where the
+    /// input went wrong.
+    ///
+    /// The input is an optional `+` or `-`, an optional radix prefix `0x`,
+    /// `0o` or `0b` in lowercase as in Rust, and digits, with no
+    /// surrounding whitespace. Hexadecimal digits may be of either case.
+    /// Single underscores may separate digits: `1_000_000_007` is accepted,
+    /// while `_1`, `1_`, `1__0` and `0x_ff` are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let err = BigInt::try_from_string("-12x4").unwrap_err();
+    /// assert_eq!((err.offset(), err.found()), (3, Some('x')));
+    /// assert_eq!(err.to_string(), "invalid integer: unexpected 'x' at byte 3");
+    ///
+    /// let p = BigInt::try_from_string("-0xFFFF_FFFF_FFFF_FFC5").unwrap();
+    /// assert_eq!(p, -(BigInt::TWO.pow(64) - BigInt::new(59)));
+    /// ```
+    pub fn try_from_string(s: &str) -> Result<Self, ParseBigIntError> {
+        let mut scanner = Scanner::new(s);
+        let n = scanner
+            .signed_integer()
+            .ok_or_else(|| ParseBigIntError::at(s, scanner.pos()))?;
+        if !scanner.at_end() {
+            return Err(ParseBigIntError::at(s, scanner.pos()));
+        }
+        Ok(n)
     }
 
     /// Creates a `BigInt` from a big-endian byte representation.
@@ -73,28 +138,106 @@ impl BigInt {
     /// let n = BigInt::from_bytes_be(Sign::Plus, &bytes);
     /// ```
     pub fn from_bytes_be(sign: Sign, bytes: &[u8]) -> Self {
-        BigInt {
-            inner: NumBigInt::from_bytes_be(sign, bytes),
-        }
+        BigInt::from(NumBigInt::from_bytes_be(sign, bytes))
     }
 
     /// Returns the big-endian byte representation of this `BigInt`.
     ///
     /// Returns a tuple of the sign and the byte vector.
     pub fn to_bytes_be(&self) -> (Sign, Vec<u8>) {
-        self.inner.to_bytes_be()
+        self.big().to_bytes_be()
+    }
+
+    /// Returns the sign and the magnitude as little-endian 64-bit limbs,
+    /// without trailing zero limbs; zero has no limbs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    /// use num_bigint::Sign;
+    ///
+    /// let n = -BigInt::TWO.pow(64);
+    /// assert_eq!(n.to_u64_digits(), (Sign::Minus, vec![0, 1]));
+    /// assert_eq!(BigInt::from_u64_digits(Sign::Minus, &[0, 1]), n);
+    /// ```
+    pub fn to_u64_digits(&self) -> (Sign, Vec<u64>) {
+        if self.is_zero() {
+            return (Sign::NoSign, Vec::new());
+        }
+        (self.sign(), self.limbs_u64().collect())
+    }
+
+    /// Creates a `BigInt` from a sign and little-endian 64-bit limbs, the
+    /// inverse of [`to_u64_digits`](Self::to_u64_digits). Trailing zero
+    /// limbs are allowed. The result is zero if `sign` is
+    /// [`Sign::NoSign`] or all limbs are zero.
+    pub fn from_u64_digits(sign: Sign, digits: &[u64]) -> Self {
+        let halves = digits
+            .iter()
+            .flat_map(|&d| [d as u32, (d >> 32) as u32])
+            .collect();
+        BigInt::from(NumBigInt::from_biguint(sign, BigUint::new(halves)))
+    }
+
+    /// Returns the minimal big-endian two's-complement representation, so
+    /// the sign travels in the bytes themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(-2).to_signed_bytes_be(), [0xfe]);
+    /// assert_eq!(BigInt::new(128).to_signed_bytes_be(), [0x00, 0x80]);
+    /// ```
+    pub fn to_signed_bytes_be(&self) -> Vec<u8> {
+        self.big().to_signed_bytes_be()
+    }
+
+    /// Little-endian counterpart of [`to_signed_bytes_be`](Self::to_signed_bytes_be).
+    pub fn to_signed_bytes_le(&self) -> Vec<u8> {
+        self.big().to_signed_bytes_le()
+    }
+
+    /// Creates a `BigInt` from big-endian two's-complement bytes; the empty
+    /// slice is zero. Sign-extended input such as `[0xff, 0xfe]` is accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let n = BigInt::from_string("-12345678901234567890").unwrap();
+    /// assert_eq!(BigInt::from_signed_bytes_be(&n.to_signed_bytes_be()), n);
+    /// ```
+    pub fn from_signed_bytes_be(bytes: &[u8]) -> Self {
+        BigInt::from(NumBigInt::from_signed_bytes_be(bytes))
+    }
+
+    /// Little-endian counterpart of [`from_signed_bytes_be`](Self::from_signed_bytes_be).
+    pub fn from_signed_bytes_le(bytes: &[u8]) -> Self {
+        BigInt::from(NumBigInt::from_signed_bytes_le(bytes))
     }
 
     /// Returns the absolute value of this `BigInt`.
     pub fn abs(&self) -> Self {
-        BigInt {
-            inner: self.inner.abs(),
+        match &self.repr {
+            Repr::Small(v) => BigInt::from_i128(i128::from(*v).abs()),
+            Repr::Large(n) => BigInt::from(n.abs()),
         }
     }
 
     /// Returns the sign of this `BigInt`.
     pub fn sign(&self) -> Sign {
-        self.inner.sign()
+        match &self.repr {
+            Repr::Small(v) => match v.cmp(&0) {
+                Ordering::Less => Sign::Minus,
+                Ordering::Equal => Sign::NoSign,
+                Ordering::Greater => Sign::Plus,
+            },
+            Repr::Large(n) => n.sign(),
+        }
     }
 
     /// Returns the number of bits required to represent the absolute value of this `BigInt`.
@@ -109,182 +252,1996 @@ impl BigInt {
     /// assert_eq!(BigInt::new(8).bits(), 4);
     /// ```
     pub fn bits(&self) -> u64 {
-        self.inner.bits()
+        match &self.repr {
+            Repr::Small(v) => u64::from(64 - v.unsigned_abs().leading_zeros()),
+            Repr::Large(n) => n.bits(),
+        }
     }
 
-    /// Returns `true` if this `BigInt` is zero.
-    pub fn is_zero(&self) -> bool {
-        self.inner.is_zero()
+    /// Returns `floor(log2(self))`, or `None` if this number is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(1).ilog2(), Some(0));
+    /// assert_eq!(BigInt::new(1023).ilog2(), Some(9));
+    /// assert_eq!(BigInt::new(1024).ilog2(), Some(10));
+    /// assert_eq!(BigInt::new(0).ilog2(), None);
+    /// ```
+    pub fn ilog2(&self) -> Option<u64> {
+        if !self.is_positive() {
+            return None;
+        }
+        Some(self.bits() - 1)
     }
 
-    /// Returns `true` if this `BigInt` is positive.
-    pub fn is_positive(&self) -> bool {
-        self.inner.is_positive()
+    /// Returns `floor(log10(self))`, or `None` if this number is not positive.
+    ///
+    /// `ilog10() + 1` is the number of decimal digits, computed without
+    /// converting to a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(999).ilog10(), Some(2));
+    /// assert_eq!(BigInt::new(1000).ilog10(), Some(3));
+    /// ```
+    pub fn ilog10(&self) -> Option<u64> {
+        self.ilog(&BigInt::new(10))
     }
 
-    /// Returns `true` if this `BigInt` is negative.
-    pub fn is_negative(&self) -> bool {
-        self.inner.is_negative()
+    /// Returns `floor(log_base(self))`.
+    ///
+    /// Returns `None` if this number is not positive or `base < 2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(80).ilog(&BigInt::new(3)), Some(3));
+    /// assert_eq!(BigInt::new(81).ilog(&BigInt::new(3)), Some(4));
+    /// assert_eq!(BigInt::new(81).ilog(&BigInt::new(1)), None);
+    /// ```
+    pub fn ilog(&self, base: &Self) -> Option<u64> {
+        if !self.is_positive() || base < &BigInt::new(2) {
+            return None;
+        }
+        if self < base {
+            return Some(0);
+        }
+
+        // Estimate from the leading bits, then correct by at most a few steps.
+        let estimate = (self.log2_approx() / base.log2_approx()).floor() as u64;
+        let mut k = estimate.saturating_sub(1);
+        let mut power = base.pow(u32::try_from(k).ok()?);
+        while &power > self {
+            power = &power / base;
+            k -= 1;
+        }
+        loop {
+            let next = &power * base;
+            if &next > self {
+                return Some(k);
+            }
+            power = next;
+            k += 1;
+        }
     }
 
-    /// Raises this `BigInt` to the power of `exp`.
+    /// Returns the number of digits of `|self|` in the given radix.
+    ///
+    /// Zero has one digit. Panics if `radix < 2`.
     ///
     /// # Examples
     ///
     /// ```
     /// use gauss_int::BigInt;
     ///
-    /// let n = BigInt::new(3);
-    /// assert_eq!(n.pow(4).to_string(), "81");
+    /// assert_eq!(BigInt::new(0).num_digits(10), 1);
+    /// assert_eq!(BigInt::new(-12345).num_digits(10), 5);
+    /// assert_eq!(BigInt::new(255).num_digits(16), 2);
     /// ```
-    pub fn pow(&self, exp: u32) -> Self {
-        BigInt {
-            inner: self.inner.pow(exp),
+    pub fn num_digits(&self, radix: u32) -> u64 {
+        assert!(radix >= 2, "radix must be at least 2");
+        match self.abs().ilog(&BigInt::from(i64::from(radix))) {
+            Some(k) => k + 1,
+            None => 1,
         }
     }
 
-    /// Returns the integer square root of this `BigInt`.
+    /// Returns the sum of the decimal digits of `|self|`.
     ///
-    /// Returns `None` if this number is negative.
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(-9875).digit_sum(), 29);
+    /// ```
+    pub fn digit_sum(&self) -> u64 {
+        self.radix_digits_le(10).iter().map(|&d| u64::from(d)).sum()
+    }
+
+    /// Returns the digital root of `|self|`: the single digit reached by
+    /// repeatedly summing decimal digits.
     ///
     /// # Examples
     ///
     /// ```
     /// use gauss_int::BigInt;
     ///
-    /// let n = BigInt::new(144);
-    /// assert_eq!(n.sqrt().unwrap().to_string(), "12");
+    /// assert_eq!(BigInt::new(9875).digital_root(), 2); // 9+8+7+5 = 29 -> 11 -> 2
+    /// assert_eq!(BigInt::new(0).digital_root(), 0);
+    /// ```
+    pub fn digital_root(&self) -> u32 {
+        if self.is_zero() {
+            return 0;
+        }
+        let nine = BigInt::new(9);
+        let r = (&self.abs() - &BigInt::one()).rem_euclid(&nine);
+        1 + r.big().to_u32().unwrap_or_default()
+    }
+
+    /// Returns the number whose digits in `radix` are those of `self`
+    /// in reverse order. The sign is preserved and leading zeros vanish.
+    ///
+    /// Panics if `radix` is not in `2..=256`.
+    ///
+    /// # Examples
     ///
-    /// let negative = BigInt::new(-4);
-    /// assert!(negative.sqrt().is_none());
     /// ```
-    pub fn sqrt(&self) -> Option<Self> {
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(1230).reverse_digits(10).to_string(), "321");
+    /// assert_eq!(BigInt::new(-12).reverse_digits(10).to_string(), "-21");
+    /// assert_eq!(BigInt::new(0b1101).reverse_digits(2).to_string(), "11");
+    /// ```
+    pub fn reverse_digits(&self, radix: u32) -> Self {
+        let digits = self.radix_digits_le(radix);
+        let magnitude =
+            BigUint::from_radix_be(&digits, radix).expect("digits are valid for their radix");
+        BigInt::from(NumBigInt::from_biguint(self.sign(), magnitude))
+    }
+
+    /// Returns `true` if the digits of `|self|` in `radix` read the same
+    /// in both directions.
+    ///
+    /// Panics if `radix` is not in `2..=256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert!(BigInt::new(12321).is_palindrome(10));
+    /// assert!(!BigInt::new(12321).is_palindrome(16));
+    /// assert!(BigInt::new(0b1001).is_palindrome(2));
+    /// ```
+    pub fn is_palindrome(&self, radix: u32) -> bool {
+        let digits = self.radix_digits_le(radix);
+        digits.iter().eq(digits.iter().rev())
+    }
+
+    /// Returns the Zeckendorf representation: the unique indices
+    /// `k₁ > k₂ > … >= 2`, no two consecutive, with `self = Σ F(kⱼ)`.
+    ///
+    /// Fibonacci numbers are indexed from `F(0) = 0`, `F(1) = 1`. Zero has
+    /// an empty representation. Returns `None` if this number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// // 100 = 89 + 8 + 3 = F(11) + F(6) + F(4)
+    /// assert_eq!(BigInt::new(100).zeckendorf(), Some(vec![11, 6, 4]));
+    /// assert_eq!(BigInt::from_zeckendorf(&[11, 6, 4]), BigInt::new(100));
+    /// ```
+    pub fn zeckendorf(&self) -> Option<Vec<u64>> {
         if self.is_negative() {
             return None;
         }
+        // F(k) >= φ^(k−2) and log_φ(2) < 1.4405, so F(k) > self here
+        let mut k = (self.bits() as f64 * 1.4405).ceil() as u64 + 2;
+        let (mut f, mut f_next) = crate::combinatorics::fibonacci_pair(k);
+        let mut rest = self.clone();
+        let mut indices = Vec::new();
+        // Walk down the sequence, greedily taking the largest F(k) <= rest
+        while !rest.is_zero() {
+            if f <= rest {
+                rest -= &f;
+                indices.push(k);
+                // The next index down cannot be used, so skip it
+                (f, f_next) = (&f_next - &f, f);
+                k -= 1;
+            }
+            (f, f_next) = (&f_next - &f, f);
+            k -= 1;
+        }
+        Some(indices)
+    }
+
+    /// Returns `Σ F(k)` over `indices`, the inverse of
+    /// [`zeckendorf`](Self::zeckendorf).
+    ///
+    /// Any indices are accepted, in any order; repeated, consecutive or
+    /// small indices simply add their Fibonacci numbers.
+    pub fn from_zeckendorf(indices: &[u64]) -> Self {
+        let mut sum = BigInt::ZERO;
+        for &k in indices {
+            sum += &crate::combinatorics::fibonacci(k);
+        }
+        sum
+    }
+
+    /// Returns the digits of `|self|` in `radix`, least significant first.
+    ///
+    /// Any radix in `2..=2^32` is supported, so every digit fits in a `u32`.
+    /// Zero is returned as `[0]`. The sign is not encoded.
+    ///
+    /// Panics if `radix` is outside `2..=2^32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(1234).to_radix_digits(10), vec![4, 3, 2, 1]);
+    /// assert_eq!(BigInt::new(1_000_000).to_radix_digits(1000), vec![0, 0, 1]);
+    /// ```
+    pub fn to_radix_digits(&self, radix: u64) -> Vec<u32> {
+        assert!(
+            (2..=RADIX_DIGITS_MAX).contains(&radix),
+            "radix must be in 2..=2^32"
+        );
+        let big = self.big();
+        let magnitude = big.magnitude();
+        if radix == RADIX_DIGITS_MAX {
+            let digits = magnitude.to_u32_digits();
+            return if digits.is_empty() { vec![0] } else { digits };
+        }
+        if radix <= 256 {
+            return magnitude
+                .to_radix_le(radix as u32)
+                .into_iter()
+                .map(u32::from)
+                .collect();
+        }
 
-        let mut low = BigInt::new(0);
-        let mut high = self.clone();
+        // Peel off as many digits per big division as fit in a u64.
+        let (chunk, per_chunk) = radix_chunk(radix);
+        let mut digits = Vec::new();
+        let mut rest = magnitude.clone();
+        while !rest.is_zero() {
+            let (q, r) = rest.div_rem(&BigUint::from(chunk));
+            let mut r = r.to_u64().expect("remainder is below the chunk size");
+            rest = q;
+            for _ in 0..per_chunk {
+                digits.push((r % radix) as u32);
+                r /= radix;
+                if rest.is_zero() && r == 0 {
+                    break;
+                }
+            }
+        }
+        if digits.is_empty() {
+            digits.push(0);
+        }
+        digits
+    }
 
-        while low <= high {
-            let mid = (&low + &high) / BigInt::new(2);
-            let mid_squared = &mid * &mid;
+    /// Builds a non-negative `BigInt` from digits in `radix`, least significant first.
+    ///
+    /// This is the inverse of [`BigInt::to_radix_digits`]. Returns `None` if
+    /// `radix` is outside `2..=2^32` or any digit is not below `radix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let n = BigInt::from_radix_digits(1000, &[0, 0, 1]).unwrap();
+    /// assert_eq!(n.to_string(), "1000000");
+    /// assert!(BigInt::from_radix_digits(10, &[10]).is_none());
+    /// ```
+    pub fn from_radix_digits(radix: u64, digits: &[u32]) -> Option<Self> {
+        if !(2..=RADIX_DIGITS_MAX).contains(&radix) || digits.iter().any(|&d| u64::from(d) >= radix)
+        {
+            return None;
+        }
+        if radix == RADIX_DIGITS_MAX {
+            return Some(BigInt::from(NumBigInt::from_biguint(
+                Sign::Plus,
+                BigUint::new(digits.to_vec()),
+            )));
+        }
 
-            match mid_squared.cmp(self) {
-                Ordering::Equal => return Some(mid),
-                Ordering::Less => low = mid + BigInt::new(1),
-                Ordering::Greater => high = mid - BigInt::new(1),
+        // Horner's rule over chunks of digits that fit in a u64.
+        let (chunk, per_chunk) = radix_chunk(radix);
+        let mut value = BigUint::zero();
+        for group in digits.rchunks(per_chunk) {
+            let mut word = 0u64;
+            let mut scale = 1u64;
+            for &d in group.iter().rev() {
+                word = word * radix + u64::from(d);
+                scale *= radix;
             }
+            let multiplier = if group.len() == per_chunk {
+                chunk
+            } else {
+                scale
+            };
+            value = value * multiplier + word;
         }
+        Some(BigInt::from(NumBigInt::from_biguint(Sign::Plus, value)))
+    }
 
-        Some(high)
+    /// Digits of `|self|` in `radix`, least significant first.
+    fn radix_digits_le(&self, radix: u32) -> Vec<u8> {
+        self.big().magnitude().to_radix_le(radix)
     }
 
-    /// Returns the greatest common divisor of this `BigInt` and `other`.
-    pub fn gcd(&self, other: &Self) -> Self {
-        BigInt {
-            inner: self.inner.gcd(&other.inner),
+    /// Converts a value known to fit in a `u64`, saturating otherwise.
+    pub(crate) fn to_u64_lossy(&self) -> u64 {
+        match &self.repr {
+            Repr::Small(v) => u64::try_from(*v).unwrap_or(u64::MAX),
+            Repr::Large(n) => n.to_u64().unwrap_or(u64::MAX),
         }
     }
 
-    /// Returns the least common multiple of this `BigInt` and `other`.
-    pub fn lcm(&self, other: &Self) -> Self {
-        BigInt {
-            inner: self.inner.lcm(&other.inner),
+    /// Approximates `log2(|self|)` from the leading 64 bits.
+    fn log2_approx(&self) -> f64 {
+        let bits = self.bits();
+        let shift = bits.saturating_sub(64);
+        let top = (self.big().magnitude() >> shift)
+            .to_u64()
+            .unwrap_or(u64::MAX);
+        shift as f64 + (top as f64).log2()
+    }
+
+    /// Returns `true` if this `BigInt` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.repr == Repr::Small(0)
+    }
+
+    /// Returns `true` if this `BigInt` is positive.
+    pub fn is_positive(&self) -> bool {
+        self.sign() == Sign::Plus
+    }
+
+    /// Returns `true` if this `BigInt` is negative.
+    pub fn is_negative(&self) -> bool {
+        self.sign() == Sign::Minus
+    }
+
+    /// Returns `-1`, `0` or `1` according to the sign.
+    pub fn signum(&self) -> i8 {
+        match self.sign() {
+            Sign::Minus => -1,
+            Sign::NoSign => 0,
+            Sign::Plus => 1,
         }
     }
 
-    /// Computes modular exponentiation: (self^exp) mod modulus.
+    /// Returns `|self - other|`.
     ///
     /// # Examples
     ///
     /// ```
     /// use gauss_int::BigInt;
     ///
-    /// let base = BigInt::new(7);
-    /// let exp = BigInt::new(3);
-    /// let modulus = BigInt::new(11);
-    /// // 7^3 mod 11 = 343 mod 11 = 2
-    /// assert_eq!(base.mod_pow(&exp, &modulus).to_string(), "2");
+    /// assert_eq!(BigInt::new(-3).abs_diff(&BigInt::new(4)), BigInt::new(7));
     /// ```
-    pub fn mod_pow(&self, exp: &Self, modulus: &Self) -> Self {
-        BigInt {
-            inner: self.inner.modpow(&exp.inner, &modulus.inner),
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        if let (Some(a), Some(b)) = (self.small(), other.small()) {
+            return BigInt::from_i128((i128::from(a) - i128::from(b)).abs());
         }
+        (self - other).abs()
     }
 
-    /// Returns the modular multiplicative inverse of this `BigInt` modulo `modulus`.
+    /// Returns the smaller of `self` and `other` by reference, so neither is
+    /// cloned or consumed as with [`Ord::min`]; `self` on ties.
+    pub fn min_ref<'a>(&'a self, other: &'a Self) -> &'a Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of `self` and `other` by reference; `self` on ties.
+    pub fn max_ref<'a>(&'a self, other: &'a Self) -> &'a Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restricts `self` to `[lo, hi]` by reference, like [`Ord::clamp`].
     ///
-    /// Returns `None` if the inverse does not exist.
+    /// Panics if `lo > hi`.
     ///
     /// # Examples
     ///
     /// ```
     /// use gauss_int::BigInt;
     ///
-    /// let n = BigInt::new(3);
-    /// let modulus = BigInt::new(11);
-    /// // 3 * 4 = 12 ≡ 1 mod 11
-    /// assert_eq!(n.mod_inv(&modulus).unwrap().to_string(), "4");
+    /// let (lo, hi) = (BigInt::new(0), BigInt::new(255));
+    /// assert_eq!(BigInt::new(300).clamp_ref(&lo, &hi), &hi);
+    /// assert_eq!(BigInt::new(-5).clamp_ref(&lo, &hi), &lo);
     /// ```
-    pub fn mod_inv(&self, modulus: &Self) -> Option<Self> {
-        self.inner
-            .modinv(&modulus.inner)
-            .map(|n| BigInt { inner: n })
+    pub fn clamp_ref<'a>(&'a self, lo: &'a Self, hi: &'a Self) -> &'a Self {
+        assert!(lo <= hi, "clamp_ref: lo > hi");
+        if self < lo {
+            lo
+        } else if self > hi {
+            hi
+        } else {
+            self
+        }
     }
 
-    /// Returns the factorial of this `BigInt`.
-    ///
-    /// Returns `None` if this number is negative.
+    /// Writes `self + other` into `out`, reusing its allocation.
     ///
     /// # Examples
     ///
     /// ```
     /// use gauss_int::BigInt;
     ///
-    /// let n = BigInt::new(5);
-    /// assert_eq!(n.factorial().unwrap().to_string(), "120"); // 5! = 120
-    ///
-    /// let negative = BigInt::new(-5);
-    /// assert!(negative.factorial().is_none());
+    /// let mut out = BigInt::new(0);
+    /// BigInt::new(40).add_into(&BigInt::new(2), &mut out);
+    /// assert_eq!(out, BigInt::new(42));
     /// ```
-    pub fn factorial(&self) -> Option<Self> {
-        if self.is_negative() {
-            return None;
+    pub fn add_into(&self, other: &Self, out: &mut Self) {
+        if let (Some(a), Some(b)) = (self.small(), other.small()) {
+            *out = BigInt::from_i128(i128::from(a) + i128::from(b));
+            return;
         }
+        let mut value = out.take_big();
+        value.clone_from(&self.big());
+        value += &*other.big();
+        *out = BigInt::from(value);
+    }
 
-        let mut result = BigInt::one();
-        let mut current = BigInt::one();
-
-        while current <= *self {
-            result = result * current.clone();
-            current = current + BigInt::one();
+    /// Writes `self - other` into `out`, reusing its allocation.
+    pub fn sub_into(&self, other: &Self, out: &mut Self) {
+        if let (Some(a), Some(b)) = (self.small(), other.small()) {
+            *out = BigInt::from_i128(i128::from(a) - i128::from(b));
+            return;
         }
+        let mut value = out.take_big();
+        value.clone_from(&self.big());
+        value -= &*other.big();
+        *out = BigInt::from(value);
+    }
 
-        Some(result)
+    /// Writes `self * other` into `out`.
+    pub fn mul_into(&self, other: &Self, out: &mut Self) {
+        if let (Some(a), Some(b)) = (self.small(), other.small()) {
+            *out = BigInt::from_i128(i128::from(a) * i128::from(b));
+            return;
+        }
+        let mut value = out.take_big();
+        value.clone_from(&self.big());
+        value *= &*other.big();
+        *out = BigInt::from(value);
     }
 
-    /// Checks if this `BigInt` is a prime number.
-    ///
-    /// Uses the Baillie-PSW primality test, which is deterministic for
-    /// `n < 2^64` and has no known counterexamples for larger values.
+    /// Raises this `BigInt` to the power of `exp`.
     ///
     /// # Examples
     ///
     /// ```
     /// use gauss_int::BigInt;
     ///
-    /// assert!(BigInt::new(2).is_prime());
-    /// assert!(BigInt::new(97).is_prime());
-    /// assert!(!BigInt::new(100).is_prime());
+    /// let n = BigInt::new(3);
+    /// assert_eq!(n.pow(4).to_string(), "81");
     /// ```
-    pub fn is_prime(&self) -> bool {
-        crate::number_theory::is_prime(self)
+    pub fn pow(&self, exp: u32) -> Self {
+        BigInt::from(self.big().pow(exp))
     }
 
-    /// Returns (quotient, remainder) of division, where quotient truncates toward zero.
-    pub fn div_mod(&self, other: &Self) -> (Self, Self) {
-        (self / other, self % other)
+    /// Returns the integer square root of this `BigInt`.
+    ///
+    /// Returns `None` if this number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let n = BigInt::new(144);
+    /// assert_eq!(n.sqrt().unwrap().to_string(), "12");
+    ///
+    /// let negative = BigInt::new(-4);
+    /// assert!(negative.sqrt().is_none());
+    /// ```
+    pub fn sqrt(&self) -> Option<Self> {
+        self.sqrt_rem().map(|(root, _)| root)
+    }
+
+    /// Returns the integer square root together with the remainder.
+    ///
+    /// The result `(s, r)` satisfies `self = s² + r` with `0 <= r <= 2s`,
+    /// so `r` is zero exactly when this number is a perfect square.
+    /// Returns `None` if this number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let (s, r) = BigInt::new(150).sqrt_rem().unwrap();
+    /// assert_eq!(s.to_string(), "12");
+    /// assert_eq!(r.to_string(), "6");
+    /// ```
+    pub fn sqrt_rem(&self) -> Option<(Self, Self)> {
+        if self.is_negative() {
+            return None;
+        }
+        let root = BigInt::from(self.big().sqrt());
+        let rem = self - &(&root * &root);
+        Some((root, rem))
+    }
+
+    /// Returns `true` if this `BigInt` is the square of an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert!(BigInt::new(144).is_perfect_square());
+    /// assert!(!BigInt::new(145).is_perfect_square());
+    /// assert!(!BigInt::new(-4).is_perfect_square());
+    /// ```
+    pub fn is_perfect_square(&self) -> bool {
+        self.sqrt_rem().is_some_and(|(_, rem)| rem.is_zero())
+    }
+
+    /// Returns the greatest common divisor of this `BigInt` and `other`.
+    pub fn gcd(&self, other: &Self) -> Self {
+        if let (Some(a), Some(b)) = (self.small(), other.small()) {
+            let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            return BigInt::from_i128(i128::from(a));
+        }
+        BigInt::from(self.big().gcd(&*other.big()))
+    }
+
+    /// Returns the least common multiple of this `BigInt` and `other`.
+    pub fn lcm(&self, other: &Self) -> Self {
+        BigInt::from(self.big().lcm(&*other.big()))
+    }
+
+    /// Computes modular exponentiation: (self^exp) mod modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let base = BigInt::new(7);
+    /// let exp = BigInt::new(3);
+    /// let modulus = BigInt::new(11);
+    /// // 7^3 mod 11 = 343 mod 11 = 2
+    /// assert_eq!(base.mod_pow(&exp, &modulus).to_string(), "2");
+    /// ```
+    pub fn mod_pow(&self, exp: &Self, modulus: &Self) -> Self {
+        BigInt::from(self.big().modpow(&exp.big(), &modulus.big()))
+    }
+
+    /// Returns the modular multiplicative inverse of this `BigInt` modulo `modulus`.
+    ///
+    /// Returns `None` if the inverse does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let n = BigInt::new(3);
+    /// let modulus = BigInt::new(11);
+    /// // 3 * 4 = 12 ≡ 1 mod 11
+    /// assert_eq!(n.mod_inv(&modulus).unwrap().to_string(), "4");
+    /// ```
+    pub fn mod_inv(&self, modulus: &Self) -> Option<Self> {
+        self.big().modinv(&modulus.big()).map(BigInt::from)
+    }
+
+    /// Returns the factorial of this `BigInt`.
+    ///
+    /// Uses binary splitting, so the multiplications stay balanced.
+    /// Returns `None` if this number is negative or too large to enumerate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let n = BigInt::new(5);
+    /// assert_eq!(n.factorial().unwrap().to_string(), "120"); // 5! = 120
+    ///
+    /// let negative = BigInt::new(-5);
+    /// assert!(negative.factorial().is_none());
+    /// ```
+    pub fn factorial(&self) -> Option<Self> {
+        if self.is_negative() {
+            return None;
+        }
+        let n = self.big().to_u64()?;
+        Some(product_range(&BigInt::one(), n, 1))
+    }
+
+    /// Returns the double factorial `n!! = n·(n−2)·(n−4)·…`, ending at 1 or 2.
+    ///
+    /// Returns `None` if this number is negative or too large to enumerate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(7).double_factorial().unwrap().to_string(), "105");
+    /// assert_eq!(BigInt::new(8).double_factorial().unwrap().to_string(), "384");
+    /// assert_eq!(BigInt::new(0).double_factorial().unwrap().to_string(), "1");
+    /// ```
+    pub fn double_factorial(&self) -> Option<Self> {
+        if self.is_negative() {
+            return None;
+        }
+        let n = self.big().to_u64()?;
+        let start = if n % 2 == 0 { 2 } else { 1 };
+        Some(product_range(&BigInt::new(start), n.div_ceil(2), 2))
+    }
+
+    /// Returns the rising factorial `x·(x+1)·…·(x+k−1)` (Pochhammer symbol).
+    ///
+    /// The empty product (`k = 0`) is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(3).rising_factorial(4).to_string(), "360"); // 3·4·5·6
+    /// assert_eq!(BigInt::new(-2).rising_factorial(3).to_string(), "0");
+    /// ```
+    pub fn rising_factorial(&self, k: u32) -> Self {
+        product_range(self, u64::from(k), 1)
+    }
+
+    /// Returns the falling factorial `x·(x−1)·…·(x−k+1)`.
+    ///
+    /// The empty product (`k = 0`) is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(6).falling_factorial(3).to_string(), "120"); // 6·5·4
+    /// assert_eq!(BigInt::new(-3).falling_factorial(2).to_string(), "12");
+    /// ```
+    pub fn falling_factorial(&self, k: u32) -> Self {
+        if k == 0 {
+            return BigInt::one();
+        }
+        let start = self - &BigInt::from(i64::from(k) - 1);
+        product_range(&start, u64::from(k), 1)
+    }
+
+    /// Checks if this `BigInt` is a prime number.
+    ///
+    /// Uses the Baillie-PSW primality test, which is deterministic for
+    /// `n < 2^64` and has no known counterexamples for larger values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert!(BigInt::new(2).is_prime());
+    /// assert!(BigInt::new(97).is_prime());
+    /// assert!(!BigInt::new(100).is_prime());
+    /// ```
+    pub fn is_prime(&self) -> bool {
+        crate::number_theory::is_prime(self)
+    }
+
+    /// Returns the smallest prime strictly greater than this `BigInt`.
+    ///
+    /// See [`crate::number_theory::next_prime`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(97).next_prime().to_string(), "101");
+    /// ```
+    pub fn next_prime(&self) -> Self {
+        crate::number_theory::next_prime(self)
+    }
+
+    /// Returns the largest prime strictly less than this `BigInt`,
+    /// or `None` if there is none (inputs below 3).
+    ///
+    /// See [`crate::number_theory::prev_prime`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(100).prev_prime().unwrap().to_string(), "97");
+    /// assert!(BigInt::new(2).prev_prime().is_none());
+    /// ```
+    pub fn prev_prime(&self) -> Option<Self> {
+        crate::number_theory::prev_prime(self)
+    }
+
+    /// Returns Carmichael's function λ(self), the largest multiplicative
+    /// order of any unit modulo this number.
+    ///
+    /// See [`crate::number_theory::carmichael_lambda`].
+    pub fn carmichael_lambda(&self) -> Self {
+        crate::number_theory::carmichael_lambda(self)
+    }
+
+    /// Writes this `BigInt` as a sum of four squares `a² + b² + c² + d²`.
+    ///
+    /// Always succeeds for non-negative inputs (Lagrange's theorem) and returns
+    /// `None` for negative ones. See [`crate::number_theory::four_squares`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let [a, b, c, d] = BigInt::new(7).as_sum_of_four_squares().unwrap();
+    /// assert_eq!([a, b, c, d].map(|x| x.to_string()), ["2", "1", "1", "1"]);
+    /// ```
+    pub fn as_sum_of_four_squares(&self) -> Option<[Self; 4]> {
+        crate::number_theory::four_squares(self)
+    }
+
+    /// Returns (quotient, remainder) of division, where quotient truncates toward zero.
+    ///
+    /// Equivalent to [`BigInt::div_rem`].
+    pub fn div_mod(&self, other: &Self) -> (Self, Self) {
+        self.div_rem(other)
+    }
+
+    /// Returns `(quotient, remainder)` with the quotient truncated toward zero,
+    /// in a single division.
+    ///
+    /// The remainder has the sign of `self`, matching the `/` and `%` operators.
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let (q, r) = BigInt::new(-7).div_rem(&BigInt::new(2));
+    /// assert_eq!((q.to_string(), r.to_string()), ("-3".into(), "-1".into()));
+    /// ```
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        if let (Some(a), Some(b)) = (self.small(), other.small()) {
+            let (a, b) = (i128::from(a), i128::from(b));
+            return (BigInt::from_i128(a / b), BigInt::from_i128(a % b));
+        }
+        let (q, r) = self.big().div_rem(&*other.big());
+        (BigInt::from(q), BigInt::from(r))
+    }
+
+    /// Like `/`, but returns `None` instead of panicking when `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(-7).checked_div(&BigInt::new(2)), Some(BigInt::new(-3)));
+    /// assert_eq!(BigInt::new(7).checked_div(&BigInt::ZERO), None);
+    /// ```
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        (!other.is_zero()).then(|| self / other)
+    }
+
+    /// Like `%`, but returns `None` instead of panicking when `other` is zero.
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        (!other.is_zero()).then(|| self % other)
+    }
+
+    /// Like [`div_rem`](Self::div_rem), but returns `None` instead of
+    /// panicking when `other` is zero.
+    pub fn checked_div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        (!other.is_zero()).then(|| self.div_rem(other))
+    }
+
+    /// Compares absolute values without allocating either of them.
+    pub(crate) fn cmp_abs(&self, other: &Self) -> Ordering {
+        match (&self.repr, &other.repr) {
+            (Repr::Small(a), Repr::Small(b)) => a.unsigned_abs().cmp(&b.unsigned_abs()),
+            // Large values lie outside the i64 range
+            (Repr::Small(_), Repr::Large(_)) => Ordering::Less,
+            (Repr::Large(_), Repr::Small(_)) => Ordering::Greater,
+            (Repr::Large(a), Repr::Large(b)) => a.magnitude().cmp(b.magnitude()),
+        }
+    }
+
+    /// Returns the quotient rounded toward negative infinity.
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(-7).div_floor(&BigInt::new(2)).to_string(), "-4");
+    /// assert_eq!(BigInt::new(7).div_floor(&BigInt::new(2)).to_string(), "3");
+    /// ```
+    pub fn div_floor(&self, other: &Self) -> Self {
+        BigInt::from(self.big().div_floor(&*other.big()))
+    }
+
+    /// Returns the quotient rounded toward positive infinity.
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(7).div_ceil(&BigInt::new(2)).to_string(), "4");
+    /// assert_eq!(BigInt::new(-7).div_ceil(&BigInt::new(2)).to_string(), "-3");
+    /// ```
+    pub fn div_ceil(&self, other: &Self) -> Self {
+        BigInt::from(Integer::div_ceil(&*self.big(), &*other.big()))
+    }
+
+    /// Returns the Euclidean quotient `q` such that `self = q·other + r`
+    /// with `0 <= r < |other|`.
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(-7).div_euclid(&BigInt::new(2)).to_string(), "-4");
+    /// assert_eq!(BigInt::new(-7).div_euclid(&BigInt::new(-2)).to_string(), "4");
+    /// ```
+    pub fn div_euclid(&self, other: &Self) -> Self {
+        if other.is_negative() {
+            Integer::div_ceil(&*self.big(), &*other.big()).into()
+        } else {
+            self.div_floor(other)
+        }
+    }
+
+    /// Returns the Euclidean remainder, which always lies in `0..|other|`.
+    ///
+    /// This is the canonical residue for modular arithmetic.
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(-7).rem_euclid(&BigInt::new(3)).to_string(), "2");
+    /// assert_eq!(BigInt::new(-7).rem_euclid(&BigInt::new(-3)).to_string(), "2");
+    /// ```
+    pub fn rem_euclid(&self, other: &Self) -> Self {
+        if let (Some(a), Some(b)) = (self.small(), other.small()) {
+            return BigInt::from_i128(i128::from(a).rem_euclid(i128::from(b)));
+        }
+        BigInt::from(self.big().mod_floor(&other.big().abs()))
+    }
+}
+
+/// Largest radix accepted by [`BigInt::to_radix_digits`].
+const RADIX_DIGITS_MAX: u64 = 1 << 32;
+
+/// Returns `(radix^k, k)` for the largest `k` with `radix^k <= u64::MAX`.
+fn radix_chunk(radix: u64) -> (u64, usize) {
+    let mut chunk = radix;
+    let mut per_chunk = 1;
+    while let Some(next) = chunk.checked_mul(radix) {
+        chunk = next;
+        per_chunk += 1;
+    }
+    (chunk, per_chunk)
+}
+
+/// Product `start·(start+step)·…·(start+(count−1)·step)` by binary splitting.
+///
+/// Splitting the range in half keeps both operands of every multiplication
+/// roughly the same size, which is what the fast multiplication kernels in
+/// num-bigint want.
+pub(crate) fn product_range(start: &BigInt, count: u64, step: u64) -> BigInt {
+    match count {
+        0 => BigInt::one(),
+        1 => start.clone(),
+        2 => start * &(start + &BigInt::from(NumBigInt::from(step))),
+        _ => {
+            let half = count / 2;
+            let offset = BigInt::from(NumBigInt::from(half) * step);
+            let left = product_range(start, half, step);
+            let right = product_range(&(start + &offset), count - half, step);
+            left * right
+        }
+    }
+}
+
+// --- Bit operations ---
+//
+// The operators `&`, `|`, `^`, `!`, `<<` and `>>` treat values as infinite
+// two's complement, like the primitive signed integers.
+
+impl BigInt {
+    /// Returns the binary reflected Gray code `self ^ (self >> 1)`, in which
+    /// consecutive integers differ in exactly one bit.
+    ///
+    /// Returns `None` if this number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let codes: Vec<_> = (0..5).map(|n| BigInt::new(n).to_gray().unwrap()).collect();
+    /// assert_eq!(codes, [0, 1, 3, 2, 6].map(BigInt::new));
+    /// assert_eq!(BigInt::new(6).from_gray(), Some(BigInt::new(4)));
+    /// ```
+    pub fn to_gray(&self) -> Option<Self> {
+        (!self.is_negative()).then(|| self ^ &(self >> 1))
+    }
+
+    /// Inverts [`to_gray`](Self::to_gray), treating this number as a Gray
+    /// code; each bit of the result is the XOR of this number's bits at and
+    /// above it.
+    ///
+    /// Takes `O(log bits)` shifts. Returns `None` if this number is negative.
+    pub fn from_gray(&self) -> Option<Self> {
+        if self.is_negative() {
+            return None;
+        }
+        let mut n = self.clone();
+        let mut shift = 1;
+        while shift < self.bits() {
+            n = &n ^ &(&n >> shift);
+            shift *= 2;
+        }
+        Some(n)
+    }
+
+    /// Returns the number of one bits in a non-negative value.
+    ///
+    /// A negative value has infinitely many one bits in two's complement,
+    /// so it gives `None` rather than a misleading count; use
+    /// [`count_ones_twos_complement`](Self::count_ones_twos_complement) to
+    /// count within a fixed width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(0b1011).count_ones(), Some(3));
+    /// assert_eq!(BigInt::new(-1).count_ones(), None);
+    /// ```
+    pub fn count_ones(&self) -> Option<u64> {
+        (!self.is_negative()).then(|| self.count_ones_low(u64::MAX))
+    }
+
+    /// Counts the one bits among the low `width` bits of the two's-complement
+    /// representation, that is of `self mod 2^width`, as if this number had
+    /// been cast to a `width`-bit unsigned integer.
+    ///
+    /// Bits at or above `width` are ignored, whatever the sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(-1).count_ones_twos_complement(128), 128);
+    /// assert_eq!(BigInt::new(-8).count_ones_twos_complement(8), 5); // 0b11111000
+    /// assert_eq!(BigInt::new(0b1011).count_ones_twos_complement(2), 2);
+    /// ```
+    pub fn count_ones_twos_complement(&self, width: u64) -> u64 {
+        if self.is_negative() {
+            // The low bits of x are the complements of those of !x >= 0
+            width - (!self).count_ones_low(width)
+        } else {
+            self.count_ones_low(width)
+        }
+    }
+
+    /// Counts the zero bits among the low `width` bits of the
+    /// two's-complement representation; always
+    /// `width - count_ones_twos_complement(width)`.
+    pub fn count_zeros(&self, width: u64) -> u64 {
+        width - self.count_ones_twos_complement(width)
+    }
+
+    /// Returns `self mod 2^width`: the low `width` bits of the
+    /// two's-complement representation, as a non-negative value.
+    fn low_bits(&self, width: u64) -> Self {
+        if !self.is_negative() && self.bits() <= width {
+            return self.clone();
+        }
+        self & &(&(&BigInt::ONE << width) - &BigInt::ONE)
+    }
+
+    /// Reverses the low `width` bits of the two's-complement
+    /// representation, so bit `k` moves to bit `width − 1 − k`; higher bits
+    /// are dropped. The result lies in `0..2^width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// // NTT index permutation for size 8
+    /// assert_eq!(BigInt::new(0b001).bit_reverse(3), BigInt::new(0b100));
+    /// assert_eq!(BigInt::new(0b110).bit_reverse(3), BigInt::new(0b011));
+    /// assert_eq!(BigInt::new(-1).bit_reverse(70), BigInt::TWO.pow(70) - BigInt::ONE);
+    /// ```
+    pub fn bit_reverse(&self, width: u64) -> Self {
+        let value = NumBigInt::from(self.low_bits(width));
+        let len = width.div_ceil(32) as usize;
+        let mut digits: Vec<u32> = value.magnitude().iter_u32_digits().collect();
+        digits.resize(len, 0);
+        let reversed: Vec<u32> = digits.iter().rev().map(|d| d.reverse_bits()).collect();
+        // Reversing whole 32-bit digits overshoots by the padding bits
+        let padding = len as u64 * 32 - width;
+        BigInt::from(NumBigInt::from(BigUint::new(reversed) >> padding))
+    }
+
+    /// Counts the leading zeros of the low `width` bits of the
+    /// two's-complement representation, as for a `width`-bit unsigned
+    /// integer; `width` for zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(1).leading_zeros(256), 255);
+    /// assert_eq!(BigInt::new(-1).leading_zeros(256), 0);
+    /// assert_eq!(BigInt::new(0x1ff).leading_zeros(8), 0); // only 0xff is kept
+    /// ```
+    pub fn leading_zeros(&self, width: u64) -> u64 {
+        width - self.low_bits(width).bits()
+    }
+
+    /// Popcount of the low `width` bits of a non-negative value.
+    fn count_ones_low(&self, width: u64) -> u64 {
+        let mut count = 0;
+        for (i, limb) in (0u64..).zip(self.limbs_u64()) {
+            let start = i.saturating_mul(64);
+            if start >= width {
+                break;
+            }
+            let limb = if width - start < 64 {
+                limb & ((1 << (width - start)) - 1)
+            } else {
+                limb
+            };
+            count += u64::from(limb.count_ones());
+        }
+        count
+    }
+
+    /// Returns the number of bit positions in which the two's-complement
+    /// representations of `self` and `other` differ.
+    ///
+    /// The shorter value is sign-extended, so for non-negative values this
+    /// is the popcount of `self ^ other`. Values of opposite sign differ in
+    /// infinitely many high bits and give `None`. The count runs over the
+    /// 64-bit limbs directly, without building byte vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(0b1011).hamming_distance(&BigInt::new(0b0110)), Some(3));
+    /// assert_eq!(BigInt::new(-1).hamming_distance(&BigInt::new(-4)), Some(2));
+    /// assert_eq!(BigInt::new(-1).hamming_distance(&BigInt::new(1)), None);
+    /// ```
+    pub fn hamming_distance(&self, other: &Self) -> Option<u64> {
+        if self.is_negative() != other.is_negative() {
+            return None;
+        }
+        if self.is_negative() {
+            // !x = −x − 1 flips every bit, leaving the differences alone
+            return (!self).hamming_distance(&!other);
+        }
+        let (mut a, mut b) = (self.limbs_u64(), other.limbs_u64());
+        let mut count = 0;
+        loop {
+            match (a.next(), b.next()) {
+                (None, None) => return Some(count),
+                (x, y) => count += u64::from((x.unwrap_or(0) ^ y.unwrap_or(0)).count_ones()),
+            }
+        }
+    }
+}
+
+// --- Streaming output ---
+
+/// Decimal digits formatted at once at the bottom of [`BigInt::write_to`].
+const STREAM_LEAF_DIGITS: usize = 256;
+
+impl BigInt {
+    /// Writes the decimal representation to a [`fmt::Write`] sink, the same
+    /// text as `Display`, without building it as one `String`.
+    ///
+    /// The magnitude is split recursively by `10^(256·2^k)`, so only one
+    /// 256-digit chunk is formatted at a time; the quotients still take
+    /// memory proportional to the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let n = -BigInt::new(10).pow(600) - BigInt::new(7);
+    /// let mut out = String::new();
+    /// n.write_to(&mut out).unwrap();
+    /// assert_eq!(out, n.to_string());
+    /// ```
+    pub fn write_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        let n = match &self.repr {
+            Repr::Small(v) => return write!(w, "{}", v),
+            Repr::Large(n) => n,
+        };
+        if n.is_negative() {
+            w.write_char('-')?;
+        }
+        let magnitude = n.magnitude();
+        let mut powers = Vec::new();
+        let mut power = BigUint::from(10u32).pow(STREAM_LEAF_DIGITS as u32);
+        while &power <= magnitude {
+            let next = &power * &power;
+            powers.push(power);
+            power = next;
+        }
+        write_decimal(magnitude, &powers, false, w)
+    }
+
+    /// Like [`write_to`](Self::write_to), for an [`io::Write`] sink.
+    pub fn write_to_io<W: io::Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        IoSink::run(w, |sink| self.write_to(sink))
+    }
+}
+
+/// Writes `n < powers[k]²` in decimal, or `n < 10^256` if `powers` is empty,
+/// where `powers[i] = 10^(256·2^i)`. With `padded`, leading zeros fill the
+/// full width of that bound.
+fn write_decimal<W: fmt::Write + ?Sized>(
+    n: &BigUint,
+    powers: &[BigUint],
+    padded: bool,
+    w: &mut W,
+) -> fmt::Result {
+    let Some((power, lower)) = powers.split_last() else {
+        return if padded {
+            write!(w, "{:0width$}", n, width = STREAM_LEAF_DIGITS)
+        } else {
+            write!(w, "{}", n)
+        };
+    };
+    let (q, r) = n.div_rem(power);
+    if !padded && q.is_zero() {
+        return write_decimal(&r, lower, false, w);
+    }
+    write_decimal(&q, lower, padded, w)?;
+    write_decimal(&r, lower, true, w)
+}
+
+/// Adapts an [`io::Write`] to [`fmt::Write`], keeping the first I/O error.
+pub(crate) struct IoSink<'a, W: io::Write + ?Sized> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<'a, W: io::Write + ?Sized> IoSink<'a, W> {
+    /// Runs `f` against the adapted sink and recovers its I/O error.
+    pub(crate) fn run(
+        inner: &'a mut W,
+        f: impl FnOnce(&mut Self) -> fmt::Result,
+    ) -> io::Result<()> {
+        let mut sink = IoSink { inner, error: None };
+        match f(&mut sink) {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => Err(sink
+                .error
+                .unwrap_or_else(|| io::Error::other("formatter error"))),
+        }
+    }
+}
+
+impl<W: io::Write + ?Sized> fmt::Write for IoSink<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+// --- String parsing ---
+
+/// A cursor over a string for the decimal parsers, tracking the byte offset
+/// for error reports.
+pub(crate) struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        Scanner {
+            bytes: s.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    pub(crate) fn pos(&self) -> usize {
+        self.pos
+    }
+
+    pub(crate) fn at_end(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    /// Consumes `b` if it comes next.
+    pub(crate) fn eat(&mut self, b: u8) -> bool {
+        let found = self.peek() == Some(b);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    /// Consumes a leading `+` or `-`, returning `true` for `-`.
+    pub(crate) fn minus_sign(&mut self) -> bool {
+        !self.eat(b'+') && self.eat(b'-')
+    }
+
+    /// A run of decimal digits, or `Ok(None)` without consuming anything if
+    /// the next byte is not a digit.
+    ///
+    /// Single underscores between digits are skipped, as in `1_000`. An
+    /// underscore not followed by a digit is an error, with the position
+    /// left at the byte after it.
+    pub(crate) fn digits(&mut self) -> Result<Option<BigUint>, ()> {
+        self.digits_in(10)
+    }
+
+    /// Like [`digits`](Self::digits), in `radix`, with letters of either
+    /// case for digits above 9.
+    fn digits_in(&mut self, radix: u32) -> Result<Option<BigUint>, ()> {
+        let is_digit = |b: Option<u8>| b.is_some_and(|b| char::from(b).is_digit(radix));
+        let start = self.pos;
+        if !is_digit(self.peek()) {
+            return Ok(None);
+        }
+        loop {
+            match self.peek() {
+                b if is_digit(b) => self.pos += 1,
+                Some(b'_') => {
+                    self.pos += 1;
+                    if !is_digit(self.peek()) {
+                        return Err(());
+                    }
+                }
+                _ => break,
+            }
+        }
+        // num-bigint skips the underscores itself
+        Ok(BigUint::parse_bytes(&self.bytes[start..self.pos], radix))
+    }
+
+    /// Consumes a `0x`, `0o` or `0b` prefix and returns its radix, or 10
+    /// if there is none.
+    fn radix_prefix(&mut self) -> u32 {
+        let radix = match self.bytes.get(self.pos..self.pos + 2) {
+            Some(b"0x") => 16,
+            Some(b"0o") => 8,
+            Some(b"0b") => 2,
+            _ => return 10,
+        };
+        self.pos += 2;
+        radix
+    }
+
+    /// An optionally signed run of digits, after an optional radix prefix.
+    /// On `None` the position is left at the offending byte.
+    pub(crate) fn signed_integer(&mut self) -> Option<BigInt> {
+        let negative = self.minus_sign();
+        let radix = self.radix_prefix();
+        let magnitude = self.digits_in(radix).ok().flatten()?;
+        let sign = if negative { Sign::Minus } else { Sign::Plus };
+        Some(BigInt::from(NumBigInt::from_biguint(sign, magnitude)))
+    }
+}
+
+// --- Streaming input ---
+
+/// Bytes requested from the reader at a time by [`BigInt::read_from`].
+const READ_CHUNK: usize = 1 << 16;
+
+/// Decimal digits packed into each `u64` while parsing, and `10^19`.
+const DECIMAL_LIMB_DIGITS: u32 = 19;
+const DECIMAL_LIMB: u64 = 10_000_000_000_000_000_000;
+
+impl BigInt {
+    /// Parses decimal text from a reader, such as `-123` followed by a
+    /// newline, reading it in 64 KiB chunks instead of into one `String`.
+    ///
+    /// Accepts an optional sign, an optional `0x`, `0o` or `0b` prefix and
+    /// at least one digit, with optional ASCII whitespace around them;
+    /// underscores between digits are skipped as in
+    /// [`try_from_string`](Self::try_from_string). Decimal digits are
+    /// packed 19 to a `u64` as they arrive and combined by divide and
+    /// conquer at the end, so memory stays below the size of the text;
+    /// other radixes keep one byte per digit until the end.
+    ///
+    /// Malformed text fails with [`io::ErrorKind::InvalidData`] wrapping
+    /// an [`Error::ParseBigInt`] that gives the offending byte offset; read
+    /// errors are passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let text = format!("  -{}\n", "9".repeat(500));
+    /// let n = BigInt::read_from(text.as_bytes()).unwrap();
+    /// assert_eq!(n, -BigInt::new(10).pow(500) + BigInt::ONE);
+    /// assert!(BigInt::read_from("12x".as_bytes()).is_err());
+    /// ```
+    pub fn read_from<R: io::Read>(reader: R) -> io::Result<Self> {
+        let mut text = TextReader::new(reader);
+        text.skip_whitespace()?;
+        let n = text.signed_integer()?.ok_or_else(|| text.invalid())?;
+        text.finish()?;
+        Ok(n)
+    }
+}
+
+/// A byte cursor over an [`io::Read`] with its own buffer, for the
+/// `read_from` parsers.
+pub(crate) struct TextReader<R> {
+    inner: R,
+    buf: Box<[u8]>,
+    pos: usize,
+    len: usize,
+    offset: u64,
+    /// Report errors as [`ParseGaussIntError`] rather than [`ParseBigIntError`].
+    gauss: bool,
+}
+
+impl<R: io::Read> TextReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        TextReader {
+            inner,
+            buf: vec![0; READ_CHUNK].into_boxed_slice(),
+            pos: 0,
+            len: 0,
+            offset: 0,
+            gauss: false,
+        }
+    }
+
+    /// Like [`new`](Self::new), for parsing a [`GaussInt`](crate::GaussInt).
+    pub(crate) fn for_gauss_int(inner: R) -> Self {
+        TextReader {
+            gauss: true,
+            ..TextReader::new(inner)
+        }
+    }
+
+    /// The next byte without consuming it, or `None` at end of input.
+    pub(crate) fn peek(&mut self) -> io::Result<Option<u8>> {
+        while self.pos == self.len {
+            match self.inner.read(&mut self.buf) {
+                Ok(0) => return Ok(None),
+                Ok(n) => (self.pos, self.len) = (0, n),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(Some(self.buf[self.pos]))
+    }
+
+    /// Consumes the byte returned by the last [`peek`](Self::peek).
+    pub(crate) fn bump(&mut self) {
+        self.pos += 1;
+        self.offset += 1;
+    }
+
+    pub(crate) fn skip_whitespace(&mut self) -> io::Result<()> {
+        while self.peek()?.is_some_and(|b| b.is_ascii_whitespace()) {
+            self.bump();
+        }
+        Ok(())
+    }
+
+    /// Skips trailing whitespace and requires the end of input.
+    pub(crate) fn finish(&mut self) -> io::Result<()> {
+        self.skip_whitespace()?;
+        match self.peek()? {
+            None => Ok(()),
+            Some(_) => Err(self.invalid()),
+        }
+    }
+
+    /// Consumes a leading `+` or `-`, returning `true` for `-`.
+    pub(crate) fn minus_sign(&mut self) -> io::Result<bool> {
+        match self.peek()? {
+            Some(b @ (b'+' | b'-')) => {
+                self.bump();
+                Ok(b == b'-')
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// An optionally signed run of digits, or `None` if no digit follows
+    /// the sign.
+    pub(crate) fn signed_integer(&mut self) -> io::Result<Option<BigInt>> {
+        let negative = self.minus_sign()?;
+        let magnitude = match self.radix_prefix()? {
+            (10, leading_zero) => self.decimal_digits(leading_zero)?,
+            (radix, _) => self.digits_in(radix)?,
+        };
+        let Some(magnitude) = magnitude else {
+            return Ok(None);
+        };
+        let sign = if negative { Sign::Minus } else { Sign::Plus };
+        Ok(Some(BigInt::from(NumBigInt::from_biguint(sign, magnitude))))
+    }
+
+    /// Consumes a `0x`, `0o` or `0b` prefix, as for strings, and returns
+    /// its radix. The reader cannot look two bytes ahead, so a `0` that
+    /// turns out to start a decimal number is consumed too; the flag says
+    /// so, and the radix is then 10.
+    fn radix_prefix(&mut self) -> io::Result<(u32, bool)> {
+        if self.peek()? != Some(b'0') {
+            return Ok((10, false));
+        }
+        self.bump();
+        let radix = match self.peek()? {
+            Some(b'x') => 16,
+            Some(b'o') => 8,
+            Some(b'b') => 2,
+            _ => return Ok((10, true)),
+        };
+        self.bump();
+        Ok((radix, false))
+    }
+
+    /// A run of digits in `radix`, with underscores as in
+    /// [`digits`](Self::digits). The digit values are kept until the end,
+    /// one byte each, since their place values are not known before then.
+    fn digits_in(&mut self, radix: u32) -> io::Result<Option<BigUint>> {
+        let digit = |b: Option<u8>| b.and_then(|b| char::from(b).to_digit(radix));
+        let mut values = Vec::new();
+        loop {
+            let b = self.peek()?;
+            if let Some(d) = digit(b) {
+                self.bump();
+                values.push(d as u8);
+            } else if b == Some(b'_') && !values.is_empty() {
+                self.bump();
+                if digit(self.peek()?).is_none() {
+                    return Err(self.invalid());
+                }
+            } else {
+                break;
+            }
+        }
+        if values.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(
+            BigUint::from_radix_be(&values, radix).expect("digits are below the radix"),
+        ))
+    }
+
+    /// A run of decimal digits, or `None` if the next byte is not a digit.
+    /// Single underscores between digits are skipped, as for strings.
+    pub(crate) fn digits(&mut self) -> io::Result<Option<BigUint>> {
+        self.decimal_digits(false)
+    }
+
+    /// [`digits`](Self::digits), continuing after a `0` already consumed
+    /// if `leading_zero` is set.
+    fn decimal_digits(&mut self, leading_zero: bool) -> io::Result<Option<BigUint>> {
+        let mut limbs = Vec::new();
+        let (mut word, mut count) = (0u64, 0u32);
+        let mut any = leading_zero;
+        loop {
+            let b = match self.peek()? {
+                Some(b) if b.is_ascii_digit() => b,
+                Some(b'_') if any => {
+                    self.bump();
+                    if !self.peek()?.is_some_and(|b| b.is_ascii_digit()) {
+                        return Err(self.invalid());
+                    }
+                    continue;
+                }
+                _ => break,
+            };
+            self.bump();
+            any = true;
+            word = word * 10 + u64::from(b - b'0');
+            count += 1;
+            if count == DECIMAL_LIMB_DIGITS {
+                limbs.push(word);
+                (word, count) = (0, 0);
+            }
+        }
+        if !any {
+            return Ok(None);
+        }
+        let mut powers = vec![BigUint::from(DECIMAL_LIMB)];
+        while limbs.len() > 1 << powers.len() {
+            let last = powers.last().expect("powers is never empty");
+            powers.push(last * last);
+        }
+        let value = combine_decimal_limbs(&limbs, &powers);
+        Ok(Some(value * 10u64.pow(count) + word))
+    }
+
+    /// A parse error at the current position, or the read error hit while
+    /// looking at it.
+    pub(crate) fn invalid(&mut self) -> io::Error {
+        let found = match self.peek() {
+            Ok(b) => b.map(char::from),
+            Err(e) => return e,
+        };
+        let offset = usize::try_from(self.offset).unwrap_or(usize::MAX);
+        let error = if self.gauss {
+            Error::ParseGaussInt(ParseGaussIntError::new(offset, found))
+        } else {
+            Error::ParseBigInt(ParseBigIntError::new(offset, found))
+        };
+        io::Error::new(io::ErrorKind::InvalidData, error)
+    }
+}
+
+/// The value of base-`10^19` limbs, most significant first, where
+/// `powers[k] = 10^(19·2^k)` covers every split. The low half of each
+/// split is a power-of-two number of limbs so the powers are shared.
+fn combine_decimal_limbs(limbs: &[u64], powers: &[BigUint]) -> BigUint {
+    if limbs.len() <= 16 {
+        return limbs
+            .iter()
+            .fold(BigUint::zero(), |acc, &limb| acc * DECIMAL_LIMB + limb);
+    }
+    let k = (usize::BITS - 1 - (limbs.len() - 1).leading_zeros()) as usize;
+    let (high, low) = limbs.split_at(limbs.len() - (1 << k));
+    combine_decimal_limbs(high, powers) * &powers[k] + combine_decimal_limbs(low, powers)
+}
+
+// --- Fallible variants ---
+
+/// Parses an integer as [`BigInt::try_from_string`] does, failing with
+/// [`Error::ParseBigInt`].
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::{BigInt, Error};
+///
+/// let n: BigInt = "-12345678901234567890".parse().unwrap();
+/// assert_eq!(n.to_string(), "-12345678901234567890");
+/// let Err(Error::ParseBigInt(e)) = "12x".parse::<BigInt>() else { panic!() };
+/// assert_eq!((e.offset(), e.found()), (2, Some('x')));
+/// ```
+impl std::str::FromStr for BigInt {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(BigInt::try_from_string(s)?)
+    }
+}
+
+impl BigInt {
+    /// Like [`sqrt`](Self::sqrt), but fails with [`Error::NegativeSqrt`].
+    pub fn try_sqrt(&self) -> Result<Self, Error> {
+        self.sqrt().ok_or(Error::NegativeSqrt)
+    }
+
+    /// Like [`mod_inv`](Self::mod_inv), but says why no inverse exists.
+    ///
+    /// Fails with [`Error::DivisionByZero`] if `modulus` is zero and with
+    /// [`Error::DomainError`] if this number shares a factor with it.
+    pub fn try_mod_inv(&self, modulus: &Self) -> Result<Self, Error> {
+        if modulus.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        self.mod_inv(modulus).ok_or(Error::DomainError(
+            "not invertible modulo the given modulus",
+        ))
+    }
+
+    /// Like [`factorial`](Self::factorial), but fails with
+    /// [`Error::DomainError`].
+    pub fn try_factorial(&self) -> Result<Self, Error> {
+        if self.is_negative() {
+            return Err(Error::DomainError("factorial of a negative number"));
+        }
+        self.factorial()
+            .ok_or(Error::DomainError("factorial argument too large"))
+    }
+
+    /// Like [`div_rem`](Self::div_rem), but fails with
+    /// [`Error::DivisionByZero`] instead of panicking.
+    pub fn try_div_rem(&self, other: &Self) -> Result<(Self, Self), Error> {
+        self.checked_div_rem(other).ok_or(Error::DivisionByZero)
+    }
+
+    /// Divides by `other`, failing with [`Error::NotExactlyDivisible`] unless
+    /// the remainder is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{BigInt, Error};
+    ///
+    /// assert_eq!(BigInt::new(84).div_exact(&BigInt::new(-7)), Ok(BigInt::new(-12)));
+    /// assert_eq!(BigInt::new(85).div_exact(&BigInt::new(7)), Err(Error::NotExactlyDivisible));
+    /// assert_eq!(BigInt::new(85).div_exact(&BigInt::ZERO), Err(Error::DivisionByZero));
+    /// ```
+    pub fn div_exact(&self, other: &Self) -> Result<Self, Error> {
+        let (q, r) = self.try_div_rem(other)?;
+        if r.is_zero() {
+            Ok(q)
+        } else {
+            Err(Error::NotExactlyDivisible)
+        }
+    }
+}
+
+// --- Floating-point conversion ---
+
+/// How [`BigInt::from_f64`] and
+/// [`GaussInt::div_rounded`](crate::GaussInt::div_rounded) round a value
+/// with a fractional part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Toward −∞.
+    Floor,
+    /// Toward +∞.
+    Ceil,
+    /// Toward zero, discarding the fractional part.
+    TowardZero,
+    /// To the nearest integer, ties to even.
+    NearestEven,
+    /// To the nearest integer, ties away from zero, like [`f64::round`].
+    NearestAway,
+}
+
+/// Whether [`BigInt::to_f64`] represented the value exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Exactness {
+    /// The `f64` equals the integer.
+    Exact,
+    /// The integer needs more than 53 significant bits; the `f64` is the
+    /// nearest representable value, ties to even.
+    Inexact,
+    /// The integer lies beyond `f64::MAX`; the `f64` is an infinity of the
+    /// same sign.
+    Overflow,
+}
+
+impl BigInt {
+    /// Converts a finite `f64` to an integer, rounding any fractional part
+    /// as `mode` says.
+    ///
+    /// Fails with [`Error::DomainError`] for NaN and the infinities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{BigInt, RoundingMode};
+    ///
+    /// assert_eq!(BigInt::from_f64(-2.5, RoundingMode::Floor), Ok(BigInt::new(-3)));
+    /// assert_eq!(BigInt::from_f64(-2.5, RoundingMode::NearestEven), Ok(BigInt::new(-2)));
+    /// assert_eq!(BigInt::from_f64(1e20, RoundingMode::TowardZero).unwrap().to_string(), "100000000000000000000");
+    /// assert!(BigInt::from_f64(f64::NAN, RoundingMode::Floor).is_err());
+    /// ```
+    pub fn from_f64(x: f64, mode: RoundingMode) -> Result<Self, Error> {
+        if x.is_nan() {
+            return Err(Error::DomainError("cannot convert NaN to an integer"));
+        }
+        if x.is_infinite() {
+            return Err(Error::DomainError(
+                "cannot convert an infinity to an integer",
+            ));
+        }
+        // x = ±mantissa · 2^exponent
+        let bits = x.to_bits();
+        let negative = bits >> 63 == 1;
+        let biased = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased - 1075)
+        };
+        let magnitude = if exponent >= 0 {
+            BigInt::from(NumBigInt::from(mantissa) << exponent as usize)
+        } else {
+            let shift = exponent.unsigned_abs();
+            // The mantissa has at most 53 bits, so beyond 60 the integer part
+            // is zero and the fraction below one half
+            let (int_part, rem, half) = if shift > 60 {
+                (0, mantissa, u64::MAX)
+            } else {
+                let rem = mantissa & ((1 << shift) - 1);
+                (mantissa >> shift, rem, 1 << (shift - 1))
+            };
+            let round_up = match mode {
+                RoundingMode::TowardZero => false,
+                RoundingMode::Floor => negative && rem != 0,
+                RoundingMode::Ceil => !negative && rem != 0,
+                RoundingMode::NearestEven => rem > half || (rem == half && int_part % 2 == 1),
+                RoundingMode::NearestAway => rem >= half,
+            };
+            BigInt::new((int_part + u64::from(round_up)) as i64)
+        };
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+
+    /// Converts to the nearest `f64`, ties to even, reporting whether the
+    /// result is exact.
+    ///
+    /// Values beyond `f64::MAX` give an infinity with
+    /// [`Exactness::Overflow`] rather than a silently wrong finite number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{BigInt, Exactness};
+    ///
+    /// assert_eq!(BigInt::new(-12).to_f64(), (-12.0, Exactness::Exact));
+    /// assert_eq!(BigInt::new((1 << 53) + 1).to_f64(), (9007199254740992.0, Exactness::Inexact));
+    /// let huge = BigInt::new(10).pow(400);
+    /// assert_eq!(huge.to_f64(), (f64::INFINITY, Exactness::Overflow));
+    /// ```
+    pub fn to_f64(&self) -> (f64, Exactness) {
+        let value = match self.small() {
+            Some(v) => v as f64,
+            None => self.big().to_f64().unwrap_or(f64::NAN),
+        };
+        let exactness = if value.is_infinite() {
+            Exactness::Overflow
+        } else if self.is_zero() || self.bits() - self.big().trailing_zeros().unwrap_or(0) <= 53 {
+            Exactness::Exact
+        } else {
+            Exactness::Inexact
+        };
+        (value, exactness)
+    }
+}
+
+// --- Constant-time helpers ---
+
+impl BigInt {
+    /// Compares for equality without exiting early on the first differing
+    /// limb, so the running time depends only on the operands' lengths.
+    ///
+    /// This is best effort: it avoids the data-dependent early exit of `==`,
+    /// but the compiler and the length of each value are outside its control.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let secret = BigInt::from_string("123456789012345678901234567890").unwrap();
+    /// assert!(secret.ct_eq(&secret.clone()));
+    /// assert!(!secret.ct_eq(&-&secret));
+    /// ```
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let len = self.limb_count().max(other.limb_count());
+        let a = self.limbs_u64().chain(std::iter::repeat(0)).take(len);
+        let b = other.limbs_u64().chain(std::iter::repeat(0)).take(len);
+        let mut diff = u64::from(self.sign() as u8 ^ other.sign() as u8);
+        for (a, b) in a.zip(b) {
+            diff |= a ^ b;
+        }
+        std::hint::black_box(diff) == 0
+    }
+
+    /// Modular exponentiation by a Montgomery ladder: for each of
+    /// `max(exp.bits(), modulus.bits())` exponent bits it performs one
+    /// multiplication and one squaring, whatever the bit's value.
+    ///
+    /// Agrees with [`mod_pow`](Self::mod_pow). This is a best-effort mode
+    /// for prototyping: the uniform operation sequence hides the exponent's
+    /// bit pattern from coarse timing, but the underlying big-number
+    /// arithmetic is not itself constant time.
+    ///
+    /// Panics if `exp` is negative or `modulus` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let (b, e, m) = (BigInt::new(7), BigInt::new(560), BigInt::new(561));
+    /// assert_eq!(b.mod_pow_ct(&e, &m), b.mod_pow(&e, &m));
+    /// ```
+    pub fn mod_pow_ct(&self, exp: &Self, modulus: &Self) -> Self {
+        assert!(!exp.is_negative(), "negative exponent");
+        assert!(modulus.is_positive(), "modulus must be positive");
+        let e = exp.big();
+        let mut r0 = BigInt::ONE.rem_euclid(modulus);
+        let mut r1 = self.rem_euclid(modulus);
+        for i in (0..e.bits().max(modulus.bits())).rev() {
+            let bit = e.bit(i);
+            if bit {
+                std::mem::swap(&mut r0, &mut r1);
+            }
+            r1 = (&r0 * &r1).rem_euclid(modulus);
+            r0 = (&r0 * &r0).rem_euclid(modulus);
+            if bit {
+                std::mem::swap(&mut r0, &mut r1);
+            }
+        }
+        r0
+    }
+
+    /// Number of 64-bit limbs in the magnitude; at least one.
+    fn limb_count(&self) -> usize {
+        match &self.repr {
+            Repr::Small(_) => 1,
+            Repr::Large(n) => n.magnitude().iter_u64_digits().len(),
+        }
+    }
+
+    /// The magnitude as little-endian 64-bit limbs, without allocating.
+    fn limbs_u64(&self) -> impl Iterator<Item = u64> + '_ {
+        let (small, large) = match &self.repr {
+            Repr::Small(v) => (Some(v.unsigned_abs()), None),
+            Repr::Large(n) => (None, Some(n.magnitude().iter_u64_digits())),
+        };
+        small.into_iter().chain(large.into_iter().flatten())
+    }
+}
+
+/// Overwrites the value with zeros before resetting it to zero.
+///
+/// Inline values are wiped by `zeroize` itself. `num-bigint` does not hand
+/// out its limb buffer, so heap values are overwritten through
+/// `assign_from_slice` and then passed to [`std::hint::black_box`], which
+/// keeps the compiler from discarding the stores as dead before the buffer
+/// is freed. That barrier is best-effort rather than the volatile writes
+/// `zeroize` uses, and spare capacity left over from earlier, larger values
+/// is not touched.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for BigInt {
+    fn zeroize(&mut self) {
+        match &mut self.repr {
+            Repr::Small(v) => v.zeroize(),
+            Repr::Large(_) => {
+                let (_, mut magnitude) = self.take_big().into_parts();
+                // Same length with a non-zero top limb, so normalization
+                // neither truncates nor reallocates: every limb of the
+                // original buffer is rewritten, all but the top one to zero.
+                let mut wipe = vec![0u32; magnitude.bits().div_ceil(32) as usize];
+                *wipe.last_mut().expect("heap values are non-zero") = 1;
+                magnitude.assign_from_slice(&wipe);
+                std::hint::black_box(&magnitude);
+                std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+            }
+        }
+    }
+}
+
+// --- Representation helpers ---
+
+impl BigInt {
+    /// Builds a `BigInt` from an `i128`, inline whenever it fits in an `i64`.
+    fn from_i128(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(small) => BigInt {
+                repr: Repr::Small(small),
+            },
+            Err(_) => BigInt {
+                repr: Repr::Large(NumBigInt::from(value)),
+            },
+        }
+    }
+
+    fn small(&self) -> Option<i64> {
+        match self.repr {
+            Repr::Small(v) => Some(v),
+            Repr::Large(_) => None,
+        }
+    }
+
+    /// Views the value as a `num_bigint::BigInt`, allocating only for
+    /// inline values.
+    fn big(&self) -> Cow<'_, NumBigInt> {
+        match &self.repr {
+            Repr::Small(v) => Cow::Owned(NumBigInt::from(*v)),
+            Repr::Large(n) => Cow::Borrowed(n),
+        }
+    }
+
+    fn into_big(self) -> NumBigInt {
+        self.repr.into_big()
+    }
+
+    /// Moves the value out as a `num_bigint::BigInt`, leaving zero behind,
+    /// so that a heap buffer can be reused in place.
+    fn take_big(&mut self) -> NumBigInt {
+        std::mem::replace(&mut self.repr, Repr::Small(0)).into_big()
+    }
+
+    /// Applies a binary operation, on `i128` when both operands are inline.
+    fn binary(
+        &self,
+        other: &Self,
+        small: fn(i128, i128) -> i128,
+        large: fn(&NumBigInt, &NumBigInt) -> NumBigInt,
+    ) -> Self {
+        match (self.small(), other.small()) {
+            (Some(a), Some(b)) => BigInt::from_i128(small(a.into(), b.into())),
+            _ => BigInt::from(large(&self.big(), &other.big())),
+        }
+    }
+
+    /// Like [`binary`](Self::binary), consuming the operands so that heap
+    /// buffers can be reused.
+    fn binary_owned(
+        self,
+        other: Self,
+        small: fn(i128, i128) -> i128,
+        large: fn(NumBigInt, NumBigInt) -> NumBigInt,
+    ) -> Self {
+        match (self.small(), other.small()) {
+            (Some(a), Some(b)) => BigInt::from_i128(small(a.into(), b.into())),
+            _ => BigInt::from(large(self.into_big(), other.into_big())),
+        }
+    }
+}
+
+impl Repr {
+    fn into_big(self) -> NumBigInt {
+        match self {
+            Repr::Small(v) => NumBigInt::from(v),
+            Repr::Large(n) => n,
+        }
     }
 }
 
@@ -292,57 +2249,286 @@ impl Rem for BigInt {
     type Output = Self;
 
     fn rem(self, other: Self) -> Self {
-        BigInt {
-            inner: self.inner % other.inner,
+        self.binary_owned(other, |a, b| a % b, |a, b| a % b)
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: Self) -> BigInt {
+        self.binary(other, |a, b| a % b, |a, b| a % b)
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        BigInt::new(value)
+    }
+}
+
+impl From<NumBigInt> for BigInt {
+    fn from(value: NumBigInt) -> Self {
+        match value.to_i64() {
+            Some(small) => BigInt {
+                repr: Repr::Small(small),
+            },
+            None => BigInt {
+                repr: Repr::Large(value),
+            },
+        }
+    }
+}
+
+impl From<BigInt> for NumBigInt {
+    fn from(value: BigInt) -> Self {
+        value.into_big()
+    }
+}
+
+impl BigInt {
+    /// Formats with custom options; only digit grouping applies to a
+    /// single integer. See [`FormatOptions`](crate::format::FormatOptions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    /// use gauss_int::format::FormatOptions;
+    ///
+    /// let opts = FormatOptions::new().group_digits(",", 3);
+    /// assert_eq!(BigInt::new(-1234567).format_with(&opts), "-1,234,567");
+    /// ```
+    pub fn format_with(&self, options: &crate::format::FormatOptions) -> String {
+        options.format_big_int(self)
+    }
+
+    /// Formats in scientific notation with `sig_figs` significant digits,
+    /// rounding ties away from zero. Only the leading digits are converted
+    /// to text, so this is cheap even for huge numbers.
+    ///
+    /// Panics if `sig_figs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let x = BigInt::from_string("123456789012345678901234567890").unwrap();
+    /// assert_eq!(x.to_scientific(5), "1.2346e+29");
+    /// ```
+    pub fn to_scientific(&self, sig_figs: u32) -> String {
+        crate::format::exponent_notation(self, sig_figs, 1)
+    }
+
+    /// Renders as LaTeX math-mode source.
+    pub fn to_latex(&self) -> String {
+        crate::format::FormatOptions::new().latex_big_int(self)
+    }
+
+    /// Renders as a presentation MathML `<math>` element, e.g.
+    /// `<math><mo>-</mo><mn>42</mn></math>`.
+    pub fn to_mathml(&self) -> String {
+        crate::format::FormatOptions::new().mathml_big_int(self)
+    }
+
+    /// Like [`to_scientific`](Self::to_scientific), but with the exponent
+    /// a multiple of three, e.g. `123.46e+27`.
+    pub fn to_engineering(&self, sig_figs: u32) -> String {
+        crate::format::exponent_notation(self, sig_figs, 3)
+    }
+
+    /// Spells the number out as American English cardinal words, using
+    /// short-scale names up to vigintillion (`10^63`), or `None` if
+    /// `|self| >= 10^66`.
+    ///
+    /// Tens and ones are hyphenated and no "and" is inserted; negative
+    /// numbers start with "negative".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// let n = BigInt::from_string("12000000000000000345").unwrap();
+    /// assert_eq!(
+    ///     n.to_words().unwrap(),
+    ///     "twelve quintillion three hundred forty-five"
+    /// );
+    /// assert_eq!(BigInt::new(-21).to_words().unwrap(), "negative twenty-one");
+    /// ```
+    pub fn to_words(&self) -> Option<String> {
+        crate::format::cardinal_words(self)
+    }
+
+    /// The canonical Roman numeral, such as `MCMXCIV`, failing with
+    /// [`Error::DomainError`] outside the classical range `1..=3999`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    ///
+    /// assert_eq!(BigInt::new(1994).to_roman().unwrap(), "MCMXCIV");
+    /// assert!(BigInt::new(4000).to_roman().is_err());
+    /// ```
+    pub fn to_roman(&self) -> Result<String, Error> {
+        crate::format::to_roman(self)
+    }
+
+    /// Parses a Roman numeral in either case, the inverse of
+    /// [`to_roman`](Self::to_roman). Only the canonical form is accepted,
+    /// so `IIII` and `IC` fail with [`Error::ParseBigInt`] at the first
+    /// symbol that does not fit.
+    pub fn from_roman(s: &str) -> Result<Self, Error> {
+        crate::format::from_roman(s)
+    }
+}
+
+/// Serializes as a raw JSON number rather than a string, relying on
+/// `serde_json`'s `arbitrary_precision` mode to carry every digit.
+#[cfg(feature = "serde-json")]
+impl serde::Serialize for BigInt {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let number: serde_json::Number = self
+            .to_string()
+            .parse()
+            .map_err(serde::ser::Error::custom)?;
+        serde::Serialize::serialize(&number, serializer)
+    }
+}
+
+/// Accepts any JSON integer losslessly; fractions and exponents are rejected.
+#[cfg(feature = "serde-json")]
+impl<'de> serde::Deserialize<'de> for BigInt {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let number: serde_json::Number = serde::Deserialize::deserialize(deserializer)?;
+        BigInt::from_string(number.as_str()).ok_or_else(|| {
+            serde::de::Error::custom(format!("expected an integer, found {}", number))
+        })
+    }
+}
+
+/// Archived form of a [`BigInt`] under the `rkyv` feature: a sign flag and
+/// the magnitude as little-endian `u32` limbs, usable in place without
+/// parsing.
+#[cfg(feature = "rkyv")]
+#[derive(Debug, PartialEq, Eq, rkyv::Portable, rkyv::bytecheck::CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[rkyv(crate = rkyv)]
+#[repr(C)]
+pub struct ArchivedBigInt {
+    limbs: rkyv::vec::ArchivedVec<rkyv::Archived<u32>>,
+    negative: bool,
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedBigInt {
+    /// Returns `true` if the archived value is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the magnitude as little-endian `u32` limbs, with no trailing
+    /// zero limbs; zero has none.
+    pub fn limbs(&self) -> &[rkyv::Archived<u32>] {
+        self.limbs.as_slice()
+    }
+
+    /// Copies the archived value out into a [`BigInt`].
+    pub fn to_big_int(&self) -> BigInt {
+        let limbs = self.limbs();
+        if limbs.len() <= 2 {
+            let magnitude = limbs
+                .iter()
+                .rev()
+                .fold(0u64, |acc, limb| (acc << 32) | u64::from(limb.to_native()));
+            let magnitude = i128::from(magnitude);
+            return BigInt::from_i128(if self.negative { -magnitude } else { magnitude });
         }
+        let sign = if self.negative {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        let digits = limbs.iter().map(|limb| limb.to_native()).collect();
+        BigInt::from(NumBigInt::from_biguint(sign, BigUint::new(digits)))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl PartialEq<BigInt> for ArchivedBigInt {
+    fn eq(&self, other: &BigInt) -> bool {
+        self.negative == (other.sign() == Sign::Minus)
+            && self
+                .limbs
+                .iter()
+                .map(|limb| limb.to_native())
+                .eq(other.big().magnitude().iter_u32_digits())
     }
 }
 
-impl Rem for &BigInt {
-    type Output = BigInt;
+#[cfg(feature = "rkyv")]
+impl rkyv::Archive for BigInt {
+    type Archived = ArchivedBigInt;
+    type Resolver = rkyv::vec::VecResolver;
 
-    fn rem(self, other: Self) -> BigInt {
-        BigInt {
-            inner: &self.inner % &other.inner,
-        }
+    fn resolve(&self, resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        rkyv::munge::munge!(let ArchivedBigInt { limbs, negative } = out);
+        let len = self.bits().div_ceil(32) as usize;
+        rkyv::vec::ArchivedVec::resolve_from_len(len, resolver, limbs);
+        rkyv::Archive::resolve(&(self.sign() == Sign::Minus), (), negative);
     }
 }
 
-impl From<i64> for BigInt {
-    fn from(value: i64) -> Self {
-        BigInt::new(value)
+#[cfg(feature = "rkyv")]
+impl<S> rkyv::Serialize<S> for BigInt
+where
+    S: rkyv::rancor::Fallible + rkyv::ser::Allocator + rkyv::ser::Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let limbs = match &self.repr {
+            Repr::Small(v) => {
+                let magnitude = v.unsigned_abs();
+                let mut limbs = vec![magnitude as u32, (magnitude >> 32) as u32];
+                limbs.truncate(self.bits().div_ceil(32) as usize);
+                limbs
+            }
+            Repr::Large(n) => n.magnitude().to_u32_digits(),
+        };
+        rkyv::vec::ArchivedVec::serialize_from_slice(&limbs, serializer)
     }
 }
 
-impl From<NumBigInt> for BigInt {
-    fn from(value: NumBigInt) -> Self {
-        BigInt { inner: value }
+#[cfg(feature = "rkyv")]
+impl<D: rkyv::rancor::Fallible + ?Sized> rkyv::Deserialize<BigInt, D> for ArchivedBigInt {
+    fn deserialize(&self, _: &mut D) -> Result<BigInt, D::Error> {
+        Ok(self.to_big_int())
     }
 }
 
 impl fmt::Display for BigInt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.inner)
+        match &self.repr {
+            Repr::Small(v) => write!(f, "{}", v),
+            Repr::Large(n) => write!(f, "{}", n),
+        }
     }
 }
 
 impl Zero for BigInt {
     fn zero() -> Self {
-        BigInt {
-            inner: NumBigInt::zero(),
-        }
+        BigInt::ZERO
     }
 
     fn is_zero(&self) -> bool {
-        self.inner.is_zero()
+        BigInt::is_zero(self)
     }
 }
 
 impl One for BigInt {
     fn one() -> Self {
-        BigInt {
-            inner: NumBigInt::one(),
-        }
+        BigInt::ONE
     }
 }
 
@@ -350,9 +2536,7 @@ impl Add for BigInt {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        BigInt {
-            inner: self.inner + other.inner,
-        }
+        self.binary_owned(other, |a, b| a + b, |a, b| a + b)
     }
 }
 
@@ -360,9 +2544,7 @@ impl Add for &BigInt {
     type Output = BigInt;
 
     fn add(self, other: Self) -> BigInt {
-        BigInt {
-            inner: &self.inner + &other.inner,
-        }
+        self.binary(other, |a, b| a + b, |a, b| a + b)
     }
 }
 
@@ -370,9 +2552,7 @@ impl Sub for BigInt {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        BigInt {
-            inner: self.inner - other.inner,
-        }
+        self.binary_owned(other, |a, b| a - b, |a, b| a - b)
     }
 }
 
@@ -380,9 +2560,7 @@ impl Sub for &BigInt {
     type Output = BigInt;
 
     fn sub(self, other: Self) -> BigInt {
-        BigInt {
-            inner: &self.inner - &other.inner,
-        }
+        self.binary(other, |a, b| a - b, |a, b| a - b)
     }
 }
 
@@ -390,9 +2568,7 @@ impl Mul for BigInt {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
-        BigInt {
-            inner: self.inner * other.inner,
-        }
+        self.binary_owned(other, |a, b| a * b, |a, b| a * b)
     }
 }
 
@@ -400,9 +2576,43 @@ impl Mul for &BigInt {
     type Output = BigInt;
 
     fn mul(self, other: Self) -> BigInt {
-        BigInt {
-            inner: &self.inner * &other.inner,
+        self.binary(other, |a, b| a * b, |a, b| a * b)
+    }
+}
+
+impl AddAssign<&BigInt> for BigInt {
+    fn add_assign(&mut self, other: &BigInt) {
+        if let (Some(a), Some(b)) = (self.small(), other.small()) {
+            *self = BigInt::from_i128(i128::from(a) + i128::from(b));
+            return;
+        }
+        let mut value = self.take_big();
+        value += &*other.big();
+        *self = BigInt::from(value);
+    }
+}
+
+impl SubAssign<&BigInt> for BigInt {
+    fn sub_assign(&mut self, other: &BigInt) {
+        if let (Some(a), Some(b)) = (self.small(), other.small()) {
+            *self = BigInt::from_i128(i128::from(a) - i128::from(b));
+            return;
+        }
+        let mut value = self.take_big();
+        value -= &*other.big();
+        *self = BigInt::from(value);
+    }
+}
+
+impl MulAssign<&BigInt> for BigInt {
+    fn mul_assign(&mut self, other: &BigInt) {
+        if let (Some(a), Some(b)) = (self.small(), other.small()) {
+            *self = BigInt::from_i128(i128::from(a) * i128::from(b));
+            return;
         }
+        let mut value = self.take_big();
+        value *= &*other.big();
+        *self = BigInt::from(value);
     }
 }
 
@@ -410,9 +2620,7 @@ impl Div for BigInt {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
-        BigInt {
-            inner: self.inner / other.inner,
-        }
+        self.binary_owned(other, |a, b| a / b, |a, b| a / b)
     }
 }
 
@@ -420,9 +2628,7 @@ impl Div for &BigInt {
     type Output = BigInt;
 
     fn div(self, other: Self) -> BigInt {
-        BigInt {
-            inner: &self.inner / &other.inner,
-        }
+        self.binary(other, |a, b| a / b, |a, b| a / b)
     }
 }
 
@@ -430,7 +2636,10 @@ impl Neg for BigInt {
     type Output = Self;
 
     fn neg(self) -> Self {
-        BigInt { inner: -self.inner }
+        match self.repr {
+            Repr::Small(v) => BigInt::from_i128(-i128::from(v)),
+            Repr::Large(n) => BigInt::from(-n),
+        }
     }
 }
 
@@ -438,12 +2647,119 @@ impl Neg for &BigInt {
     type Output = BigInt;
 
     fn neg(self) -> BigInt {
-        BigInt {
-            inner: -&self.inner,
+        match &self.repr {
+            Repr::Small(v) => BigInt::from_i128(-i128::from(*v)),
+            Repr::Large(n) => BigInt::from(-n),
+        }
+    }
+}
+
+impl BitAnd for BigInt {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.binary_owned(other, |a, b| a & b, |a, b| a & b)
+    }
+}
+
+impl BitAnd for &BigInt {
+    type Output = BigInt;
+
+    fn bitand(self, other: Self) -> BigInt {
+        self.binary(other, |a, b| a & b, |a, b| a & b)
+    }
+}
+
+impl BitOr for BigInt {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.binary_owned(other, |a, b| a | b, |a, b| a | b)
+    }
+}
+
+impl BitOr for &BigInt {
+    type Output = BigInt;
+
+    fn bitor(self, other: Self) -> BigInt {
+        self.binary(other, |a, b| a | b, |a, b| a | b)
+    }
+}
+
+impl BitXor for BigInt {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        self.binary_owned(other, |a, b| a ^ b, |a, b| a ^ b)
+    }
+}
+
+impl BitXor for &BigInt {
+    type Output = BigInt;
+
+    fn bitxor(self, other: Self) -> BigInt {
+        self.binary(other, |a, b| a ^ b, |a, b| a ^ b)
+    }
+}
+
+impl Not for &BigInt {
+    type Output = BigInt;
+
+    /// Returns `-self - 1`, the complement in infinite two's complement.
+    fn not(self) -> BigInt {
+        -self - BigInt::ONE
+    }
+}
+
+impl Not for BigInt {
+    type Output = BigInt;
+
+    fn not(self) -> BigInt {
+        !&self
+    }
+}
+
+impl Shl<u64> for &BigInt {
+    type Output = BigInt;
+
+    /// Returns `self·2^bits`.
+    fn shl(self, bits: u64) -> BigInt {
+        match self.small() {
+            Some(v) if bits < 64 => BigInt::from_i128(i128::from(v) << bits),
+            _ => BigInt::from(&*self.big() << bits),
+        }
+    }
+}
+
+impl Shl<u64> for BigInt {
+    type Output = BigInt;
+
+    fn shl(self, bits: u64) -> BigInt {
+        &self << bits
+    }
+}
+
+impl Shr<u64> for &BigInt {
+    type Output = BigInt;
+
+    /// Returns `⌊self / 2^bits⌋`, an arithmetic shift: negative values
+    /// round toward −∞.
+    fn shr(self, bits: u64) -> BigInt {
+        match self.small() {
+            Some(v) => BigInt::new(v >> bits.min(63)),
+            None => BigInt::from(&*self.big() >> bits),
         }
     }
 }
 
+impl Shr<u64> for BigInt {
+    type Output = BigInt;
+
+    fn shr(self, bits: u64) -> BigInt {
+        &self >> bits
+    }
+}
+
 impl PartialOrd for BigInt {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -452,7 +2768,10 @@ impl PartialOrd for BigInt {
 
 impl Ord for BigInt {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.inner.cmp(&other.inner)
+        match (&self.repr, &other.repr) {
+            (Repr::Small(a), Repr::Small(b)) => a.cmp(b),
+            _ => self.big().cmp(&other.big()),
+        }
     }
 }
 
@@ -570,4 +2889,782 @@ mod tests {
         assert_eq!(q2.to_string(), "-3");
         assert_eq!(r2.to_string(), "-2");
     }
+
+    #[test]
+    fn test_big_int_factorial_binary_splitting() {
+        // Compare against the plain iterative product.
+        let mut expected = BigInt::one();
+        for i in 1..=200 {
+            expected = expected * BigInt::new(i);
+        }
+        assert_eq!(BigInt::new(200).factorial().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_big_int_double_factorial() {
+        assert_eq!(BigInt::new(0).double_factorial().unwrap(), BigInt::new(1));
+        assert_eq!(BigInt::new(1).double_factorial().unwrap(), BigInt::new(1));
+        assert_eq!(BigInt::new(2).double_factorial().unwrap(), BigInt::new(2));
+        assert_eq!(BigInt::new(9).double_factorial().unwrap(), BigInt::new(945));
+        assert_eq!(
+            BigInt::new(10).double_factorial().unwrap(),
+            BigInt::new(3840)
+        );
+        assert_eq!(BigInt::new(-1).double_factorial(), None);
+    }
+
+    #[test]
+    fn test_big_int_rising_falling_factorial() {
+        let x = BigInt::new(5);
+        assert_eq!(x.rising_factorial(0), BigInt::new(1));
+        assert_eq!(x.rising_factorial(3), BigInt::new(210)); // 5·6·7
+        assert_eq!(x.falling_factorial(0), BigInt::new(1));
+        assert_eq!(x.falling_factorial(3), BigInt::new(60)); // 5·4·3
+        assert_eq!(x.falling_factorial(5), x.factorial().unwrap());
+        assert_eq!(x.falling_factorial(6), BigInt::new(0));
+
+        // x^(k) rising = (x+k-1)_k falling
+        let y = BigInt::new(-7);
+        assert_eq!(
+            y.rising_factorial(4),
+            (&y + &BigInt::new(3)).falling_factorial(4)
+        );
+    }
+
+    #[test]
+    fn test_big_int_sqrt_rem() {
+        for n in 0..200 {
+            let (s, r) = BigInt::new(n).sqrt_rem().unwrap();
+            assert_eq!(&(&s * &s) + &r, BigInt::new(n));
+            assert!(r >= BigInt::zero());
+            assert!(r <= &s + &s);
+        }
+        assert_eq!(BigInt::new(-1).sqrt_rem(), None);
+
+        let big = BigInt::from_string("123456789012345678901234567890").unwrap();
+        let square = &big * &big;
+        assert_eq!(square.sqrt_rem().unwrap(), (big.clone(), BigInt::zero()));
+        let (s, r) = (&square + &BigInt::new(5)).sqrt_rem().unwrap();
+        assert_eq!(s, big);
+        assert_eq!(r, BigInt::new(5));
+    }
+
+    #[test]
+    fn test_big_int_is_perfect_square() {
+        let squares: Vec<i64> = (0..20).map(|i| i * i).collect();
+        for n in 0..400 {
+            assert_eq!(BigInt::new(n).is_perfect_square(), squares.contains(&n));
+        }
+    }
+
+    #[test]
+    fn test_big_int_ilog() {
+        for n in 1..5000i64 {
+            let big = BigInt::new(n);
+            assert_eq!(big.ilog2(), Some(u64::from(n.ilog2())));
+            assert_eq!(big.ilog10(), Some(u64::from(n.ilog10())));
+            assert_eq!(big.ilog(&BigInt::new(7)), Some(u64::from(n.ilog(7))));
+        }
+        assert_eq!(BigInt::new(0).ilog10(), None);
+        assert_eq!(BigInt::new(-10).ilog10(), None);
+        assert_eq!(BigInt::new(10).ilog(&BigInt::new(0)), None);
+    }
+
+    #[test]
+    fn test_big_int_ilog_large() {
+        let ten = BigInt::new(10);
+        let p = ten.pow(500);
+        assert_eq!(p.ilog10(), Some(500));
+        assert_eq!((&p - &BigInt::one()).ilog10(), Some(499));
+        assert_eq!((&p + &BigInt::one()).ilog10(), Some(500));
+        assert_eq!(p.ilog2(), Some(1660));
+
+        let base = BigInt::from_string("123456789123456789").unwrap();
+        let q = base.pow(40);
+        assert_eq!(q.ilog(&base), Some(40));
+        assert_eq!((&q - &BigInt::one()).ilog(&base), Some(39));
+    }
+
+    #[test]
+    fn test_big_int_division_variants() {
+        // (a, b, floor, ceil, euclid, rem_euclid)
+        let cases = [
+            (7, 2, 3, 4, 3, 1),
+            (-7, 2, -4, -3, -4, 1),
+            (7, -2, -4, -3, -3, 1),
+            (-7, -2, 3, 4, 4, 1),
+            (6, 3, 2, 2, 2, 0),
+            (-6, 3, -2, -2, -2, 0),
+            (0, -5, 0, 0, 0, 0),
+        ];
+        for (a, b, floor, ceil, euclid, rem) in cases {
+            let (a, b) = (BigInt::new(a), BigInt::new(b));
+            assert_eq!(a.div_floor(&b), BigInt::new(floor), "{} div_floor {}", a, b);
+            assert_eq!(a.div_ceil(&b), BigInt::new(ceil), "{} div_ceil {}", a, b);
+            assert_eq!(
+                a.div_euclid(&b),
+                BigInt::new(euclid),
+                "{} div_euclid {}",
+                a,
+                b
+            );
+            assert_eq!(a.rem_euclid(&b), BigInt::new(rem), "{} rem_euclid {}", a, b);
+            assert_eq!(&(&a.div_euclid(&b) * &b) + &a.rem_euclid(&b), a);
+        }
+    }
+
+    #[test]
+    fn test_big_int_div_rem() {
+        let (q, r) = BigInt::new(-17).div_rem(&BigInt::new(5));
+        assert_eq!(q, BigInt::new(-3));
+        assert_eq!(r, BigInt::new(-2));
+        assert_eq!(BigInt::new(-17).div_mod(&BigInt::new(5)), (q, r));
+    }
+
+    #[test]
+    fn test_big_int_digit_utilities() {
+        let n = BigInt::from_string("-1234567890123456789").unwrap();
+        assert_eq!(n.num_digits(10), 19);
+        assert_eq!(n.digit_sum(), 90);
+        assert_eq!(n.digital_root(), 9);
+        assert_eq!(n.reverse_digits(10).to_string(), "-9876543210987654321");
+        assert!(!n.is_palindrome(10));
+    }
+
+    #[test]
+    fn test_big_int_digital_root_matches_iterated_sum() {
+        for i in 0..2000 {
+            let mut x = i as u64;
+            while x >= 10 {
+                x = BigInt::new(x as i64).digit_sum();
+            }
+            assert_eq!(u64::from(BigInt::new(i).digital_root()), x);
+        }
+    }
+
+    #[test]
+    fn test_big_int_num_digits_other_radices() {
+        assert_eq!(BigInt::new(1).num_digits(2), 1);
+        assert_eq!(BigInt::new(1024).num_digits(2), 11);
+        assert_eq!(BigInt::new(1295).num_digits(36), 2);
+        assert_eq!(BigInt::new(1296).num_digits(36), 3);
+    }
+
+    #[test]
+    fn test_big_int_palindromes() {
+        assert!(BigInt::new(0).is_palindrome(10));
+        assert!(BigInt::new(-44).is_palindrome(10));
+        assert!(BigInt::from_string("12345678987654321")
+            .unwrap()
+            .is_palindrome(10));
+        assert!(BigInt::new(0x1f1).is_palindrome(16));
+        assert_eq!(BigInt::new(0).reverse_digits(10), BigInt::zero());
+    }
+
+    #[test]
+    fn test_big_int_radix_digits_round_trip() {
+        let n = BigInt::from_string("98765432109876543210987654321098765432109876543210").unwrap();
+        for radix in [
+            2u64,
+            3,
+            10,
+            16,
+            255,
+            256,
+            257,
+            1000,
+            65_537,
+            4_294_967_295,
+            1 << 32,
+        ] {
+            let digits = n.to_radix_digits(radix);
+            assert!(digits.iter().all(|&d| u64::from(d) < radix));
+            assert_ne!(*digits.last().unwrap(), 0, "radix {}", radix);
+            assert_eq!(
+                BigInt::from_radix_digits(radix, &digits).unwrap(),
+                n,
+                "radix {}",
+                radix
+            );
+        }
+    }
+
+    #[test]
+    fn test_big_int_radix_digits_edge_cases() {
+        assert_eq!(BigInt::zero().to_radix_digits(1000), vec![0]);
+        assert_eq!(BigInt::zero().to_radix_digits(1 << 32), vec![0]);
+        assert_eq!(BigInt::new(-255).to_radix_digits(16), vec![15, 15]);
+        assert_eq!(BigInt::new(1 << 40).to_radix_digits(1 << 32), vec![0, 256]);
+        assert_eq!(BigInt::from_radix_digits(7, &[]).unwrap(), BigInt::zero());
+        assert_eq!(
+            BigInt::from_radix_digits(1000, &[5, 0, 0, 0]).unwrap(),
+            BigInt::new(5)
+        );
+        assert!(BigInt::from_radix_digits(1, &[0]).is_none());
+        assert!(BigInt::from_radix_digits((1 << 32) + 1, &[0]).is_none());
+    }
+
+    #[test]
+    fn test_big_int_inline_promotion_and_demotion() {
+        let max = BigInt::new(i64::MAX);
+        let min = BigInt::new(i64::MIN);
+        let one = BigInt::one();
+        let past_max = &max + &one;
+        assert!(past_max.small().is_none());
+        assert_eq!(past_max.to_string(), "9223372036854775808");
+        // Results back in range are stored inline again
+        assert_eq!((&past_max - &one).small(), Some(i64::MAX));
+        assert_eq!(&past_max - &one, max);
+        assert_eq!((-&min).to_string(), "9223372036854775808");
+        assert_eq!(-(-&min), min);
+        assert_eq!(min.abs(), past_max);
+        assert_eq!((&min / &BigInt::new(-1)), past_max);
+        assert_eq!(&min % &BigInt::new(-1), BigInt::zero());
+        assert_eq!(
+            (&max * &max).to_string(),
+            "85070591730234615847396907784232501249"
+        );
+        assert_eq!(min.bits(), 64);
+        assert_eq!(min.gcd(&BigInt::zero()), past_max);
+
+        let mut acc = max.clone();
+        acc += &one;
+        assert_eq!(acc, past_max);
+        acc -= &one;
+        assert_eq!(acc.small(), Some(i64::MAX));
+        acc *= &BigInt::new(4);
+        assert_eq!(acc, &max * &BigInt::new(4));
+
+        let huge = BigInt::from_string("-123456789012345678901234567890").unwrap();
+        assert!(huge < min && min < max && max < past_max);
+        assert_eq!(huge.sign(), Sign::Minus);
+        let (q, r) = huge.div_rem(&BigInt::new(1_000_000_007));
+        assert_eq!(&(&q * &BigInt::new(1_000_000_007)) + &r, huge);
+        assert_eq!(BigInt::from(NumBigInt::from(5)).small(), Some(5));
+        assert_eq!(
+            NumBigInt::from(past_max.clone()),
+            NumBigInt::from(i64::MAX) + 1
+        );
+    }
+
+    #[cfg(feature = "serde-json")]
+    #[test]
+    fn test_json_number_round_trip() {
+        let huge = BigInt::from_string("-123456789012345678901234567890").unwrap();
+        let json = serde_json::to_string(&huge).unwrap();
+        assert_eq!(json, "-123456789012345678901234567890");
+        assert_eq!(serde_json::from_str::<BigInt>(&json).unwrap(), huge);
+
+        let values: Vec<BigInt> = serde_json::from_str("[0, 42, -7]").unwrap();
+        assert_eq!(values, vec![BigInt::ZERO, BigInt::new(42), BigInt::new(-7)]);
+        assert!(serde_json::from_str::<BigInt>("1.5").is_err());
+        assert!(serde_json::from_str::<BigInt>("\"12\"").is_err());
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_round_trip() {
+        use rkyv::rancor::Error;
+
+        let values = vec![
+            BigInt::ZERO,
+            BigInt::new(-1),
+            BigInt::new(i64::MIN),
+            BigInt::new(i64::MAX),
+            &BigInt::new(i64::MAX) + &BigInt::ONE,
+            BigInt::from_string("-123456789012345678901234567890").unwrap(),
+        ];
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+        let archived =
+            rkyv::access::<rkyv::vec::ArchivedVec<ArchivedBigInt>, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), values.len());
+        for (a, v) in archived.iter().zip(&values) {
+            assert_eq!(a, v);
+            assert_eq!(a.to_big_int(), *v);
+            assert_eq!(a.is_negative(), v.sign() == Sign::Minus);
+        }
+        assert!(archived[0].limbs().is_empty());
+        assert_eq!(
+            rkyv::deserialize::<Vec<BigInt>, Error>(archived).unwrap(),
+            values
+        );
+    }
+
+    #[test]
+    fn test_signed_bytes_round_trip() {
+        let huge = BigInt::from_string("-123456789012345678901234567890").unwrap();
+        for n in [
+            BigInt::ZERO,
+            BigInt::new(-1),
+            BigInt::new(127),
+            BigInt::new(-128),
+            BigInt::new(i64::MIN),
+            huge.clone(),
+            -huge,
+        ] {
+            let be = n.to_signed_bytes_be();
+            let le = n.to_signed_bytes_le();
+            assert_eq!(le.iter().rev().copied().collect::<Vec<_>>(), be);
+            assert_eq!(BigInt::from_signed_bytes_be(&be), n);
+            assert_eq!(BigInt::from_signed_bytes_le(&le), n);
+        }
+        assert_eq!(BigInt::new(-1).to_signed_bytes_le(), [0xff]);
+        assert_eq!(
+            BigInt::from_signed_bytes_be(&[0xff, 0xff, 0xfe]),
+            BigInt::new(-2)
+        );
+        assert_eq!(BigInt::from_signed_bytes_le(&[]), BigInt::ZERO);
+    }
+
+    #[test]
+    fn test_ct_eq_matches_eq() {
+        let huge = BigInt::from_string("123456789012345678901234567890").unwrap();
+        let values = [
+            BigInt::ZERO,
+            BigInt::ONE,
+            BigInt::new(-1),
+            BigInt::new(i64::MIN),
+            huge.clone(),
+            -&huge,
+            &huge + &BigInt::ONE,
+        ];
+        for a in &values {
+            for b in &values {
+                assert_eq!(a.ct_eq(b), a == b, "{} vs {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_pow_ct_matches_mod_pow() {
+        let p = BigInt::from_string("170141183460469231731687303715884105727").unwrap();
+        let cases = [
+            (BigInt::new(7), BigInt::new(560), BigInt::new(561)),
+            (BigInt::new(-3), BigInt::new(5), BigInt::new(7)),
+            (BigInt::new(5), BigInt::ZERO, BigInt::ONE),
+            (BigInt::new(2), &p - &BigInt::ONE, p.clone()),
+            (&p + &BigInt::new(5), BigInt::new(65537), p),
+        ];
+        for (b, e, m) in &cases {
+            assert_eq!(b.mod_pow_ct(e, m), b.mod_pow(e, m));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be positive")]
+    fn test_mod_pow_ct_zero_modulus() {
+        BigInt::new(2).mod_pow_ct(&BigInt::new(3), &BigInt::ZERO);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut small = BigInt::new(-42);
+        small.zeroize();
+        assert_eq!(small, BigInt::ZERO);
+        let mut large = BigInt::from_string("-123456789012345678901234567890").unwrap();
+        large.zeroize();
+        assert_eq!(large, BigInt::ZERO);
+        assert_eq!(large.small(), Some(0));
+    }
+
+    #[test]
+    fn test_scalar_helpers() {
+        let huge = BigInt::from_string("123456789012345678901234567890").unwrap();
+        let neg = -&huge;
+        assert_eq!(
+            (neg.signum(), BigInt::ZERO.signum(), huge.signum()),
+            (-1, 0, 1)
+        );
+        assert_eq!(huge.abs_diff(&neg), &huge + &huge);
+        assert_eq!(neg.abs_diff(&huge), &huge + &huge);
+        assert_eq!(
+            BigInt::new(i64::MIN).abs_diff(&BigInt::new(i64::MAX)),
+            BigInt::from_string("18446744073709551615").unwrap()
+        );
+
+        assert!(std::ptr::eq(huge.min_ref(&neg), &neg));
+        assert!(std::ptr::eq(huge.max_ref(&neg), &huge));
+        let tie = huge.clone();
+        assert!(std::ptr::eq(huge.min_ref(&tie), &huge));
+        assert!(std::ptr::eq(huge.max_ref(&tie), &huge));
+
+        let (lo, hi) = (BigInt::new(-10), BigInt::new(10));
+        assert_eq!(huge.clamp_ref(&lo, &hi), &hi);
+        assert_eq!(neg.clamp_ref(&lo, &hi), &lo);
+        assert_eq!(BigInt::new(3).clamp_ref(&lo, &hi), &BigInt::new(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "lo > hi")]
+    fn test_clamp_ref_inverted_bounds() {
+        BigInt::ZERO.clamp_ref(&BigInt::ONE, &BigInt::ZERO);
+    }
+
+    #[test]
+    fn test_checked_division() {
+        let huge = BigInt::from_string("-123456789012345678901234567890").unwrap();
+        let d = BigInt::new(1_000_000_007);
+        assert_eq!(huge.checked_div(&d), Some(&huge / &d));
+        assert_eq!(huge.checked_rem(&d), Some(&huge % &d));
+        assert_eq!(huge.checked_div_rem(&d), Some(huge.div_rem(&d)));
+        assert_eq!(huge.checked_div(&BigInt::ZERO), None);
+        assert_eq!(BigInt::ONE.checked_rem(&BigInt::ZERO), None);
+        assert_eq!(BigInt::ZERO.checked_div_rem(&BigInt::ZERO), None);
+        // i64::MIN / -1 overflows i64 but not BigInt.
+        assert_eq!(
+            BigInt::new(i64::MIN).checked_div(&BigInt::new(-1)),
+            Some(-BigInt::new(i64::MIN))
+        );
+    }
+
+    #[test]
+    fn test_fallible_variants() {
+        use crate::Error;
+        assert_eq!("+42".parse::<BigInt>(), Ok(BigInt::new(42)));
+        assert_eq!(
+            "".parse::<BigInt>(),
+            Err(Error::ParseBigInt(ParseBigIntError::at("", 0)))
+        );
+        assert_eq!("0x1F".parse::<BigInt>(), Ok(BigInt::new(31)));
+        assert_eq!(BigInt::new(50).try_sqrt(), Ok(BigInt::new(7)));
+        assert_eq!(BigInt::new(-1).try_sqrt(), Err(Error::NegativeSqrt));
+        let m = BigInt::new(12);
+        assert_eq!(BigInt::new(5).try_mod_inv(&m), Ok(BigInt::new(5)));
+        assert!(matches!(
+            BigInt::new(4).try_mod_inv(&m),
+            Err(Error::DomainError(_))
+        ));
+        assert_eq!(
+            BigInt::new(5).try_mod_inv(&BigInt::ZERO),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(BigInt::new(4).try_factorial(), Ok(BigInt::new(24)));
+        assert!(matches!(
+            BigInt::new(-4).try_factorial(),
+            Err(Error::DomainError(_))
+        ));
+        assert_eq!(
+            BigInt::ONE.try_div_rem(&BigInt::ZERO),
+            Err(Error::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_f64_conversions() {
+        use RoundingMode::*;
+        let round = |x: f64, mode| BigInt::from_f64(x, mode).unwrap().to_string();
+        for (x, expected) in [
+            (2.5, ["2", "3", "2", "2", "3"]),
+            (-2.5, ["-3", "-2", "-2", "-2", "-3"]),
+            (3.5, ["3", "4", "3", "4", "4"]),
+            (-0.25, ["-1", "0", "0", "0", "0"]),
+            (-0.0, ["0", "0", "0", "0", "0"]),
+            (5e-324, ["0", "1", "0", "0", "0"]),
+            (7.0, ["7", "7", "7", "7", "7"]),
+        ] {
+            let got = [Floor, Ceil, TowardZero, NearestEven, NearestAway].map(|m| round(x, m));
+            assert_eq!(got, expected.map(String::from), "{}", x);
+        }
+        assert_eq!(round(-f64::MAX, Floor).len(), 310);
+        assert!(BigInt::from_f64(f64::NEG_INFINITY, Ceil).is_err());
+
+        for x in [0.0, -1.0, 1e15, -(2f64.powi(80)), f64::MAX] {
+            assert_eq!(
+                BigInt::from_f64(x, Floor).unwrap().to_f64(),
+                (x, Exactness::Exact)
+            );
+        }
+        let (v, e) = BigInt::new(i64::MAX).to_f64();
+        assert_eq!((v, e), (2f64.powi(63), Exactness::Inexact));
+        // Just above f64::MAX, but rounds down to it
+        let max = BigInt::from_f64(f64::MAX, Floor).unwrap();
+        assert_eq!(
+            (&max + &BigInt::ONE).to_f64(),
+            (f64::MAX, Exactness::Inexact)
+        );
+        assert_eq!(
+            (-BigInt::new(2).pow(1024)).to_f64(),
+            (f64::NEG_INFINITY, Exactness::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_zeckendorf() {
+        assert_eq!(BigInt::ZERO.zeckendorf(), Some(vec![]));
+        assert_eq!(BigInt::ONE.zeckendorf(), Some(vec![2]));
+        assert_eq!(BigInt::new(-1).zeckendorf(), None);
+        for n in 0..2000 {
+            let n = BigInt::new(n);
+            let indices = n.zeckendorf().unwrap();
+            assert!(indices.windows(2).all(|w| w[0] >= w[1] + 2));
+            assert!(indices.last().is_none_or(|&k| k >= 2));
+            assert_eq!(BigInt::from_zeckendorf(&indices), n);
+        }
+        let big = BigInt::new(10).pow(200) - BigInt::ONE;
+        let indices = big.zeckendorf().unwrap();
+        assert_eq!(BigInt::from_zeckendorf(&indices), big);
+        // F(k) − 1 = F(k−1) + F(k−3) + …, alternating down to 2 or 3
+        let fib = crate::combinatorics::fibonacci(30);
+        let expected: Vec<u64> = (2..=29).rev().step_by(2).collect();
+        assert_eq!((&fib - &BigInt::ONE).zeckendorf(), Some(expected));
+    }
+
+    #[test]
+    fn test_bitwise_operators() {
+        let big = BigInt::new(3).pow(50);
+        let n = -BigInt::new(12345);
+        for (a, b) in [(&big, &n), (&n, &BigInt::new(-99)), (&big, &(&big >> 7))] {
+            let (x, y) = (NumBigInt::from(a.clone()), NumBigInt::from(b.clone()));
+            assert_eq!(NumBigInt::from(a & b), &x & &y);
+            assert_eq!(NumBigInt::from(a | b), &x | &y);
+            assert_eq!(NumBigInt::from(a ^ b), &x ^ &y);
+            assert_eq!(NumBigInt::from(!a), !&x);
+        }
+        assert_eq!(BigInt::new(-7) >> 1, BigInt::new(-4));
+        assert_eq!(BigInt::new(-7) >> 200, BigInt::new(-1));
+        assert_eq!(&BigInt::new(i64::MIN) << 63, -BigInt::TWO.pow(126));
+        assert_eq!(&(&big << 100) >> 100, big);
+    }
+
+    #[test]
+    fn test_gray_code() {
+        let mut prev = BigInt::ZERO;
+        for n in 1..300 {
+            let gray = BigInt::new(n).to_gray().unwrap();
+            assert_eq!((&gray ^ &prev).count_ones(), Some(1));
+            assert_eq!(gray.from_gray(), Some(BigInt::new(n)));
+            prev = gray;
+        }
+        let big = BigInt::new(7).pow(300);
+        assert_eq!(big.to_gray().unwrap().from_gray(), Some(big));
+        assert_eq!(BigInt::new(-1).to_gray(), None);
+        assert_eq!(BigInt::new(-1).from_gray(), None);
+    }
+
+    #[test]
+    fn test_hamming_distance() {
+        let a = BigInt::TWO.pow(200) + BigInt::new(5);
+        assert_eq!(a.hamming_distance(&BigInt::ZERO), Some(3));
+        assert_eq!(BigInt::ZERO.hamming_distance(&a), Some(3));
+        assert_eq!(a.hamming_distance(&a), Some(0));
+        let b = -BigInt::TWO.pow(130);
+        // -2^130 is ...1100…0 and -1 is all ones: 130 low bits differ
+        assert_eq!(b.hamming_distance(&BigInt::new(-1)), Some(130));
+        assert_eq!(b.hamming_distance(&a), None);
+        for (x, y) in [(-5i64, -9i64), (i64::MIN, -1), (12, 1 << 40)] {
+            let expected = u64::from((x ^ y).count_ones());
+            assert_eq!(
+                BigInt::new(x).hamming_distance(&BigInt::new(y)),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_popcount_widths() {
+        let big = BigInt::TWO.pow(100) - BigInt::ONE;
+        assert_eq!(big.count_ones(), Some(100));
+        assert_eq!(BigInt::ZERO.count_ones(), Some(0));
+        assert_eq!((-&big).count_ones(), None);
+        assert_eq!(big.count_ones_twos_complement(64), 64);
+        assert_eq!(big.count_ones_twos_complement(0), 0);
+        // -(2^100 - 1) = ...1 000…0001 (99 zeros) in two's complement
+        assert_eq!((-&big).count_ones_twos_complement(100), 1);
+        assert_eq!((-&big).count_ones_twos_complement(110), 11);
+        assert_eq!((-&big).count_zeros(110), 99);
+        for x in [0i64, 5, -5, i64::MIN, i64::MAX, -1] {
+            assert_eq!(
+                BigInt::new(x).count_ones_twos_complement(64),
+                u64::from(x.count_ones())
+            );
+            assert_eq!(BigInt::new(x).count_zeros(64), u64::from(x.count_zeros()));
+        }
+    }
+
+    #[test]
+    fn test_fixed_width_bit_twiddling() {
+        for x in [
+            0u64,
+            1,
+            0b1011,
+            1 << 63,
+            u64::MAX - 5,
+            0x0123_4567_89ab_cdef,
+        ] {
+            let n = BigInt::from(NumBigInt::from(x));
+            let reversed = BigInt::from(NumBigInt::from(x.reverse_bits()));
+            assert_eq!(n.bit_reverse(64), reversed);
+            assert_eq!(n.leading_zeros(64), u64::from(x.leading_zeros()));
+        }
+        let big = BigInt::new(3).pow(90);
+        let width = big.bits() + 10;
+        assert_eq!(big.bit_reverse(width).bit_reverse(width), big);
+        assert_eq!(big.leading_zeros(width), 10);
+        assert_eq!(BigInt::new(-2).bit_reverse(5), BigInt::new(0b01111));
+        assert_eq!(BigInt::new(5).bit_reverse(0), BigInt::ZERO);
+        assert_eq!(BigInt::new(-1).leading_zeros(0), 0);
+    }
+
+    #[test]
+    fn test_u64_digits_round_trip() {
+        assert_eq!(BigInt::ZERO.to_u64_digits(), (Sign::NoSign, vec![]));
+        assert_eq!(BigInt::new(-7).to_u64_digits(), (Sign::Minus, vec![7]));
+        assert_eq!(
+            BigInt::new(i64::MIN).to_u64_digits(),
+            (Sign::Minus, vec![1 << 63])
+        );
+        for n in [
+            BigInt::new(3).pow(200),
+            -BigInt::new(7).pow(77),
+            BigInt::new(i64::MAX),
+        ] {
+            let (sign, digits) = n.to_u64_digits();
+            assert_eq!(n.big().to_u64_digits(), (sign, digits.clone()));
+            assert_eq!(BigInt::from_u64_digits(sign, &digits), n);
+        }
+        assert_eq!(
+            BigInt::from_u64_digits(Sign::Plus, &[5, 0, 0]),
+            BigInt::new(5)
+        );
+        assert_eq!(BigInt::from_u64_digits(Sign::NoSign, &[5]), BigInt::ZERO);
+        assert_eq!(BigInt::from_u64_digits(Sign::Minus, &[]), BigInt::ZERO);
+    }
+
+    #[test]
+    fn test_write_to_matches_display() {
+        let cases = [
+            BigInt::ZERO,
+            BigInt::new(i64::MIN),
+            BigInt::new(10).pow(256),
+            &BigInt::new(10).pow(256) - &BigInt::ONE,
+            -BigInt::new(10).pow(1024),
+            BigInt::new(10).pow(2000) + BigInt::new(42),
+            BigInt::new(7).pow(5000),
+        ];
+        for n in cases {
+            let mut text = String::new();
+            n.write_to(&mut text).unwrap();
+            assert_eq!(text, n.to_string());
+            let mut bytes = Vec::new();
+            n.write_to_io(&mut bytes).unwrap();
+            assert_eq!(bytes, n.to_string().into_bytes());
+        }
+    }
+
+    #[test]
+    fn test_write_to_io_reports_errors() {
+        let mut buf = [0u8; 100];
+        let err = BigInt::new(3)
+            .pow(1000)
+            .write_to_io(&mut &mut buf[..])
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    fn test_read_from_matches_parse() {
+        let long = format!("{}{}", "1234567890".repeat(700), "98765");
+        let prefixed = ["0x1F", "-0b101", "0o17", "+0xdead_beef", "0_7", "00"];
+        for text in ["0", "-0", "+17", "-9223372036854775808", &long]
+            .into_iter()
+            .chain(prefixed)
+        {
+            let parsed: BigInt = text.parse().unwrap();
+            assert_eq!(BigInt::read_from(text.as_bytes()).unwrap(), parsed);
+            let padded = format!("\t {}\r\n", text);
+            assert_eq!(BigInt::read_from(padded.as_bytes()).unwrap(), parsed);
+        }
+        // Chunk boundaries fall inside the digits of a 200,000-digit number
+        let n = BigInt::new(3).pow(420_000);
+        let text = n.to_string();
+        assert!(text.len() > 3 * READ_CHUNK);
+        assert_eq!(BigInt::read_from(text.as_bytes()).unwrap(), n);
+        // The prefix is split across two reads
+        let split = io::Read::chain("-0".as_bytes(), "xff\n".as_bytes());
+        assert_eq!(BigInt::read_from(split).unwrap(), BigInt::new(-255));
+        let split = io::Read::chain("0".as_bytes(), "12".as_bytes());
+        assert_eq!(BigInt::read_from(split).unwrap(), BigInt::new(12));
+    }
+
+    #[test]
+    fn test_read_from_rejects_malformed_text() {
+        for (text, offset) in [
+            ("", 0),
+            ("-", 1),
+            ("12 3", 3),
+            ("1__000", 2),
+            ("1_", 2),
+            ("_1", 0),
+            ("0x", 2),
+            ("0x_f", 2),
+            ("0b12", 3),
+            ("0X1", 1),
+        ] {
+            let err = BigInt::read_from(text.as_bytes()).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+            let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+            assert_eq!(
+                *inner,
+                Error::ParseBigInt(ParseBigIntError::at(text, offset))
+            );
+        }
+        let err = BigInt::read_from("12 3".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "invalid integer: unexpected '3' at byte 3");
+    }
+
+    #[test]
+    fn test_try_from_string_positions() {
+        assert_eq!(BigInt::try_from_string("+42"), Ok(BigInt::new(42)));
+        assert_eq!(BigInt::try_from_string("-0"), Ok(BigInt::ZERO));
+        assert_eq!(
+            BigInt::try_from_string("1_000_000_007"),
+            Ok(BigInt::new(1_000_000_007))
+        );
+        for (text, value) in [
+            ("0x1f", 0x1f),
+            ("-0xDeadBeef", -0xdead_beef),
+            ("+0o777", 0o777),
+            ("0b1010_1010", 0b1010_1010),
+            ("-0b0", 0),
+            ("007", 7),
+        ] {
+            assert_eq!(
+                BigInt::try_from_string(text),
+                Ok(BigInt::new(value)),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(
+            BigInt::read_from("-1_000_000_007\n".as_bytes()).unwrap(),
+            BigInt::new(-1_000_000_007)
+        );
+        for (text, offset, found) in [
+            ("", 0, None),
+            ("-", 1, None),
+            ("--5", 1, Some('-')),
+            (" 5", 0, Some(' ')),
+            ("5 ", 1, Some(' ')),
+            ("12.5", 2, Some('.')),
+            ("99€", 2, Some('€')),
+            ("_1", 0, Some('_')),
+            ("-_1", 1, Some('_')),
+            ("1_", 2, None),
+            ("1__0", 2, Some('_')),
+            ("1_x", 2, Some('x')),
+            ("0x", 2, None),
+            ("0xg", 2, Some('g')),
+            ("0b102", 4, Some('2')),
+            ("0x_ff", 2, Some('_')),
+            ("0X1", 1, Some('X')),
+            ("0o8", 2, Some('8')),
+        ] {
+            let err = BigInt::try_from_string(text).unwrap_err();
+            assert_eq!((err.offset(), err.found()), (offset, found), "{:?}", text);
+            assert_eq!(BigInt::from_string(text), None);
+        }
+    }
 }
@@ -0,0 +1,269 @@
+//! Non-negative integers.
+//!
+//! [`BigNat`] wraps a [`BigInt`] that is known to be `>= 0`, so operations
+//! that only make sense for naturals, such as square roots and factorials,
+//! need no `Option`. Subtraction is the one operation that can leave the
+//! naturals and is only offered as [`checked_sub`](BigNat::checked_sub) and
+//! [`abs_diff`](BigNat::abs_diff).
+
+use crate::error::{Error, ParseBigIntError};
+use crate::BigInt;
+use num_traits::{One, Zero};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Rem};
+use std::str::FromStr;
+
+/// An arbitrary-precision natural number, `0, 1, 2, …`.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::{BigInt, BigNat};
+///
+/// let n = BigNat::try_from(BigInt::new(150)).unwrap();
+/// assert_eq!(n.sqrt(), BigNat::from(12u64));
+/// assert_eq!(BigNat::from(5u64).factorial().to_string(), "120");
+/// assert!(BigNat::try_from(BigInt::new(-1)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BigNat(BigInt);
+
+impl BigNat {
+    pub const ZERO: BigNat = BigNat(BigInt::ZERO);
+    pub const ONE: BigNat = BigNat(BigInt::ONE);
+
+    /// Borrows the value as a [`BigInt`].
+    pub fn as_big_int(&self) -> &BigInt {
+        &self.0
+    }
+
+    /// Converts into a [`BigInt`] without copying.
+    pub fn into_big_int(self) -> BigInt {
+        self.0
+    }
+
+    /// Returns `true` for zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns the bit length, zero for zero.
+    pub fn bits(&self) -> u64 {
+        self.0.bits()
+    }
+
+    /// Returns `self^exp`.
+    pub fn pow(&self, exp: u32) -> Self {
+        BigNat(self.0.pow(exp))
+    }
+
+    /// The greatest common divisor, zero only if both are zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        BigNat(self.0.gcd(&other.0))
+    }
+
+    /// The least common multiple, zero if either is zero.
+    pub fn lcm(&self, other: &Self) -> Self {
+        BigNat(self.0.lcm(&other.0))
+    }
+
+    /// The integer square root `⌊√self⌋`.
+    pub fn sqrt(&self) -> Self {
+        BigNat(self.0.sqrt().expect("natural numbers have square roots"))
+    }
+
+    /// The integer square root `s` and remainder `r`, with `self = s² + r`.
+    pub fn sqrt_rem(&self) -> (Self, Self) {
+        let (s, r) = self
+            .0
+            .sqrt_rem()
+            .expect("natural numbers have square roots");
+        (BigNat(s), BigNat(r))
+    }
+
+    /// Returns `self!`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` exceeds `u64::MAX`; such a factorial could not be
+    /// stored anyway.
+    pub fn factorial(&self) -> Self {
+        BigNat(self.0.factorial().expect("factorial argument too large"))
+    }
+
+    /// Returns `self - other`, or `None` if that would be negative.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        (self >= other).then(|| BigNat(&self.0 - &other.0))
+    }
+
+    /// Returns `|self - other|`.
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        BigNat(self.0.abs_diff(&other.0))
+    }
+
+    /// Like `/`, but returns `None` instead of panicking when `other` is zero.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.0.checked_div(&other.0).map(BigNat)
+    }
+}
+
+impl TryFrom<BigInt> for BigNat {
+    type Error = Error;
+
+    /// Fails with [`Error::DomainError`] for negative values.
+    fn try_from(n: BigInt) -> Result<Self, Error> {
+        if n.is_negative() {
+            return Err(Error::DomainError("negative value is not a natural number"));
+        }
+        Ok(BigNat(n))
+    }
+}
+
+impl From<BigNat> for BigInt {
+    fn from(n: BigNat) -> Self {
+        n.0
+    }
+}
+
+impl From<u64> for BigNat {
+    fn from(n: u64) -> Self {
+        BigNat(BigInt::from(num_bigint::BigInt::from(n)))
+    }
+}
+
+impl From<u32> for BigNat {
+    fn from(n: u32) -> Self {
+        BigNat(BigInt::new(i64::from(n)))
+    }
+}
+
+impl FromStr for BigNat {
+    type Err = Error;
+
+    /// Parses as [`BigInt::try_from_string`] does, radix prefixes
+    /// included; a minus sign is rejected even for `-0`.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s.starts_with('-') {
+            return Err(Error::ParseBigInt(ParseBigIntError::at(s, 0)));
+        }
+        s.parse::<BigInt>().map(BigNat)
+    }
+}
+
+impl fmt::Display for BigNat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Zero for BigNat {
+    fn zero() -> Self {
+        BigNat::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl One for BigNat {
+    fn one() -> Self {
+        BigNat::ONE
+    }
+}
+
+impl Add for BigNat {
+    type Output = BigNat;
+
+    fn add(self, other: Self) -> BigNat {
+        BigNat(self.0 + other.0)
+    }
+}
+
+impl Add for &BigNat {
+    type Output = BigNat;
+
+    fn add(self, other: Self) -> BigNat {
+        BigNat(&self.0 + &other.0)
+    }
+}
+
+impl Mul for BigNat {
+    type Output = BigNat;
+
+    fn mul(self, other: Self) -> BigNat {
+        BigNat(self.0 * other.0)
+    }
+}
+
+impl Mul for &BigNat {
+    type Output = BigNat;
+
+    fn mul(self, other: Self) -> BigNat {
+        BigNat(&self.0 * &other.0)
+    }
+}
+
+impl Div for &BigNat {
+    type Output = BigNat;
+
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn div(self, other: Self) -> BigNat {
+        BigNat(&self.0 / &other.0)
+    }
+}
+
+impl Rem for &BigNat {
+    type Output = BigNat;
+
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn rem(self, other: Self) -> BigNat {
+        BigNat(&self.0 % &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nat(n: u64) -> BigNat {
+        BigNat::from(n)
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(nat(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(BigNat::try_from(BigInt::new(7)), Ok(nat(7)));
+        assert!(matches!(
+            BigNat::try_from(BigInt::new(-7)),
+            Err(Error::DomainError(_))
+        ));
+        assert_eq!(BigInt::from(nat(9)), BigInt::new(9));
+        assert_eq!("123".parse::<BigNat>(), Ok(nat(123)));
+        assert!("-0".parse::<BigNat>().is_err());
+        assert_eq!("0xff".parse::<BigNat>(), Ok(BigNat::from(255u32)));
+        assert_eq!("0b1010".parse::<BigNat>(), Ok(BigNat::from(10u32)));
+        assert!("-0x1".parse::<BigNat>().is_err());
+        assert!("x".parse::<BigNat>().is_err());
+    }
+
+    #[test]
+    fn test_natural_arithmetic() {
+        assert_eq!(nat(0).sqrt(), nat(0));
+        assert_eq!(nat(99).sqrt_rem(), (nat(9), nat(18)));
+        assert_eq!(nat(0).factorial(), nat(1));
+        assert_eq!(nat(20).factorial(), nat(2_432_902_008_176_640_000));
+        assert_eq!(nat(3).checked_sub(&nat(5)), None);
+        assert_eq!(nat(5).checked_sub(&nat(3)), Some(nat(2)));
+        assert_eq!(nat(3).abs_diff(&nat(5)), nat(2));
+        assert_eq!(&nat(17) / &nat(5), nat(3));
+        assert_eq!(&nat(17) % &nat(5), nat(2));
+        assert_eq!(nat(1).checked_div(&BigNat::ZERO), None);
+        assert_eq!(&(&nat(4) + &nat(6)) * &nat(3), nat(30));
+        assert_eq!(nat(12).gcd(&nat(18)), nat(6));
+    }
+}
@@ -0,0 +1,138 @@
+//! A Gaussian integer that memoizes its norm and magnitude.
+//!
+//! Algorithms that repeatedly compare the sizes of the same values, such as
+//! sorting by norm or nearest-point searches, otherwise recompute the same
+//! big squarings on every comparison.
+
+use crate::{BigInt, GaussInt};
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+
+/// A [`GaussInt`] with lazily computed, cached `norm` and `magnitude`.
+///
+/// The caches are filled on first use and cleared whenever the value is
+/// mutated through [`value_mut`](Self::value_mut) or [`set`](Self::set).
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::{BigInt, GaussInt};
+/// use gauss_int::cached::CachedGaussInt;
+///
+/// let mut z = CachedGaussInt::new(GaussInt::from_i64(3, 4));
+/// assert_eq!(z.norm(), &BigInt::new(25));
+/// assert_eq!(z.magnitude(), &BigInt::new(5));
+/// *z.value_mut() = GaussInt::from_i64(5, 12);
+/// assert_eq!(z.magnitude(), &BigInt::new(13));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CachedGaussInt {
+    value: GaussInt,
+    norm: OnceCell<BigInt>,
+    magnitude: OnceCell<BigInt>,
+}
+
+impl CachedGaussInt {
+    /// Wraps `value` with empty caches.
+    pub fn new(value: GaussInt) -> Self {
+        CachedGaussInt {
+            value,
+            norm: OnceCell::new(),
+            magnitude: OnceCell::new(),
+        }
+    }
+
+    /// Returns the wrapped value.
+    pub fn value(&self) -> &GaussInt {
+        &self.value
+    }
+
+    /// Returns mutable access to the value, clearing the cached results.
+    pub fn value_mut(&mut self) -> &mut GaussInt {
+        self.norm.take();
+        self.magnitude.take();
+        &mut self.value
+    }
+
+    /// Replaces the value, clearing the cached results.
+    pub fn set(&mut self, value: GaussInt) {
+        *self.value_mut() = value;
+    }
+
+    /// Unwraps the value, discarding the caches.
+    pub fn into_inner(self) -> GaussInt {
+        self.value
+    }
+
+    /// The norm `a² + b²`, computed once.
+    pub fn norm(&self) -> &BigInt {
+        self.norm.get_or_init(|| self.value.norm())
+    }
+
+    /// The magnitude `⌊√(a² + b²)⌋`, computed once from the cached norm.
+    pub fn magnitude(&self) -> &BigInt {
+        self.magnitude
+            .get_or_init(|| self.norm().sqrt().expect("norm is non-negative"))
+    }
+
+    /// Compares by norm, using the cached values.
+    pub fn cmp_norm(&self, other: &Self) -> Ordering {
+        self.norm().cmp(other.norm())
+    }
+}
+
+impl From<GaussInt> for CachedGaussInt {
+    fn from(value: GaussInt) -> Self {
+        CachedGaussInt::new(value)
+    }
+}
+
+impl PartialEq for CachedGaussInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for CachedGaussInt {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_values_match_direct_computation() {
+        let z = CachedGaussInt::new(GaussInt::from_i64(-7, 24));
+        assert_eq!(z.norm(), &BigInt::new(625));
+        assert_eq!(z.magnitude(), &BigInt::new(25));
+        // The second call returns the same cached value
+        assert!(std::ptr::eq(z.norm(), z.norm()));
+        let w = CachedGaussInt::from(GaussInt::from_i64(2, 3));
+        assert_eq!(w.magnitude(), &BigInt::new(3));
+        assert_eq!(z.cmp_norm(&w), Ordering::Greater);
+        assert_eq!(w.clone().into_inner(), GaussInt::from_i64(2, 3));
+    }
+
+    #[test]
+    fn test_mutation_invalidates_cache() {
+        let mut z = CachedGaussInt::new(GaussInt::from_i64(1, 1));
+        assert_eq!(z.norm(), &BigInt::new(2));
+        *z.value_mut() = GaussInt::from_i64(6, 8);
+        assert_eq!(z.norm(), &BigInt::new(100));
+        assert_eq!(z.magnitude(), &BigInt::new(10));
+        z.set(GaussInt::from_i64(0, -3));
+        assert_eq!(z.magnitude(), &BigInt::new(3));
+        assert_eq!(z, CachedGaussInt::new(GaussInt::from_i64(0, -3)));
+    }
+
+    #[test]
+    fn test_sort_by_cached_norm() {
+        let mut values: Vec<CachedGaussInt> = [(5, 5), (1, 0), (-3, 2), (0, 0)]
+            .iter()
+            .map(|&(a, b)| CachedGaussInt::new(GaussInt::from_i64(a, b)))
+            .collect();
+        values.sort_by(CachedGaussInt::cmp_norm);
+        let norms: Vec<BigInt> = values.iter().map(|z| z.norm().clone()).collect();
+        let expected: Vec<BigInt> = [0, 1, 13, 50].iter().map(|&n| BigInt::new(n)).collect();
+        assert_eq!(norms, expected);
+    }
+}
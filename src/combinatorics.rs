@@ -0,0 +1,409 @@
+//! Combinatorial number sequences on BigInt.
+//!
+//! Catalan, Bell and Stirling numbers, computed exactly with recurrence
+//! tables. These are intended for moderate `n` (a few thousand); the tables
+//! grow quadratically in `n`. Fibonacci numbers use fast doubling instead
+//! and take `O(log n)` multiplications, binomial and multinomial
+//! coefficients never form a factorial, and harmonic numbers are summed by
+//! binary splitting. Bernoulli numbers use the Akiyama–Tanigawa triangle.
+
+use crate::rational::BigRational;
+use crate::BigInt;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// The n-th Catalan number `C(2n, n) / (n + 1)`.
+///
+/// Uses the recurrence `C(k+1) = C(k)·2(2k+1)/(k+2)`, where every division
+/// is exact.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::combinatorics::catalan;
+///
+/// assert_eq!(catalan(0).to_string(), "1");
+/// assert_eq!(catalan(10).to_string(), "16796");
+/// ```
+pub fn catalan(n: u32) -> BigInt {
+    let mut c = BigInt::one();
+    for k in 0..i64::from(n) {
+        c = c * BigInt::new(2 * (2 * k + 1)) / BigInt::new(k + 2);
+    }
+    c
+}
+
+/// The n-th Bell number — the number of partitions of an n-element set.
+///
+/// Built row by row from the Bell triangle.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::combinatorics::bell;
+///
+/// assert_eq!(bell(0).to_string(), "1");
+/// assert_eq!(bell(5).to_string(), "52");
+/// ```
+pub fn bell(n: u32) -> BigInt {
+    let mut row = vec![BigInt::one()];
+    for _ in 0..n {
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(row[row.len() - 1].clone());
+        for value in &row {
+            let sum = &next[next.len() - 1] + value;
+            next.push(sum);
+        }
+        row = next;
+    }
+    row.swap_remove(0)
+}
+
+/// Unsigned Stirling number of the first kind `[n, k]` — the number of
+/// permutations of `n` elements with exactly `k` cycles.
+///
+/// The signed variant is `(-1)^(n-k)·[n, k]`.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::combinatorics::stirling_first;
+///
+/// assert_eq!(stirling_first(4, 2).to_string(), "11");
+/// assert_eq!(stirling_first(5, 0).to_string(), "0");
+/// ```
+pub fn stirling_first(n: u32, k: u32) -> BigInt {
+    stirling_table(n, k, |row, _| BigInt::new(i64::from(row)))
+}
+
+/// Stirling number of the second kind `{n, k}` — the number of ways to
+/// partition `n` elements into exactly `k` non-empty subsets.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::combinatorics::stirling_second;
+///
+/// assert_eq!(stirling_second(4, 2).to_string(), "7");
+/// assert_eq!(stirling_second(10, 3).to_string(), "9330");
+/// ```
+pub fn stirling_second(n: u32, k: u32) -> BigInt {
+    stirling_table(n, k, |_, col| BigInt::new(i64::from(col)))
+}
+
+/// Runs the shared Stirling recurrence `S(m+1, j) = S(m, j−1) + w(m, j)·S(m, j)`
+/// up to row `n`, keeping only columns `0..=k`.
+fn stirling_table(n: u32, k: u32, weight: impl Fn(u32, u32) -> BigInt) -> BigInt {
+    if k > n {
+        return BigInt::zero();
+    }
+    let width = k as usize + 1;
+    let mut row = vec![BigInt::zero(); width];
+    row[0] = BigInt::one();
+    for m in 0..n {
+        for j in (1..width).rev() {
+            let term = &weight(m, j as u32) * &row[j];
+            row[j] = &row[j - 1] + &term;
+        }
+        row[0] = BigInt::zero();
+    }
+    row.swap_remove(k as usize)
+}
+
+/// The binomial coefficient `C(n, k)`, or `0` if `k < 0` or `k > n`.
+///
+/// Multiplies in one factor at a time, `c ← c·(n−k+i)/i`, cancelling
+/// `gcd(c, i)` first so every intermediate stays below the result.
+/// Returns `None` if `n` is negative or `min(k, n − k)` exceeds `u64::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::combinatorics::binomial;
+/// use gauss_int::BigInt;
+///
+/// assert_eq!(binomial(&BigInt::new(10), &BigInt::new(3)), Some(BigInt::new(120)));
+/// assert_eq!(binomial(&BigInt::new(3), &BigInt::new(10)), Some(BigInt::new(0)));
+/// ```
+pub fn binomial(n: &BigInt, k: &BigInt) -> Option<BigInt> {
+    if n.is_negative() {
+        return None;
+    }
+    if k.is_negative() || k > n {
+        return Some(BigInt::zero());
+    }
+    let k = k.clone().min(n - k);
+    let steps = num_bigint::BigInt::from(k.clone()).to_u64()?;
+    let base = n - &k;
+    let mut c = BigInt::one();
+    for i in 1..=steps {
+        let i = BigInt::from(num_bigint::BigInt::from(i));
+        // c·(base + i) is divisible by i and c/g is coprime to i/g,
+        // so i/g divides base + i
+        let g = c.gcd(&i);
+        c = &(&c / &g) * &(&(&base + &i) / &(&i / &g));
+    }
+    Some(c)
+}
+
+/// The multinomial coefficient `(k₁ + k₂ + …)! / (k₁!·k₂!·…)`, the number
+/// of ways to split a set into labelled parts of the given sizes.
+///
+/// Computed as the product of binomials `C(k₁ + … + kᵢ, kᵢ)`, so no
+/// factorial is ever formed. Returns `None` if a part is negative or a
+/// binomial needs more than `u64::MAX` steps. The empty product is `1`.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::combinatorics::multinomial;
+/// use gauss_int::BigInt;
+///
+/// // MISSISSIPPI: 11! / (1!·4!·4!·2!)
+/// let parts = [1, 4, 4, 2].map(BigInt::new);
+/// assert_eq!(multinomial(&parts), Some(BigInt::new(34650)));
+/// ```
+pub fn multinomial(parts: &[BigInt]) -> Option<BigInt> {
+    let mut total = BigInt::zero();
+    let mut result = BigInt::one();
+    for k in parts {
+        if k.is_negative() {
+            return None;
+        }
+        total = &total + k;
+        result = &result * &binomial(&total, k)?;
+    }
+    Some(result)
+}
+
+/// The n-th Fibonacci number, with `F(0) = 0` and `F(1) = 1`.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::combinatorics::fibonacci;
+///
+/// assert_eq!(fibonacci(10).to_string(), "55");
+/// assert_eq!(fibonacci(100).to_string(), "354224848179261915075");
+/// ```
+pub fn fibonacci(n: u64) -> BigInt {
+    fibonacci_pair(n).0
+}
+
+/// Returns `(F(n), F(n+1))` by fast doubling:
+/// `F(2k) = F(k)·(2F(k+1) − F(k))` and `F(2k+1) = F(k)² + F(k+1)²`.
+pub fn fibonacci_pair(n: u64) -> (BigInt, BigInt) {
+    let (mut a, mut b) = (BigInt::zero(), BigInt::one());
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        let double = &a * &(&(&b + &b) - &a);
+        let double_plus_one = &(&a * &a) + &(&b * &b);
+        (a, b) = if (n >> bit) & 1 == 0 {
+            (double, double_plus_one)
+        } else {
+            let next = &double + &double_plus_one;
+            (double_plus_one, next)
+        };
+    }
+    (a, b)
+}
+
+/// The n-th harmonic number `H(n) = 1 + 1/2 + … + 1/n`, with `H(0) = 0`.
+///
+/// The sum is split in halves recursively, each half kept as an unreduced
+/// fraction `P/Q`, so operands stay balanced and only the final result is
+/// reduced. That final reduction works on numbers the size of `n!`, so the
+/// cost grows roughly quadratically: `n = 10⁴` takes tens of milliseconds,
+/// `n = 10⁵` a few seconds, and `n = 3·10⁵` about half a minute in a
+/// release build.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::combinatorics::harmonic;
+///
+/// assert_eq!(harmonic(4).to_string(), "25/12");
+/// assert_eq!(harmonic(0).to_string(), "0");
+/// ```
+pub fn harmonic(n: u64) -> BigRational {
+    if n == 0 {
+        return BigRational::zero();
+    }
+    let (p, q) = harmonic_split(1, n + 1);
+    BigRational::new(p, q).expect("denominator is a product of positive integers")
+}
+
+/// Returns `(P, Q)` with `P/Q = Σ 1/k` for `k` in `a..b`, `a < b`.
+fn harmonic_split(a: u64, b: u64) -> (BigInt, BigInt) {
+    if b - a == 1 {
+        return (BigInt::one(), BigInt::from(num_bigint::BigInt::from(a)));
+    }
+    let mid = a + (b - a) / 2;
+    let (p1, q1) = harmonic_split(a, mid);
+    let (p2, q2) = harmonic_split(mid, b);
+    (&(&p1 * &q2) + &(&p2 * &q1), &q1 * &q2)
+}
+
+/// The n-th Bernoulli number `Bₙ`, with the convention `B₁ = −1/2`.
+///
+/// Uses the Akiyama–Tanigawa algorithm, `O(n²)` rational operations;
+/// odd `n > 1` return zero immediately.
+///
+/// With this convention `Σ_{k=0}^{N−1} k^m = 1/(m+1) Σ_{j=0}^{m} C(m+1, j)·Bⱼ·N^{m+1−j}`.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::combinatorics::bernoulli;
+///
+/// assert_eq!(bernoulli(1).to_string(), "-1/2");
+/// assert_eq!(bernoulli(12).to_string(), "-691/2730");
+/// assert_eq!(bernoulli(13).to_string(), "0");
+/// ```
+pub fn bernoulli(n: u32) -> BigRational {
+    if n == 1 {
+        return BigRational::new(BigInt::new(-1), BigInt::TWO).expect("nonzero denominator");
+    }
+    if n % 2 == 1 {
+        return BigRational::zero();
+    }
+    let mut row: Vec<BigRational> = Vec::with_capacity(n as usize + 1);
+    for m in 0..=n {
+        row.push(
+            BigRational::new(BigInt::one(), BigInt::new(i64::from(m) + 1))
+                .expect("nonzero denominator"),
+        );
+        for j in (1..=m as usize).rev() {
+            let weight = BigRational::from_integer(BigInt::new(j as i64));
+            row[j - 1] = &weight * &(&row[j - 1] - &row[j]);
+        }
+    }
+    row.swap_remove(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalan_sequence() {
+        let expected = [1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862];
+        for (n, c) in expected.iter().enumerate() {
+            assert_eq!(catalan(n as u32), BigInt::new(*c));
+        }
+        assert_eq!(catalan(50).to_string(), "1978261657756160653623774456");
+    }
+
+    #[test]
+    fn test_bell_sequence() {
+        let expected = [1, 1, 2, 5, 15, 52, 203, 877, 4140, 21147];
+        for (n, b) in expected.iter().enumerate() {
+            assert_eq!(bell(n as u32), BigInt::new(*b));
+        }
+    }
+
+    #[test]
+    fn test_bell_is_row_sum_of_stirling_second() {
+        for n in 0..12u32 {
+            let sum = (0..=n)
+                .map(|k| stirling_second(n, k))
+                .fold(BigInt::zero(), |a, b| a + b);
+            assert_eq!(sum, bell(n));
+        }
+    }
+
+    #[test]
+    fn test_stirling_first_row_sum_is_factorial() {
+        for n in 0..12u32 {
+            let sum = (0..=n)
+                .map(|k| stirling_first(n, k))
+                .fold(BigInt::zero(), |a, b| a + b);
+            assert_eq!(sum, BigInt::new(i64::from(n)).factorial().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_stirling_edge_cases() {
+        assert_eq!(stirling_first(0, 0), BigInt::new(1));
+        assert_eq!(stirling_second(0, 0), BigInt::new(1));
+        assert_eq!(stirling_first(3, 5), BigInt::new(0));
+        assert_eq!(stirling_second(3, 5), BigInt::new(0));
+        assert_eq!(stirling_first(6, 6), BigInt::new(1));
+        assert_eq!(stirling_second(6, 1), BigInt::new(1));
+        assert_eq!(stirling_first(6, 1), BigInt::new(120));
+    }
+
+    #[test]
+    fn test_fibonacci_fast_doubling() {
+        let (mut a, mut b) = (BigInt::zero(), BigInt::one());
+        for n in 0..200 {
+            assert_eq!(fibonacci_pair(n), (a.clone(), b.clone()), "F({})", n);
+            (a, b) = (b.clone(), &a + &b);
+        }
+        assert_eq!(fibonacci(1000).to_string().len(), 209);
+    }
+
+    #[test]
+    fn test_binomial_and_multinomial() {
+        let mut row = vec![BigInt::one()];
+        for n in 0..=80i64 {
+            for (k, c) in (0i64..).zip(&row) {
+                assert_eq!(binomial(&BigInt::new(n), &BigInt::new(k)).as_ref(), Some(c));
+            }
+            let mut next = vec![BigInt::one()];
+            next.extend(row.windows(2).map(|w| &w[0] + &w[1]));
+            next.push(BigInt::one());
+            row = next;
+        }
+        assert_eq!(binomial(&BigInt::new(-1), &BigInt::ZERO), None);
+        assert_eq!(
+            binomial(&BigInt::new(5), &BigInt::new(-2)),
+            Some(BigInt::zero())
+        );
+
+        assert_eq!(multinomial(&[]), Some(BigInt::one()));
+        assert_eq!(multinomial(&[BigInt::new(7)]), Some(BigInt::one()));
+        assert_eq!(multinomial(&[BigInt::new(3), BigInt::new(-1)]), None);
+        // 300! / (100!)³ from factorials, for comparison
+        let hundred = BigInt::new(100);
+        let expected =
+            &BigInt::new(300).factorial().unwrap() / &hundred.factorial().unwrap().pow(3);
+        assert_eq!(
+            multinomial(&[hundred.clone(), hundred.clone(), hundred]),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_harmonic_matches_naive_sum() {
+        let mut sum = BigRational::zero();
+        for n in 1..=60u64 {
+            sum = &sum + &BigRational::new(BigInt::one(), BigInt::new(n as i64)).unwrap();
+            assert_eq!(harmonic(n), sum);
+        }
+        // Wolstenholme: p² divides the numerator of H(p − 1) for primes p > 3
+        let h = harmonic(36);
+        assert!((&h.numer() % &BigInt::new(37 * 37)).is_zero());
+    }
+
+    #[test]
+    fn test_bernoulli_power_sums() {
+        let expected = ["1", "-1/2", "1/6", "0", "-1/30", "0", "1/42", "0", "-1/30"];
+        for (n, b) in expected.iter().enumerate() {
+            assert_eq!(bernoulli(n as u32).to_string(), *b);
+        }
+        assert_eq!(bernoulli(30).to_string(), "8615841276005/14322");
+        // Faulhaber's formula for Σ_{k<N} k^m
+        let big_n = BigInt::new(25);
+        for m in 0..8u32 {
+            let mut sum = BigRational::zero();
+            for j in 0..=m {
+                let c =
+                    binomial(&BigInt::new(i64::from(m) + 1), &BigInt::new(i64::from(j))).unwrap();
+                let term = BigRational::from_integer(&c * &big_n.pow(m + 1 - j));
+                sum = &sum + &(&term * &bernoulli(j));
+            }
+            let sum = &sum / &BigRational::from_integer(BigInt::new(i64::from(m) + 1));
+            let direct = (0..25).fold(BigInt::zero(), |acc, k| &acc + &BigInt::new(k).pow(m));
+            assert_eq!(sum, BigRational::from_integer(direct));
+        }
+    }
+}
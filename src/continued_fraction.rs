@@ -0,0 +1,100 @@
+//! Hurwitz complex continued fractions.
+//!
+//! Hurwitz's algorithm expands `x = p/q` as `a₀ + 1/(a₁ + 1/(a₂ + …))` with
+//! each `aₖ` the Gaussian integer nearest to the remaining value, so every
+//! partial quotient after the first has norm at least 2. For a ratio of
+//! Gaussian integers this is the Euclidean algorithm with rounded
+//! quotients, so it terminates, and the convergents `hₙ/kₙ` are good
+//! Gaussian-rational approximations of `x`.
+//!
+//! # Examples
+//!
+//! ```
+//! use gauss_int::continued_fraction::{convergents, hurwitz_expansion};
+//! use gauss_int::GaussInt;
+//!
+//! let p = GaussInt::from_i64(37, 11);
+//! let q = GaussInt::from_i64(8, -5);
+//! let terms = hurwitz_expansion(&p, &q).unwrap();
+//! let (h, k) = convergents(&terms).pop().unwrap();
+//! // The last convergent is p/q in lowest terms: h·q = k·p
+//! assert_eq!(&h * &q, &k * &p);
+//! // and p/h is a gcd of p and q
+//! let g = p.div_exact(&h).unwrap();
+//! assert_eq!(g.normalize_associate().0, p.gcd(&q));
+//! ```
+
+use crate::GaussInt;
+
+/// The partial quotients `[a₀, a₁, …, aₙ]` of `p/q`, or `None` if `q` is
+/// zero.
+///
+/// Each `aₖ` rounds both components to the nearest integer, ties away from
+/// zero, as [`GaussInt::div_rem`] does.
+pub fn hurwitz_expansion(p: &GaussInt, q: &GaussInt) -> Option<Vec<GaussInt>> {
+    let (mut p, mut q) = (p.clone(), q.clone());
+    let mut terms = Vec::new();
+    while !q.is_zero() {
+        let (a, r) = p.div_rem(&q)?;
+        terms.push(a);
+        (p, q) = (q, r);
+    }
+    (!terms.is_empty()).then_some(terms)
+}
+
+/// The convergents `hₖ/kₖ` of a continued fraction, as `(hₖ, kₖ)` pairs.
+///
+/// They satisfy `hₖ = aₖhₖ₋₁ + hₖ₋₂` and `kₖ = aₖkₖ₋₁ + kₖ₋₂`, starting
+/// from `h₋₁/k₋₁ = 1/0` and `h₋₂/k₋₂ = 0/1`.
+pub fn convergents(terms: &[GaussInt]) -> Vec<(GaussInt, GaussInt)> {
+    let (mut h_prev, mut h) = (GaussInt::ZERO, GaussInt::ONE);
+    let (mut k_prev, mut k) = (GaussInt::ONE, GaussInt::ZERO);
+    terms
+        .iter()
+        .map(|a| {
+            (h_prev, h) = (h.clone(), &(a * &h) + &h_prev);
+            (k_prev, k) = (k.clone(), &(a * &k) + &k_prev);
+            (h.clone(), k.clone())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BigInt;
+
+    #[test]
+    fn test_expansion_of_integer_ratios() {
+        let z = GaussInt::from_i64(3, -4);
+        assert_eq!(hurwitz_expansion(&z, &GaussInt::ONE), Some(vec![z.clone()]));
+        assert_eq!(hurwitz_expansion(&z, &GaussInt::ZERO), None);
+        // 1/(1+i) = (1-i)/2 rounds to 1-i, then 1/((1-i)/2 - (1-i)) = -1-i
+        let terms = hurwitz_expansion(&GaussInt::ONE, &GaussInt::from_i64(1, 1)).unwrap();
+        assert_eq!(
+            terms,
+            vec![GaussInt::from_i64(1, -1), GaussInt::from_i64(-1, -1)]
+        );
+    }
+
+    #[test]
+    fn test_convergents_approach_ratio() {
+        let p = GaussInt::new(BigInt::new(10).pow(30), BigInt::new(7).pow(33));
+        let q = GaussInt::new(BigInt::new(3).pow(60), -BigInt::new(11).pow(27));
+        let terms = hurwitz_expansion(&p, &q).unwrap();
+        assert!(terms[1..].iter().all(|a| a.norm() >= BigInt::TWO));
+        let convs = convergents(&terms);
+        for window in convs.windows(2) {
+            let [(h0, k0), (h1, k1)] = window else {
+                unreachable!()
+            };
+            // Consecutive convergents differ by a unit: h₁k₀ − h₀k₁ = ±1
+            assert!((&(h1 * k0) - &(h0 * k1)).is_unit());
+            // The residuals h·q − k·p are the Euclidean remainders
+            let err = |h: &GaussInt, k: &GaussInt| (&(h * &q) - &(k * &p)).norm();
+            assert!(err(h1, k1) <= err(h0, k0));
+        }
+        let (h, k) = convs.last().unwrap();
+        assert_eq!(&(h * &q), &(k * &p));
+    }
+}
@@ -0,0 +1,346 @@
+//! Elliptic curves in short Weierstrass form over a prime field.
+//!
+//! A [`Curve`] is `y² = x³ + a·x + b (mod p)` for a prime `p > 3`. Points are
+//! kept in affine coordinates with components reduced into `0..p`; the group
+//! law uses the usual chord-and-tangent formulas with one modular inversion
+//! per addition.
+
+use crate::BigInt;
+use std::fmt;
+
+/// A point on a curve: the point at infinity or an affine pair `(x, y)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Point {
+    Infinity,
+    Affine { x: BigInt, y: BigInt },
+}
+
+impl Point {
+    /// Returns `true` for the point at infinity, the group identity.
+    pub fn is_infinity(&self) -> bool {
+        matches!(self, Point::Infinity)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Point::Infinity => write!(f, "O"),
+            Point::Affine { x, y } => write!(f, "({}, {})", x, y),
+        }
+    }
+}
+
+/// The curve `y² = x³ + a·x + b` over GF(p).
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::curve::{Curve, Point};
+///
+/// // y² = x³ + 2x + 3 over GF(97)
+/// let curve = Curve::new(BigInt::new(2), BigInt::new(3), BigInt::new(97)).unwrap();
+/// let g = curve.point(BigInt::new(3), BigInt::new(6)).unwrap();
+/// let g2 = curve.double(&g);
+/// assert_eq!(g2.to_string(), "(80, 10)");
+/// assert_eq!(curve.add(&g2, &curve.neg(&g2)), Point::Infinity);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Curve {
+    a: BigInt,
+    b: BigInt,
+    p: BigInt,
+}
+
+impl Curve {
+    /// Creates the curve `y² = x³ + a·x + b (mod p)`.
+    ///
+    /// Returns `None` unless `p` is a prime greater than 3 and the curve is
+    /// non-singular, i.e. `4a³ + 27b² ≢ 0 (mod p)`.
+    pub fn new(a: BigInt, b: BigInt, p: BigInt) -> Option<Self> {
+        if p <= BigInt::new(3) || !p.is_prime() {
+            return None;
+        }
+        let a = a.rem_euclid(&p);
+        let b = b.rem_euclid(&p);
+        let disc = &(&BigInt::new(4) * &(&(&a * &a) * &a)) + &(&BigInt::new(27) * &(&b * &b));
+        if disc.rem_euclid(&p).is_zero() {
+            return None;
+        }
+        Some(Curve { a, b, p })
+    }
+
+    /// Returns the coefficient `a`, reduced into `0..p`.
+    pub fn a(&self) -> &BigInt {
+        &self.a
+    }
+
+    /// Returns the constant term `b`, reduced into `0..p`.
+    pub fn b(&self) -> &BigInt {
+        &self.b
+    }
+
+    /// Returns the field characteristic `p`.
+    pub fn modulus(&self) -> &BigInt {
+        &self.p
+    }
+
+    /// Creates the affine point `(x, y)`, reducing the coordinates mod `p`.
+    ///
+    /// Returns `None` if the point does not lie on the curve.
+    pub fn point(&self, x: BigInt, y: BigInt) -> Option<Point> {
+        let point = Point::Affine {
+            x: x.rem_euclid(&self.p),
+            y: y.rem_euclid(&self.p),
+        };
+        self.is_on_curve(&point).then_some(point)
+    }
+
+    /// Checks whether `point` satisfies the curve equation.
+    ///
+    /// The point at infinity is always on the curve; affine coordinates
+    /// outside `0..p` are rejected.
+    pub fn is_on_curve(&self, point: &Point) -> bool {
+        match point {
+            Point::Infinity => true,
+            Point::Affine { x, y } => {
+                let in_range = |v: &BigInt| !v.is_negative() && *v < self.p;
+                if !in_range(x) || !in_range(y) {
+                    return false;
+                }
+                let lhs = y * y;
+                let rhs = &(&(&(x * x) * x) + &(&self.a * x)) + &self.b;
+                (&lhs - &rhs).rem_euclid(&self.p).is_zero()
+            }
+        }
+    }
+
+    /// Returns `-P`, the reflection of `P` in the x-axis.
+    pub fn neg(&self, point: &Point) -> Point {
+        match self.reduce(point) {
+            Point::Infinity => Point::Infinity,
+            Point::Affine { x, y } => Point::Affine {
+                x,
+                y: (-y).rem_euclid(&self.p),
+            },
+        }
+    }
+
+    /// Adds two points with the chord-and-tangent rule.
+    ///
+    /// Coordinates are reduced mod `p` first, so a [`Point::Affine`] built
+    /// by hand with coordinates outside `0..p` is treated as its residue.
+    /// The result is only meaningful for points on the curve.
+    pub fn add(&self, p1: &Point, p2: &Point) -> Point {
+        let (p1, p2) = (self.reduce(p1), self.reduce(p2));
+        let (x1, y1, x2, y2) = match (&p1, &p2) {
+            (Point::Infinity, q) | (q, Point::Infinity) => return q.clone(),
+            (Point::Affine { x: x1, y: y1 }, Point::Affine { x: x2, y: y2 }) => (x1, y1, x2, y2),
+        };
+        if x1 == x2 {
+            if (y1 + y2).rem_euclid(&self.p).is_zero() {
+                return Point::Infinity;
+            }
+            return self.double(&p1);
+        }
+        let slope = self.div(&(y2 - y1), &(x2 - x1));
+        self.chord_point(&slope, x1, y1, x2)
+    }
+
+    /// Doubles a point using the tangent line, reducing its coordinates
+    /// mod `p` first as [`add`](Self::add) does.
+    pub fn double(&self, point: &Point) -> Point {
+        let point = self.reduce(point);
+        let (x, y) = match &point {
+            Point::Infinity => return Point::Infinity,
+            Point::Affine { x, y } => (x, y),
+        };
+        if y.is_zero() {
+            return Point::Infinity;
+        }
+        let numerator = &(&BigInt::new(3) * &(x * x)) + &self.a;
+        let slope = self.div(&numerator, &(y + y));
+        self.chord_point(&slope, x, y, x)
+    }
+
+    /// Computes `k·P` by double-and-add; negative `k` uses `-P`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    /// use gauss_int::curve::{Curve, Point};
+    ///
+    /// let curve = Curve::new(BigInt::new(2), BigInt::new(3), BigInt::new(97)).unwrap();
+    /// let g = curve.point(BigInt::new(3), BigInt::new(6)).unwrap();
+    /// // G has order 5
+    /// assert_eq!(curve.scalar_mul(&BigInt::new(5), &g), Point::Infinity);
+    /// assert_eq!(curve.scalar_mul(&BigInt::new(-1), &g), curve.scalar_mul(&BigInt::new(4), &g));
+    /// ```
+    pub fn scalar_mul(&self, k: &BigInt, point: &Point) -> Point {
+        if k.is_negative() {
+            return self.scalar_mul(&-k, &self.neg(point));
+        }
+        let mut result = Point::Infinity;
+        let mut addend = point.clone();
+        let mut e = k.clone();
+        let two = BigInt::new(2);
+        while !e.is_zero() {
+            let (q, r) = e.div_rem(&two);
+            if !r.is_zero() {
+                result = self.add(&result, &addend);
+            }
+            addend = self.double(&addend);
+            e = q;
+        }
+        result
+    }
+
+    /// Reduces affine coordinates into `0..p`.
+    fn reduce(&self, point: &Point) -> Point {
+        match point {
+            Point::Infinity => Point::Infinity,
+            Point::Affine { x, y } => Point::Affine {
+                x: x.rem_euclid(&self.p),
+                y: y.rem_euclid(&self.p),
+            },
+        }
+    }
+
+    /// Computes `num / den (mod p)`; `den` is non-zero mod `p` by construction.
+    fn div(&self, num: &BigInt, den: &BigInt) -> BigInt {
+        let inv = den
+            .rem_euclid(&self.p)
+            .mod_inv(&self.p)
+            .expect("denominator is invertible mod a prime");
+        (num * &inv).rem_euclid(&self.p)
+    }
+
+    /// Completes an addition given the slope of the line through `(x1, y1)`.
+    fn chord_point(&self, slope: &BigInt, x1: &BigInt, y1: &BigInt, x2: &BigInt) -> Point {
+        let x3 = (&(&(slope * slope) - x1) - x2).rem_euclid(&self.p);
+        let y3 = (&(slope * &(x1 - &x3)) - y1).rem_euclid(&self.p);
+        Point::Affine { x: x3, y: y3 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve97() -> Curve {
+        Curve::new(BigInt::new(2), BigInt::new(3), BigInt::new(97)).unwrap()
+    }
+
+    fn pt(curve: &Curve, x: i64, y: i64) -> Point {
+        curve.point(BigInt::new(x), BigInt::new(y)).unwrap()
+    }
+
+    /// All points of the curve, by brute force.
+    fn all_points(curve: &Curve) -> Vec<Point> {
+        let p = curve.modulus().to_u64_lossy() as i64;
+        let mut points = vec![Point::Infinity];
+        for x in 0..p {
+            for y in 0..p {
+                if let Some(point) = curve.point(BigInt::new(x), BigInt::new(y)) {
+                    points.push(point);
+                }
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn test_curve_rejects_invalid_parameters() {
+        assert!(Curve::new(BigInt::new(1), BigInt::new(1), BigInt::new(91)).is_none());
+        assert!(Curve::new(BigInt::new(1), BigInt::new(1), BigInt::new(3)).is_none());
+        // 4·(-3)³ + 27·2² = 0: singular
+        assert!(Curve::new(BigInt::new(-3), BigInt::new(2), BigInt::new(97)).is_none());
+    }
+
+    #[test]
+    fn test_point_validation() {
+        let curve = curve97();
+        assert!(curve.point(BigInt::new(3), BigInt::new(6)).is_some());
+        assert!(curve.point(BigInt::new(3), BigInt::new(-91)).is_some());
+        assert!(curve.point(BigInt::new(3), BigInt::new(7)).is_none());
+        assert!(curve.is_on_curve(&Point::Infinity));
+        let unreduced = Point::Affine {
+            x: BigInt::new(100),
+            y: BigInt::new(6),
+        };
+        assert!(!curve.is_on_curve(&unreduced));
+    }
+
+    #[test]
+    fn test_group_law() {
+        let curve = curve97();
+        let points = all_points(&curve);
+        let g = pt(&curve, 3, 6);
+        for p in points.iter().take(12) {
+            assert_eq!(curve.add(p, &Point::Infinity), *p);
+            assert!(curve.add(p, &curve.neg(p)).is_infinity());
+            assert_eq!(curve.add(p, p), curve.double(p));
+            for q in points.iter().step_by(7) {
+                let sum = curve.add(p, q);
+                assert!(curve.is_on_curve(&sum));
+                assert_eq!(sum, curve.add(q, p));
+                assert_eq!(
+                    curve.add(&sum, &g),
+                    curve.add(p, &curve.add(q, &g)),
+                    "associativity for {} + {}",
+                    p,
+                    q
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_mul_group_order() {
+        let curve = curve97();
+        let order = BigInt::new(all_points(&curve).len() as i64);
+        for p in all_points(&curve).iter().step_by(5) {
+            assert!(curve.scalar_mul(&order, p).is_infinity());
+        }
+        let g = pt(&curve, 3, 6);
+        let mut acc = Point::Infinity;
+        for k in 0..12 {
+            assert_eq!(curve.scalar_mul(&BigInt::new(k), &g), acc);
+            acc = curve.add(&acc, &g);
+        }
+    }
+
+    #[test]
+    fn test_double_two_torsion() {
+        // y² = x³ - x has the 2-torsion point (0, 0)
+        let curve = Curve::new(BigInt::new(-1), BigInt::new(0), BigInt::new(103)).unwrap();
+        let t = pt(&curve, 0, 0);
+        assert!(curve.double(&t).is_infinity());
+    }
+
+    #[test]
+    fn test_unreduced_coordinates() {
+        let curve = curve97();
+        let g = pt(&curve, 3, 6);
+        let shifted = Point::Affine {
+            x: BigInt::new(100),
+            y: BigInt::new(6),
+        };
+        // (100, 6) ≡ (3, 6), so this is a doubling, not a chord
+        assert_eq!(curve.add(&g, &shifted), curve.double(&g));
+        let negated = Point::Affine {
+            x: BigInt::new(3 - 97),
+            y: BigInt::new(-6),
+        };
+        assert_eq!(curve.add(&g, &negated), Point::Infinity);
+        assert_eq!(curve.neg(&shifted), curve.neg(&g));
+        // y ≡ 0 mod p doubles to infinity rather than dividing by zero
+        let two_torsion = Point::Affine {
+            x: BigInt::new(0),
+            y: BigInt::new(97),
+        };
+        assert_eq!(curve.double(&two_torsion), Point::Infinity);
+    }
+}
@@ -0,0 +1,202 @@
+//! The crate-wide error type.
+//!
+//! Most operations report failure with `Option`, which keeps the common
+//! case terse. The `try_*` methods and the [`FromStr`](std::str::FromStr)
+//! impls return [`Error`] instead, saying why an operation failed, for code
+//! that propagates errors with `?`. Parse failures carry a
+//! [`ParseBigIntError`] or [`ParseGaussIntError`] giving the position.
+
+use std::fmt;
+
+/// Why an operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The text is not a valid integer; says where it went wrong.
+    ParseBigInt(ParseBigIntError),
+    /// The text is not a valid Gaussian integer; says where it went wrong.
+    ParseGaussInt(ParseGaussIntError),
+    /// The divisor or modulus is zero.
+    DivisionByZero,
+    /// The square root of a negative number was requested.
+    NegativeSqrt,
+    /// The division leaves a nonzero remainder.
+    NotExactlyDivisible,
+    /// An argument lies outside the operation's domain.
+    DomainError(&'static str),
+}
+
+/// `Result` with [`Error`] as the error type.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ParseBigInt(e) => fmt::Display::fmt(e, f),
+            Error::ParseGaussInt(e) => fmt::Display::fmt(e, f),
+            Error::DivisionByZero => f.write_str("division by zero"),
+            Error::NegativeSqrt => f.write_str("square root of a negative number"),
+            Error::NotExactlyDivisible => f.write_str("division is not exact"),
+            Error::DomainError(reason) => f.write_str(reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ParseBigInt(e) => Some(e),
+            Error::ParseGaussInt(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Where parsing stopped: the byte offset of the first character that does
+/// not fit, and that character. Shared by both public parse errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParsePosition {
+    offset: usize,
+    found: Option<char>,
+}
+
+impl ParsePosition {
+    fn at(s: &str, offset: usize) -> Self {
+        ParsePosition {
+            offset,
+            found: s[offset..].chars().next(),
+        }
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>, what: &str) -> fmt::Result {
+        match self.found {
+            Some(c) => write!(
+                f,
+                "invalid {}: unexpected {:?} at byte {}",
+                what, c, self.offset
+            ),
+            None => write!(
+                f,
+                "invalid {}: unexpected end of input at byte {}",
+                what, self.offset
+            ),
+        }
+    }
+}
+
+/// Why [`BigInt::try_from_string`](crate::BigInt::try_from_string) rejected
+/// its input: the byte offset of the first character that does not fit,
+/// and that character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError(ParsePosition);
+
+/// Why [`GaussInt::try_from_string`](crate::GaussInt::try_from_string)
+/// rejected its input; see [`ParseBigIntError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGaussIntError(ParsePosition);
+
+impl ParseBigIntError {
+    pub(crate) fn at(s: &str, offset: usize) -> Self {
+        ParseBigIntError(ParsePosition::at(s, offset))
+    }
+
+    pub(crate) fn new(offset: usize, found: Option<char>) -> Self {
+        ParseBigIntError(ParsePosition { offset, found })
+    }
+
+    /// The byte offset of the offending character, or the input length if
+    /// the input ended too early.
+    pub fn offset(&self) -> usize {
+        self.0.offset
+    }
+
+    /// The offending character, or `None` if the input ended too early.
+    pub fn found(&self) -> Option<char> {
+        self.0.found
+    }
+}
+
+impl ParseGaussIntError {
+    pub(crate) fn at(s: &str, offset: usize) -> Self {
+        ParseGaussIntError(ParsePosition::at(s, offset))
+    }
+
+    pub(crate) fn new(offset: usize, found: Option<char>) -> Self {
+        ParseGaussIntError(ParsePosition { offset, found })
+    }
+
+    /// The byte offset of the offending character, or the input length if
+    /// the input ended too early.
+    pub fn offset(&self) -> usize {
+        self.0.offset
+    }
+
+    /// The offending character, or `None` if the input ended too early.
+    pub fn found(&self) -> Option<char> {
+        self.0.found
+    }
+}
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.describe(f, "integer")
+    }
+}
+
+impl fmt::Display for ParseGaussIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.describe(f, "Gaussian integer")
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl std::error::Error for ParseGaussIntError {}
+
+impl From<ParseBigIntError> for Error {
+    fn from(e: ParseBigIntError) -> Self {
+        Error::ParseBigInt(e)
+    }
+}
+
+impl From<ParseGaussIntError> for Error {
+    fn from(e: ParseGaussIntError) -> Self {
+        Error::ParseGaussInt(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!(Error::DivisionByZero.to_string(), "division by zero");
+        assert_eq!(
+            Error::DomainError("factorial of a negative number").to_string(),
+            "factorial of a negative number"
+        );
+        let boxed: Box<dyn std::error::Error> = Box::new(Error::NegativeSqrt);
+        assert_eq!(boxed.to_string(), "square root of a negative number");
+        let parse = Error::from(ParseBigIntError::at("1x", 1));
+        assert_eq!(
+            parse.to_string(),
+            "invalid integer: unexpected 'x' at byte 1"
+        );
+        let source = std::error::Error::source(&parse).unwrap();
+        assert_eq!(source.to_string(), parse.to_string());
+    }
+
+    #[test]
+    fn test_parse_error_positions() {
+        let err = ParseBigIntError::at("12é4", 2);
+        assert_eq!((err.offset(), err.found()), (2, Some('é')));
+        assert_eq!(err.to_string(), "invalid integer: unexpected 'é' at byte 2");
+        let err = ParseGaussIntError::at("3+", 2);
+        assert_eq!(err.found(), None);
+        assert_eq!(
+            err.to_string(),
+            "invalid Gaussian integer: unexpected end of input at byte 2"
+        );
+    }
+}
@@ -0,0 +1,492 @@
+//! Parsing and evaluation of arithmetic expressions.
+//!
+//! [`evaluate`] turns text such as `(3+4i)^5 * conj(2-7i) + 10!` into an
+//! exact [`Value`]. Supported syntax:
+//!
+//! - integer literals of any size, imaginary literals such as `4i`, and `i`;
+//! - `+`, `-`, `*`, `/`, `%`, right-associative `^` and postfix `!`, with
+//!   the usual precedence and parentheses;
+//! - the functions `conj`, `norm`, `re`, `im`, `abs` and `gcd`;
+//! - variables supplied through [`evaluate_with`].
+//!
+//! Integers divide with truncation toward zero; once either operand is a
+//! Gaussian integer, `/` and `%` follow [`GaussInt::div_rem`].
+//!
+//! Untrusted input cannot exhaust the stack or memory: nesting deeper than
+//! [`MAX_DEPTH`] and `^` or `!` results wider than [`MAX_RESULT_BITS`] are
+//! reported as errors.
+
+use crate::{BigInt, GaussInt};
+use num_traits::Zero;
+use std::collections::HashMap;
+use std::fmt;
+
+/// The deepest nesting of parentheses, calls and unary signs accepted,
+/// small enough for a 2 MiB thread stack in unoptimized builds.
+pub const MAX_DEPTH: usize = 128;
+
+/// The largest bit length a `^` or `!` may produce.
+pub const MAX_RESULT_BITS: u64 = 1 << 20;
+
+/// Error from [`evaluate`]: a syntax error, an unknown name, or an invalid
+/// operation such as division by zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvalError {
+    message: String,
+}
+
+impl EvalError {
+    fn new(message: impl Into<String>) -> Self {
+        EvalError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// The result of [`evaluate`]: an integer, or a Gaussian integer once `i`
+/// is involved. Results with zero imaginary part are always `Int`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(BigInt),
+    Gauss(GaussInt),
+}
+
+impl Value {
+    /// Returns the value as a Gaussian integer, with zero imaginary part
+    /// for an integer.
+    pub fn to_gauss_int(&self) -> GaussInt {
+        match self {
+            Value::Int(n) => GaussInt::new(n.clone(), BigInt::zero()),
+            Value::Gauss(z) => z.clone(),
+        }
+    }
+
+    fn as_int(&self, what: &str) -> Result<&BigInt, EvalError> {
+        match self {
+            Value::Int(n) => Ok(n),
+            Value::Gauss(_) => Err(EvalError::new(format!("{} needs an integer", what))),
+        }
+    }
+
+    fn to_u32(&self, what: &str) -> Result<u32, EvalError> {
+        let n = self.as_int(what)?;
+        if n.is_negative() || n.bits() > 32 {
+            return Err(EvalError::new(format!("{} must be in 0..2^32", what)));
+        }
+        Ok(n.to_string().parse().expect("fits in u32"))
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Gauss(z) => write!(f, "{}", z),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(BigInt),
+    Imag(BigInt),
+    Ident(String),
+    Op(char),
+}
+
+fn tokenize(line: &str) -> Result<Vec<Token>, EvalError> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < chars.len() {
+        let c = chars[pos];
+        if c.is_whitespace() {
+            pos += 1;
+        } else if c.is_ascii_digit() {
+            let start = pos;
+            while pos < chars.len() && chars[pos].is_ascii_digit() {
+                pos += 1;
+            }
+            let digits: String = chars[start..pos].iter().collect();
+            let n = BigInt::from_string(&digits).expect("decimal digits");
+            // `4i` is an imaginary literal, but `4if` is not.
+            let imag = chars.get(pos) == Some(&'i')
+                && !chars
+                    .get(pos + 1)
+                    .is_some_and(|c| c.is_alphanumeric() || *c == '_');
+            if imag {
+                pos += 1;
+                tokens.push(Token::Imag(n));
+            } else {
+                tokens.push(Token::Num(n));
+            }
+        } else if c.is_alphabetic() || c == '_' {
+            let start = pos;
+            while pos < chars.len() && (chars[pos].is_alphanumeric() || chars[pos] == '_') {
+                pos += 1;
+            }
+            tokens.push(Token::Ident(chars[start..pos].iter().collect()));
+        } else if "+-*/%^!(),".contains(c) {
+            tokens.push(Token::Op(c));
+            pos += 1;
+        } else {
+            return Err(EvalError::new(format!("unexpected character '{}'", c)));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive-descent evaluator. Precedence, loosest first: `+ -`, `* / %`,
+/// unary `-`, right-associative `^`, postfix `!`.
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    depth: usize,
+    vars: &'a HashMap<String, Value>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, op: char) -> bool {
+        if self.peek() == Some(&Token::Op(op)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, op: char) -> Result<(), EvalError> {
+        if self.eat(op) {
+            Ok(())
+        } else {
+            Err(EvalError::new(format!("expected '{}'", op)))
+        }
+    }
+
+    /// Runs `f` one nesting level deeper, failing past [`MAX_DEPTH`].
+    fn nested<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, EvalError>,
+    ) -> Result<T, EvalError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(EvalError::new("expression nested too deeply"));
+        }
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn expr(&mut self) -> Result<Value, EvalError> {
+        let mut acc = self.term()?;
+        loop {
+            if self.eat('+') {
+                acc = add(&acc, &self.term()?);
+            } else if self.eat('-') {
+                acc = add(&acc, &negate(&self.term()?));
+            } else {
+                return Ok(acc);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Value, EvalError> {
+        let mut acc = self.unary()?;
+        loop {
+            if self.eat('*') {
+                acc = mul(&acc, &self.unary()?);
+            } else if self.eat('/') {
+                acc = div_rem(&acc, &self.unary()?)?.0;
+            } else if self.eat('%') {
+                acc = div_rem(&acc, &self.unary()?)?.1;
+            } else {
+                return Ok(acc);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<Value, EvalError> {
+        if self.eat('-') {
+            return Ok(negate(&self.nested(Self::unary)?));
+        }
+        if self.eat('+') {
+            return self.nested(Self::unary);
+        }
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Value, EvalError> {
+        let base = self.postfix()?;
+        if !self.eat('^') {
+            return Ok(base);
+        }
+        let exp = self.nested(Self::unary)?.to_u32("exponent")?;
+        // At most log2 |base|², so the result has at least half of `exp`
+        // times this many bits
+        let log_norm = match &base {
+            Value::Int(n) => 2 * n.bits().saturating_sub(1),
+            Value::Gauss(z) => z.norm().bits().saturating_sub(1),
+        };
+        if log_norm.saturating_mul(u64::from(exp)) / 2 > MAX_RESULT_BITS {
+            return Err(EvalError::new("power too large"));
+        }
+        Ok(match base {
+            Value::Int(n) => Value::Int(n.pow(exp)),
+            Value::Gauss(z) => simplify(z.pow_u32(exp)),
+        })
+    }
+
+    fn postfix(&mut self) -> Result<Value, EvalError> {
+        let mut value = self.primary()?;
+        while self.eat('!') {
+            let n = value.as_int("factorial")?;
+            if n.is_negative() {
+                return Err(EvalError::new("factorial of a negative number"));
+            }
+            // n! < n^n, so n·bits(n) bounds its width
+            if u64::from(value.to_u32("factorial")?) * n.bits() > MAX_RESULT_BITS {
+                return Err(EvalError::new("factorial too large"));
+            }
+            value = Value::Int(n.factorial().expect("non-negative"));
+        }
+        Ok(value)
+    }
+
+    fn primary(&mut self) -> Result<Value, EvalError> {
+        let token = self
+            .peek()
+            .cloned()
+            .ok_or_else(|| EvalError::new("unexpected end of input"))?;
+        self.pos += 1;
+        match token {
+            Token::Num(n) => Ok(Value::Int(n)),
+            Token::Imag(n) => Ok(Value::Gauss(GaussInt::new(BigInt::zero(), n))),
+            Token::Op('(') => self.nested(|p| {
+                let value = p.expr()?;
+                p.expect(')')?;
+                Ok(value)
+            }),
+            Token::Ident(name) if self.eat('(') => self.nested(|p| {
+                let mut args = vec![p.expr()?];
+                while p.eat(',') {
+                    args.push(p.expr()?);
+                }
+                p.expect(')')?;
+                call(&name, &args)
+            }),
+            Token::Ident(name) if name == "i" => Ok(Value::Gauss(GaussInt::I)),
+            Token::Ident(name) => self
+                .vars
+                .get(&name)
+                .cloned()
+                .ok_or_else(|| EvalError::new(format!("unknown variable '{}'", name))),
+            Token::Op(op) => Err(EvalError::new(format!("unexpected '{}'", op))),
+        }
+    }
+}
+
+/// Collapses Gaussian integers with zero imaginary part back to integers.
+fn simplify(z: GaussInt) -> Value {
+    if z.is_real() {
+        Value::Int(z.real().clone())
+    } else {
+        Value::Gauss(z)
+    }
+}
+
+fn add(a: &Value, b: &Value) -> Value {
+    match (a, b) {
+        (Value::Int(x), Value::Int(y)) => Value::Int(x + y),
+        _ => simplify(&a.to_gauss_int() + &b.to_gauss_int()),
+    }
+}
+
+fn mul(a: &Value, b: &Value) -> Value {
+    match (a, b) {
+        (Value::Int(x), Value::Int(y)) => Value::Int(x * y),
+        _ => simplify(&a.to_gauss_int() * &b.to_gauss_int()),
+    }
+}
+
+fn negate(a: &Value) -> Value {
+    match a {
+        Value::Int(x) => Value::Int(-x),
+        Value::Gauss(z) => Value::Gauss(-z),
+    }
+}
+
+/// Integers divide with truncation; Gaussian integers with [`GaussInt::div_rem`].
+fn div_rem(a: &Value, b: &Value) -> Result<(Value, Value), EvalError> {
+    if b.to_gauss_int().is_zero() {
+        return Err(EvalError::new("division by zero"));
+    }
+    match (a, b) {
+        (Value::Int(x), Value::Int(y)) => {
+            let (q, r) = x.div_rem(y);
+            Ok((Value::Int(q), Value::Int(r)))
+        }
+        _ => {
+            let (q, r) = a
+                .to_gauss_int()
+                .div_rem(&b.to_gauss_int())
+                .expect("nonzero divisor");
+            Ok((simplify(q), simplify(r)))
+        }
+    }
+}
+
+fn call(name: &str, args: &[Value]) -> Result<Value, EvalError> {
+    let arity = match name {
+        "gcd" => 2,
+        "conj" | "norm" | "re" | "im" | "abs" => 1,
+        _ => return Err(EvalError::new(format!("unknown function '{}'", name))),
+    };
+    if args.len() != arity {
+        return Err(EvalError::new(format!(
+            "{} takes {} argument(s)",
+            name, arity
+        )));
+    }
+    let z = args[0].to_gauss_int();
+    Ok(match name {
+        "conj" => simplify(z.conjugate()),
+        "norm" => Value::Int(z.norm()),
+        "re" => Value::Int(z.real().clone()),
+        "im" => Value::Int(z.imag().clone()),
+        "abs" => Value::Int(args[0].as_int("abs")?.abs()),
+        _ => match (&args[0], &args[1]) {
+            (Value::Int(a), Value::Int(b)) => Value::Int(a.gcd(b)),
+            _ => simplify(z.gcd(&args[1].to_gauss_int())),
+        },
+    })
+}
+
+/// Evaluates an arithmetic expression.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::expr::{evaluate, Value};
+/// use gauss_int::GaussInt;
+///
+/// let z = evaluate("(3+4i)^5 * conj(2-7i) + 10!").unwrap();
+/// assert_eq!(z, Value::Gauss(GaussInt::from_i64(3650138, -7891)));
+/// assert_eq!(evaluate("(2+i)*(2-i)").unwrap().to_string(), "5");
+/// assert!(evaluate("1 / 0").is_err());
+/// ```
+pub fn evaluate(expr: &str) -> Result<Value, EvalError> {
+    evaluate_with(expr, &HashMap::new())
+}
+
+/// Like [`evaluate`], resolving identifiers other than `i` in `vars`.
+pub fn evaluate_with(expr: &str, vars: &HashMap<String, Value>) -> Result<Value, EvalError> {
+    let tokens = tokenize(expr)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        depth: 0,
+        vars,
+    };
+    let value = parser.expr()?;
+    if let Some(token) = parser.peek() {
+        return Err(EvalError::new(format!(
+            "unexpected trailing input {:?}",
+            token
+        )));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(expr: &str) -> Result<String, EvalError> {
+        evaluate(expr).map(|v| v.to_string())
+    }
+
+    #[test]
+    fn test_evaluate_expressions() {
+        assert_eq!(eval("1 + 2 * 3").unwrap(), "7");
+        assert_eq!(eval("-2^2").unwrap(), "-4");
+        assert_eq!(eval("2^3^2").unwrap(), "512");
+        assert_eq!(eval("(3+4i)*(3-4i)").unwrap(), "25");
+        assert_eq!(evaluate("i^2").unwrap(), Value::Int(BigInt::new(-1)));
+        assert_eq!(eval("3!!").unwrap(), "720");
+        assert_eq!(eval("-7 / 2").unwrap(), "-3");
+        assert_eq!(eval("norm(3+4i) + gcd(12, 18)").unwrap(), "31");
+        assert_eq!(eval("conj(2-7i)").unwrap(), "2+7i");
+        assert_eq!(eval("25!").unwrap(), "15511210043330985984000000");
+    }
+
+    #[test]
+    fn test_evaluate_errors() {
+        assert!(eval("1 / 0").is_err());
+        assert!(eval("(1 + 2").is_err());
+        assert!(eval("1 2").is_err());
+        assert!(eval("x + 1").is_err());
+        assert!(eval("i!").is_err());
+        assert!(eval("2 ^ -1").is_err());
+        assert_eq!(
+            eval("foo(1)").unwrap_err().to_string(),
+            "unknown function 'foo'"
+        );
+    }
+
+    #[test]
+    fn test_evaluate_limits() {
+        let deep = format!("{}1{}", "(".repeat(100_000), ")".repeat(100_000));
+        assert_eq!(
+            eval(&deep).unwrap_err().to_string(),
+            "expression nested too deeply"
+        );
+        assert!(eval(&"-".repeat(100_000)).is_err());
+        assert!(eval(&format!("{}1", "-".repeat(120))).is_ok());
+        assert!(eval(&format!("{}1{}", "abs(".repeat(120), ")".repeat(120))).is_ok());
+
+        assert_eq!(
+            eval("9^999999999").unwrap_err().to_string(),
+            "power too large"
+        );
+        assert_eq!(
+            eval("(1+i)^4000000").unwrap_err().to_string(),
+            "power too large"
+        );
+        assert_eq!(eval("1^4294967295").unwrap(), "1");
+        assert_eq!(eval("(-1)^4294967295").unwrap(), "-1");
+        assert_eq!(eval("i^4294967295").unwrap(), "-i");
+        assert!(eval("2^1000000").is_ok());
+        assert_eq!(
+            eval("1000000!").unwrap_err().to_string(),
+            "factorial too large"
+        );
+        assert!(eval("10!!").is_err());
+        assert!(eval("1000!").is_ok());
+    }
+
+    #[test]
+    fn test_evaluate_with_variables() {
+        let mut vars = HashMap::new();
+        vars.insert("x".to_string(), evaluate("2 + i").unwrap());
+        assert_eq!(
+            evaluate_with("x * conj(x)", &vars).unwrap().to_string(),
+            "5"
+        );
+        assert!(evaluate("x").is_err());
+
+        vars.insert("x".to_string(), Value::Gauss(GaussInt::ZERO));
+        assert_eq!(evaluate_with("x^2", &vars).unwrap().to_string(), "0");
+    }
+}
@@ -0,0 +1,225 @@
+//! Fixed-point fast Fourier transform over Gaussian integers.
+//!
+//! A complex value `v` is represented by a Gaussian integer approximating
+//! `v·2^precision`. The transforms use only integer arithmetic, so results
+//! are reproducible bit for bit on every platform. [`error_bound`] gives the
+//! worst-case rounding error.
+
+use crate::fixed_point::{cos_sin_turns, round_shift};
+use crate::{BigInt, GaussInt};
+
+/// Computes `X_k = Σ x_j·e^(-2πijk/n)` with radix-2 decimation in time.
+///
+/// Inputs and outputs are scaled by `2^precision`. Returns `None` unless
+/// the length is a power of two. Every output is within
+/// [`error_bound`]`(n, max_abs, precision)` units of the exact transform
+/// of the inputs, where `max_abs` bounds `|x_j|` in the same units.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::fft::{forward, inverse};
+///
+/// let one = 1 << 16;
+/// let x: Vec<GaussInt> = [1, 2, 3, 4].iter().map(|&v| GaussInt::from_i64(v * one, 0)).collect();
+/// let y = forward(&x, 16).unwrap();
+/// // X = [10, -2 + 2i, -2, -2 - 2i]
+/// assert_eq!(y[1], GaussInt::from_i64(-2 * one, 2 * one));
+/// assert_eq!(inverse(&y, 16).unwrap(), x);
+/// ```
+pub fn forward(values: &[GaussInt], precision: u32) -> Option<Vec<GaussInt>> {
+    transform(values, precision, false)
+}
+
+/// Computes `x_j = (1/n)·Σ X_k·e^(2πijk/n)`, the inverse of [`forward`].
+///
+/// The unnormalized sum carries the same error as [`forward`]; the final
+/// division by `n` rounds once more, so outputs are within
+/// `error_bound(n, max_abs, precision)/n + 1` units of the exact inverse.
+/// Returns `None` unless the length is a power of two.
+pub fn inverse(values: &[GaussInt], precision: u32) -> Option<Vec<GaussInt>> {
+    let shift = values.len().trailing_zeros();
+    let sums = transform(values, precision, true)?;
+    Some(
+        sums.iter()
+            .map(|z| GaussInt::new(round_shift(z.real(), shift), round_shift(z.imag(), shift)))
+            .collect(),
+    )
+}
+
+/// Worst-case absolute error, in units of `2^-precision`, of any output of
+/// [`forward`] on `n` inputs with `|x_j| <= max_abs` units.
+///
+/// Each twiddle factor is within `√2` units of exact and each rounded
+/// product within `√2/2` units. Over the `log₂ n` stages, with magnitudes
+/// at most doubling per stage, the error `e` obeys
+/// `e' <= (2 + √2·2^-p)·e + √2·2^(s-p)·max_abs + √2/2`. The result is
+/// roughly `(3/4)·(n·log₂n·max_abs·2^-p + n)`, so choosing `precision`
+/// about `log₂(n·log₂n)` bits above the desired accuracy suffices.
+///
+/// Returns `None` unless `n` is a power of two.
+pub fn error_bound(n: usize, max_abs: &BigInt, precision: u32) -> Option<BigInt> {
+    if !n.is_power_of_two() {
+        return None;
+    }
+    // Track F = 4·2^p·e as an integer upper bound, using √2 <= 3/2
+    let scale = BigInt::new(2).pow(precision);
+    let mut f = BigInt::new(0);
+    let mut magnitude = max_abs.clone();
+    for _ in 0..n.trailing_zeros() {
+        let drift = (&BigInt::new(3) * &f).div_ceil(&(&scale * &BigInt::new(2)));
+        f = &(&(&BigInt::new(2) * &f) + &drift)
+            + &(&(&BigInt::new(6) * &magnitude) + &(&BigInt::new(3) * &scale));
+        magnitude = &magnitude * &BigInt::new(2);
+    }
+    Some(f.div_ceil(&(&scale * &BigInt::new(4))))
+}
+
+fn transform(values: &[GaussInt], precision: u32, invert: bool) -> Option<Vec<GaussInt>> {
+    let n = values.len();
+    if !n.is_power_of_two() {
+        return None;
+    }
+    let twiddles: Vec<GaussInt> = cos_sin_turns(n as u64, (n / 2) as u64, precision)
+        .into_iter()
+        .map(|(c, s)| {
+            if invert {
+                GaussInt::new(c, s)
+            } else {
+                GaussInt::new(c, -s)
+            }
+        })
+        .collect();
+    let bits = n.trailing_zeros();
+    let mut a: Vec<GaussInt> = (0..n)
+        .map(|k| values[reverse_bits(k, bits)].clone())
+        .collect();
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let step = n / len;
+        for start in (0..n).step_by(len) {
+            for j in 0..half {
+                let t = mul_scaled(&a[start + j + half], &twiddles[j * step], precision);
+                a[start + j + half] = &a[start + j] - &t;
+                a[start + j] += &t;
+            }
+        }
+        len *= 2;
+    }
+    Some(a)
+}
+
+/// Multiplies two values scaled by `2^precision`, rounding back to that scale.
+fn mul_scaled(z: &GaussInt, w: &GaussInt, precision: u32) -> GaussInt {
+    let product = z * w;
+    GaussInt::new(
+        round_shift(product.real(), precision),
+        round_shift(product.imag(), precision),
+    )
+}
+
+fn reverse_bits(k: usize, bits: u32) -> usize {
+    if bits == 0 {
+        return 0;
+    }
+    k.reverse_bits() >> (usize::BITS - bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pseudo_random(n: usize, seed: u64, limit: i64) -> Vec<GaussInt> {
+        let mut state = seed;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) as i64) % (2 * limit + 1) - limit
+        };
+        (0..n).map(|_| GaussInt::from_i64(next(), next())).collect()
+    }
+
+    /// An upper bound on max |x_j|, from the largest component sum.
+    fn max_abs(xs: &[GaussInt]) -> BigInt {
+        xs.iter()
+            .map(|z| &z.real().abs() + &z.imag().abs())
+            .max()
+            .unwrap_or_else(|| BigInt::new(0))
+    }
+
+    #[test]
+    fn test_fft_rejects_bad_lengths() {
+        assert!(forward(&[], 8).is_none());
+        assert!(forward(&pseudo_random(6, 1, 10), 8).is_none());
+        assert!(inverse(&pseudo_random(3, 1, 10), 8).is_none());
+        assert!(error_bound(12, &BigInt::new(1), 8).is_none());
+        let x = pseudo_random(1, 2, 10);
+        assert_eq!(forward(&x, 8).unwrap(), x);
+    }
+
+    #[test]
+    fn test_fft_impulse_and_constant_are_exact() {
+        let one = GaussInt::from_i64(1 << 20, 0);
+        let zero = GaussInt::from_i64(0, 0);
+        let mut impulse = vec![zero.clone(); 16];
+        impulse[0] = one.clone();
+        assert_eq!(forward(&impulse, 20).unwrap(), vec![one.clone(); 16]);
+
+        let c = GaussInt::from_i64(3 << 20, -(5 << 20));
+        let y = forward(&vec![c.clone(); 16], 20).unwrap();
+        assert_eq!(y[0], &c * &GaussInt::from_i64(16, 0));
+        assert!(y[1..].iter().all(|z| *z == zero));
+        assert_eq!(inverse(&y, 20).unwrap(), vec![c; 16]);
+    }
+
+    #[test]
+    fn test_fft_within_error_bound() {
+        let precision = 12;
+        let guard = 40;
+        for (n, seed) in [(8, 3), (32, 5), (64, 7)] {
+            let x = pseudo_random(n, seed, 1 << precision);
+            let m = max_abs(&x);
+            let bound = error_bound(n, &m, precision).unwrap();
+            let y = forward(&x, precision).unwrap();
+
+            // Reference: the same transform with 40 extra bits, rounded back
+            let shifted: Vec<GaussInt> = x
+                .iter()
+                .map(|z| z * &GaussInt::from_i64(1 << guard, 0))
+                .collect();
+            let reference = forward(&shifted, precision + guard).unwrap();
+            for (got, exact) in y.iter().zip(&reference) {
+                let exact = GaussInt::new(
+                    round_shift(exact.real(), guard),
+                    round_shift(exact.imag(), guard),
+                );
+                let slack = &bound + &BigInt::new(2);
+                assert!((got - &exact).norm() <= &slack * &slack, "n = {}", n);
+            }
+
+            // Round trip
+            let back = inverse(&y, precision).unwrap();
+            let y_bound = &(&m * &BigInt::new(n as i64)) + &bound;
+            let inverse_bound =
+                &error_bound(n, &y_bound, precision).unwrap() / &BigInt::new(n as i64);
+            let total = &(&bound + &inverse_bound) + &BigInt::new(1);
+            for (got, orig) in back.iter().zip(&x) {
+                assert!((got - orig).norm() <= &total * &total);
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_bound_shape() {
+        let m = BigInt::new(1 << 20);
+        let small = error_bound(1024, &m, 20).unwrap();
+        let large = error_bound(1024, &m, 40).unwrap();
+        // At precision 20 the n·log n term dominates; more bits shrink it
+        assert!(large < small);
+        assert_eq!(error_bound(1, &m, 20), Some(BigInt::new(0)));
+    }
+}
@@ -0,0 +1,146 @@
+//! Fixed-point transcendental helpers on BigInt.
+//!
+//! Values are integers scaled by `2^bits`. Every routine works with extra
+//! guard bits internally and rounds once at the end, so results are within
+//! one unit in the last place of the true value.
+
+use crate::BigInt;
+
+/// Extra working precision to absorb truncation in the series.
+const GUARD_BITS: u32 = 32;
+
+fn pow2(bits: u32) -> BigInt {
+    BigInt::new(2).pow(bits)
+}
+
+/// Rounds `x / 2^shift` to the nearest integer, ties away from zero.
+pub(crate) fn round_shift(x: &BigInt, shift: u32) -> BigInt {
+    crate::gauss_int::round_div(x, &pow2(shift))
+}
+
+/// `atan(1/m)·2^bits`, truncated, for an integer `m >= 2`.
+fn atan_inv(m: i64, bits: u32) -> BigInt {
+    let m = BigInt::new(m);
+    let m_sq = &m * &m;
+    let mut term = &pow2(bits) / &m;
+    let mut sum = BigInt::new(0);
+    let mut k = 1i64;
+    let mut add = true;
+    while !term.is_zero() {
+        let part = &term / &BigInt::new(k);
+        sum = if add { &sum + &part } else { &sum - &part };
+        term = &term / &m_sq;
+        k += 2;
+        add = !add;
+    }
+    sum
+}
+
+/// `π·2^bits` at working precision, by Machin's formula.
+fn pi_scaled(bits: u32) -> BigInt {
+    &(&BigInt::new(16) * &atan_inv(5, bits)) - &(&BigInt::new(4) * &atan_inv(239, bits))
+}
+
+/// `(cos x, sin x)·2^bits` for a fixed-point `x` in `[0, π/4]` at scale
+/// `2^bits`, by Taylor series.
+fn cos_sin_small(x: &BigInt, bits: u32) -> (BigInt, BigInt) {
+    let one = pow2(bits);
+    let x_sq = &(x * x) / &one;
+    let series = |first: BigInt, offset: i64| {
+        let mut term = first;
+        let mut sum = BigInt::new(0);
+        let mut n = offset;
+        let mut add = true;
+        while !term.is_zero() {
+            sum = if add { &sum + &term } else { &sum - &term };
+            term = &(&term * &x_sq) / &(&one * &BigInt::new((n + 1) * (n + 2)));
+            n += 2;
+            add = !add;
+        }
+        sum
+    };
+    (series(one.clone(), 0), series(x.clone(), 1))
+}
+
+/// Returns `(cos θ, sin θ)·2^bits`, rounded, for `θ = 2π·k/n` and each
+/// `k = 0..count`.
+///
+/// π is computed once. Each angle is reduced exactly to an octant, so only
+/// `[0, π/4)` is ever fed to the series and symmetric roots come out
+/// exactly symmetric.
+pub(crate) fn cos_sin_turns(n: u64, count: u64, bits: u32) -> Vec<(BigInt, BigInt)> {
+    let work = bits + GUARD_BITS;
+    let quarter_pi = &pi_scaled(work) / &BigInt::new(4);
+    (0..count)
+        .map(|k| {
+            let (c, s) = cos_sin_reduced(k % n, n, &quarter_pi, work);
+            (round_shift(&c, GUARD_BITS), round_shift(&s, GUARD_BITS))
+        })
+        .collect()
+}
+
+/// `(cos θ, sin θ)` at working precision for `θ = 2π·k/n`, `k < n`.
+fn cos_sin_reduced(k: u64, n: u64, quarter_pi: &BigInt, work: u32) -> (BigInt, BigInt) {
+    // θ = (octant + r/n)·π/4 with 0 <= r < n
+    let eighths = 8 * k as u128;
+    let octant = (eighths / n as u128) as u64;
+    let r = (eighths % n as u128) as u64;
+    let phi = &(quarter_pi * &BigInt::new(r as i64)) / &BigInt::new(n as i64);
+
+    // For odd octants, reflect: cos/sin of (π/4 - φ) after swapping.
+    let (c, s) = if octant % 2 == 0 {
+        cos_sin_small(&phi, work)
+    } else {
+        let (c, s) = cos_sin_small(&(quarter_pi - &phi), work);
+        (s, c)
+    };
+    match octant / 2 {
+        0 => (c, s),
+        1 => (-&s, c),
+        2 => (-&c, -&s),
+        _ => (s, -&c),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cos_sin_turn(k: u64, n: u64, bits: u32) -> (BigInt, BigInt) {
+        cos_sin_turns(n, k + 1, bits).pop().unwrap()
+    }
+
+    #[test]
+    fn test_pi_digits() {
+        // π·10^30 from 110 bits of precision
+        let pi = pi_scaled(110);
+        let digits = &(&pi * &BigInt::new(10).pow(30)) / &pow2(110);
+        assert_eq!(digits.to_string(), "3141592653589793238462643383279");
+    }
+
+    #[test]
+    fn test_cos_sin_turn_matches_f64() {
+        let bits = 40;
+        let scale = (1u64 << bits) as f64;
+        for n in [3u64, 5, 7, 8, 12, 17] {
+            for k in 0..n {
+                let (c, s) = cos_sin_turn(k, n, bits);
+                let theta = 2.0 * std::f64::consts::PI * k as f64 / n as f64;
+                let to_f64 = |v: &BigInt| v.to_string().parse::<f64>().unwrap() / scale;
+                assert!((to_f64(&c) - theta.cos()).abs() < 1e-9, "cos {}/{}", k, n);
+                assert!((to_f64(&s) - theta.sin()).abs() < 1e-9, "sin {}/{}", k, n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cos_sin_turn_exact_angles() {
+        let one = pow2(20);
+        let zero = BigInt::new(0);
+        assert_eq!(cos_sin_turn(0, 4, 20), (one.clone(), zero.clone()));
+        assert_eq!(cos_sin_turn(1, 4, 20), (zero.clone(), one.clone()));
+        assert_eq!(cos_sin_turn(3, 4, 20), (zero, -&one));
+        let (c, s) = cos_sin_turn(1, 8, 20);
+        assert_eq!(c, s);
+    }
+}
@@ -0,0 +1,826 @@
+//! Configurable text, LaTeX and MathML output for Gaussian integers, and
+//! English words and Roman numerals for integers.
+//!
+//! [`FormatOptions`] is a small builder; its default reproduces the
+//! `Display` output, and each setter changes one aspect of the layout.
+//! Scientific and engineering notation for huge integers round from the
+//! leading digits only.
+
+use crate::error::{Error, ParseBigIntError};
+use crate::gauss_int::round_div;
+use crate::{BigInt, GaussInt};
+
+/// Layout options for [`GaussInt::format_with`].
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::format::FormatOptions;
+///
+/// let z = GaussInt::from_i64(3, -4);
+/// assert_eq!(z.format_with(&FormatOptions::new()), z.to_string());
+/// assert_eq!(z.format_with(&FormatOptions::engineering()), "3 - j4");
+/// let opts = FormatOptions::new().spaced(true).parenthesize(true);
+/// assert_eq!(z.format_with(&opts), "(3 - 4i)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    unit: String,
+    unit_first: bool,
+    spaced: bool,
+    show_both_parts: bool,
+    parenthesize: bool,
+    grouping: Option<(String, usize)>,
+    sig_figs: Option<u32>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            unit: "i".to_string(),
+            unit_first: false,
+            spaced: false,
+            show_both_parts: false,
+            parenthesize: false,
+            grouping: None,
+            sig_figs: None,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Options matching the `Display` output, e.g. `3-4i`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Electrical-engineering style: `j` written before the coefficient,
+    /// with spaces around the sign, e.g. `3 + j4`.
+    pub fn engineering() -> Self {
+        Self::new()
+            .imaginary_unit("j")
+            .unit_first(true)
+            .spaced(true)
+    }
+
+    /// Sets the symbol for the imaginary unit; the default is `i`.
+    pub fn imaginary_unit(mut self, unit: &str) -> Self {
+        self.unit = unit.to_string();
+        self
+    }
+
+    /// Writes the unit before the imaginary coefficient (`j4`) instead of
+    /// after it (`4j`).
+    pub fn unit_first(mut self, yes: bool) -> Self {
+        self.unit_first = yes;
+        self
+    }
+
+    /// Puts spaces around the sign joining the two parts.
+    pub fn spaced(mut self, yes: bool) -> Self {
+        self.spaced = yes;
+        self
+    }
+
+    /// Always writes both parts, including zeros, e.g. `0+1i` and `5+0i`.
+    pub fn show_both_parts(mut self, yes: bool) -> Self {
+        self.show_both_parts = yes;
+        self
+    }
+
+    /// Wraps the output in parentheses whenever it has two parts.
+    pub fn parenthesize(mut self, yes: bool) -> Self {
+        self.parenthesize = yes;
+        self
+    }
+
+    /// Inserts `separator` between every `size` digits, counting from the
+    /// right, e.g. `1_234_567` or `1,234,567`. Applies to each component.
+    ///
+    /// Panics if `size` is zero.
+    pub fn group_digits(mut self, separator: &str, size: usize) -> Self {
+        assert!(size > 0, "digit group size must be positive");
+        self.grouping = Some((separator.to_string(), size));
+        self
+    }
+
+    /// Writes each component in scientific notation with `sig_figs`
+    /// significant digits, e.g. `1.2346e+29` in plain text and
+    /// `1.2346 \cdot 10^{29}` in LaTeX. Digit grouping is then ignored,
+    /// and plain text puts a space before the imaginary unit.
+    ///
+    /// Panics if `sig_figs` is zero.
+    pub fn scientific(mut self, sig_figs: u32) -> Self {
+        assert!(sig_figs > 0, "sig_figs must be positive");
+        self.sig_figs = Some(sig_figs);
+        self
+    }
+
+    /// Formats a single integer. Only digit grouping and scientific
+    /// notation apply.
+    pub fn format_big_int(&self, x: &BigInt) -> String {
+        self.render(&[Term::real(x)], Markup::Plain)
+    }
+
+    /// Formats `z` with these options.
+    pub fn format(&self, z: &GaussInt) -> String {
+        self.render(&self.terms(z), Markup::Plain)
+    }
+
+    /// Renders `z` as LaTeX math-mode source, e.g. `3 + 4\,i`.
+    pub fn latex(&self, z: &GaussInt) -> String {
+        self.render(&self.terms(z), Markup::Latex)
+    }
+
+    /// Renders a single integer as LaTeX math-mode source.
+    pub fn latex_big_int(&self, x: &BigInt) -> String {
+        self.render(&[Term::real(x)], Markup::Latex)
+    }
+
+    /// Renders `z` as a presentation MathML `<math>` element.
+    pub fn mathml(&self, z: &GaussInt) -> String {
+        format!(
+            "<math>{}</math>",
+            self.render(&self.terms(z), Markup::MathMl)
+        )
+    }
+
+    /// Renders a single integer as a presentation MathML `<math>` element.
+    pub fn mathml_big_int(&self, x: &BigInt) -> String {
+        format!(
+            "<math>{}</math>",
+            self.render(&[Term::real(x)], Markup::MathMl)
+        )
+    }
+
+    /// Splits `z` into the terms to write. As in `Display`, a zero part is
+    /// dropped and an imaginary coefficient of one is left implicit when
+    /// the term stands alone, unless both parts are always shown.
+    fn terms<'a>(&self, z: &'a GaussInt) -> Vec<Term<'a>> {
+        let (re, im) = (z.real(), z.imag());
+        if !self.show_both_parts {
+            if im.is_zero() {
+                return vec![Term::real(re)];
+            }
+            if re.is_zero() {
+                let implicit_one = im.cmp_abs(&BigInt::ONE).is_eq();
+                return vec![Term::imaginary(im, implicit_one)];
+            }
+        }
+        vec![Term::real(re), Term::imaginary(im, false)]
+    }
+
+    fn render(&self, terms: &[Term], markup: Markup) -> String {
+        let mut out = String::new();
+        for (k, term) in terms.iter().enumerate() {
+            let negative = term.value.is_negative();
+            match (k, negative) {
+                (0, false) => {}
+                (0, true) => out.push_str(&markup.operator("-", false)),
+                (_, _) => {
+                    let sign = if negative { "-" } else { "+" };
+                    out.push_str(&markup.operator(sign, self.spaced || markup != Markup::Plain));
+                }
+            }
+            out.push_str(&self.term(term, markup));
+        }
+        if self.parenthesize && terms.len() > 1 {
+            let (open, close) = markup.parentheses();
+            out = format!("{}{}{}", open, out, close);
+        }
+        out
+    }
+
+    /// Writes one term without its sign.
+    fn term(&self, term: &Term, markup: Markup) -> String {
+        if !term.imaginary {
+            return self.magnitude(term.value, markup);
+        }
+        let unit = markup.unit(&self.unit);
+        if term.implicit_one {
+            return unit;
+        }
+        let coefficient = self.magnitude(term.value, markup);
+        let (first, second) = if self.unit_first {
+            (unit, coefficient)
+        } else {
+            (coefficient, unit)
+        };
+        // An exponent must not run into the unit, as in `4.56e+449 i`
+        let joiner = match (markup, self.sig_figs) {
+            (Markup::Plain, Some(_)) => " ",
+            _ => markup.juxtapose(),
+        };
+        format!("{}{}{}", first, joiner, second)
+    }
+
+    /// Writes `|x|` in this markup, honoring grouping and scientific notation.
+    fn magnitude(&self, x: &BigInt, markup: Markup) -> String {
+        let magnitude = x.abs();
+        let Some(sig_figs) = self.sig_figs else {
+            let digits = magnitude.to_string();
+            let digits = match (&self.grouping, markup) {
+                (Some((separator, size)), Markup::Plain) => group(&digits, separator, *size),
+                _ => digits,
+            };
+            return markup.number(&digits);
+        };
+        let text = exponent_notation(&magnitude, sig_figs, 1);
+        let (mantissa, exponent) = text.split_once("e+").expect("exponent marker");
+        match markup {
+            Markup::Plain => text.clone(),
+            Markup::Latex if exponent == "0" => mantissa.to_string(),
+            Markup::Latex => format!("{} \\cdot 10^{{{}}}", mantissa, exponent),
+            Markup::MathMl if exponent == "0" => markup.number(mantissa),
+            Markup::MathMl => format!(
+                "<mrow><mn>{}</mn><mo>&#x22C5;</mo><msup><mn>10</mn><mn>{}</mn></msup></mrow>",
+                mantissa, exponent
+            ),
+        }
+    }
+}
+
+/// One signed part of a formatted value.
+struct Term<'a> {
+    value: &'a BigInt,
+    imaginary: bool,
+    implicit_one: bool,
+}
+
+impl<'a> Term<'a> {
+    fn real(value: &'a BigInt) -> Self {
+        Term {
+            value,
+            imaginary: false,
+            implicit_one: false,
+        }
+    }
+
+    fn imaginary(value: &'a BigInt, implicit_one: bool) -> Self {
+        Term {
+            value,
+            imaginary: true,
+            implicit_one,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Markup {
+    Plain,
+    Latex,
+    MathMl,
+}
+
+impl Markup {
+    fn operator(self, op: &str, spaced: bool) -> String {
+        match self {
+            Markup::Plain | Markup::Latex if spaced => format!(" {} ", op),
+            Markup::Plain | Markup::Latex => op.to_string(),
+            Markup::MathMl => format!("<mo>{}</mo>", op),
+        }
+    }
+
+    fn number(self, digits: &str) -> String {
+        match self {
+            Markup::MathMl => format!("<mn>{}</mn>", digits),
+            _ => digits.to_string(),
+        }
+    }
+
+    fn unit(self, unit: &str) -> String {
+        match self {
+            Markup::MathMl => format!("<mi>{}</mi>", unit),
+            _ => unit.to_string(),
+        }
+    }
+
+    /// The separator written between a coefficient and the unit.
+    fn juxtapose(self) -> &'static str {
+        match self {
+            Markup::Plain => "",
+            Markup::Latex => "\\,",
+            Markup::MathMl => "<mo>&#x2062;</mo>",
+        }
+    }
+
+    fn parentheses(self) -> (&'static str, &'static str) {
+        match self {
+            Markup::Plain => ("(", ")"),
+            Markup::Latex => ("\\left(", "\\right)"),
+            Markup::MathMl => ("<mrow><mo>(</mo>", "<mo>)</mo></mrow>"),
+        }
+    }
+}
+
+/// Rounds `x` to `sig_figs` significant digits and writes it as
+/// `m.mmm e+k` with the exponent a multiple of `step` (1 for scientific,
+/// 3 for engineering notation).
+///
+/// Only the `sig_figs` leading digits are ever converted to a string.
+pub(crate) fn exponent_notation(x: &BigInt, sig_figs: u32, step: u64) -> String {
+    assert!(sig_figs > 0, "sig_figs must be positive");
+    let sign = if x.is_negative() { "-" } else { "" };
+    let magnitude = x.abs();
+    let (mantissa, exponent) = match magnitude.ilog10() {
+        None => (BigInt::ZERO, 0),
+        Some(e) => round_to_sig_figs(&magnitude, e, sig_figs),
+    };
+    let mut digits = mantissa.to_string();
+    let width = sig_figs as usize;
+    digits.extend(std::iter::repeat_n('0', width.saturating_sub(digits.len())));
+
+    let shown = exponent - exponent % step;
+    let int_len = (exponent % step) as usize + 1;
+    if digits.len() < int_len {
+        digits.extend(std::iter::repeat_n('0', int_len - digits.len()));
+    }
+    let (int_part, frac_part) = digits.split_at(int_len);
+    if frac_part.is_empty() {
+        format!("{}{}e+{}", sign, int_part, shown)
+    } else {
+        format!("{}{}.{}e+{}", sign, int_part, frac_part, shown)
+    }
+}
+
+/// Rounds a positive `x = 10^e·(1.ddd…)` to the integer mantissa of its
+/// `sig_figs` leading digits, ties away from zero, returning the mantissa
+/// and the decimal exponent after any carry.
+fn round_to_sig_figs(x: &BigInt, e: u64, sig_figs: u32) -> (BigInt, u64) {
+    let ten = BigInt::new(10);
+    let kept = u64::from(sig_figs) - 1;
+    if e <= kept {
+        let scale = ten.pow((kept - e) as u32);
+        return (x * &scale, e);
+    }
+    let divisor = ten.pow((e - kept) as u32);
+    let mantissa = round_div(x, &divisor);
+    if mantissa == ten.pow(sig_figs) {
+        (&mantissa / &ten, e + 1)
+    } else {
+        (mantissa, e)
+    }
+}
+
+/// Short-scale names for `1000^k`, `k = 1..=21`, ending at vigintillion
+/// (`10^63`).
+const SCALE_WORDS: [&str; 21] = [
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+    "duodecillion",
+    "tredecillion",
+    "quattuordecillion",
+    "quindecillion",
+    "sexdecillion",
+    "septendecillion",
+    "octodecillion",
+    "novemdecillion",
+    "vigintillion",
+];
+
+const ONES_WORDS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS_WORDS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// American English cardinal words for `x`, or `None` if `|x| >= 10^66`.
+pub(crate) fn cardinal_words(x: &BigInt) -> Option<String> {
+    if x.is_zero() {
+        return Some(ONES_WORDS[0].to_string());
+    }
+    let groups = x.to_radix_digits(1000);
+    if groups.len() > SCALE_WORDS.len() + 1 {
+        return None;
+    }
+    let mut words: Vec<String> = Vec::new();
+    if x.is_negative() {
+        words.push("negative".to_string());
+    }
+    for (k, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        words.push(words_below_thousand(group));
+        if k > 0 {
+            words.push(SCALE_WORDS[k - 1].to_string());
+        }
+    }
+    Some(words.join(" "))
+}
+
+/// Words for `1..=999`, e.g. `three hundred forty-five`.
+fn words_below_thousand(n: u32) -> String {
+    let (hundreds, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let mut out = String::new();
+    if hundreds > 0 {
+        out.push_str(ONES_WORDS[hundreds]);
+        out.push_str(" hundred");
+        if rest > 0 {
+            out.push(' ');
+        }
+    }
+    match rest {
+        0 => {}
+        1..=19 => out.push_str(ONES_WORDS[rest]),
+        _ => {
+            out.push_str(TENS_WORDS[rest / 10]);
+            if rest % 10 > 0 {
+                out.push('-');
+                out.push_str(ONES_WORDS[rest % 10]);
+            }
+        }
+    }
+    out
+}
+
+/// Roman numeral symbols with their values, subtractive pairs included,
+/// largest first.
+const ROMAN_SYMBOLS: [(&str, u32); 13] = [
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+/// The largest value with a classical Roman numeral, `MMMCMXCIX`.
+const ROMAN_MAX: u32 = 3999;
+
+/// Each decimal place as `(value, one, five, ten)` symbols, largest first;
+/// thousands have no five or ten and stop at three.
+const ROMAN_PLACES: [(u32, &str, &str, &str); 4] = [
+    (1000, "M", "", ""),
+    (100, "C", "D", "M"),
+    (10, "X", "L", "C"),
+    (1, "I", "V", "X"),
+];
+
+/// Spells the digit `d` in `1..=9` of one decimal place.
+fn roman_digit(d: u32, one: &str, five: &str, ten: &str) -> String {
+    match d {
+        1..=3 => one.repeat(d as usize),
+        4 => format!("{}{}", one, five),
+        5..=8 => format!("{}{}", five, one.repeat(d as usize - 5)),
+        _ => format!("{}{}", one, ten),
+    }
+}
+
+/// The canonical Roman numeral for `1..=3999`.
+pub(crate) fn to_roman(x: &BigInt) -> Result<String, Error> {
+    let out_of_range = Error::DomainError("Roman numerals cover 1 to 3999");
+    let mut n =
+        u32::try_from(num_bigint::BigInt::from(x.clone())).map_err(|_| out_of_range.clone())?;
+    if !(1..=ROMAN_MAX).contains(&n) {
+        return Err(out_of_range);
+    }
+    let mut out = String::new();
+    for (symbol, value) in ROMAN_SYMBOLS {
+        while n >= value {
+            out.push_str(symbol);
+            n -= value;
+        }
+    }
+    Ok(out)
+}
+
+/// Parses a canonical Roman numeral in either case; non-canonical forms
+/// such as `IIII` or `IC` are rejected at the first symbol that does not
+/// fit.
+///
+/// Reads one decimal place at a time, taking the longest digit spelling
+/// that matches, so at most three `M`s are ever consumed.
+pub(crate) fn from_roman(s: &str) -> Result<BigInt, Error> {
+    // ASCII case changes keep byte offsets, so positions in `upper` are
+    // positions in `s`
+    let upper = s.to_ascii_uppercase();
+    let mut pos = 0;
+    let mut n = 0;
+    for (value, one, five, ten) in ROMAN_PLACES {
+        let max_digit = if five.is_empty() { 3 } else { 9 };
+        let longest = (1..=max_digit)
+            .map(|d| (d, roman_digit(d, one, five, ten)))
+            .filter(|(_, spelling)| upper[pos..].starts_with(spelling.as_str()))
+            .max_by_key(|(_, spelling)| spelling.len());
+        if let Some((d, spelling)) = longest {
+            pos += spelling.len();
+            n += d * value;
+        }
+    }
+    if pos < s.len() || n == 0 {
+        return Err(ParseBigIntError::at(s, pos).into());
+    }
+    Ok(BigInt::new(i64::from(n)))
+}
+
+/// Inserts `separator` into a decimal string every `size` digits from the
+/// right, keeping any leading minus sign in front.
+fn group(digits: &str, separator: &str, size: usize) -> String {
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", digits),
+    };
+    let mut out = String::with_capacity(digits.len() + digits.len() / size * separator.len() + 1);
+    out.push_str(sign);
+    for (k, c) in digits.chars().enumerate() {
+        if k > 0 && (digits.len() - k) % size == 0 {
+            out.push_str(separator);
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_display() {
+        let opts = FormatOptions::new();
+        for (a, b) in [
+            (0, 0),
+            (3, 4),
+            (3, -4),
+            (0, 1),
+            (0, -1),
+            (0, 7),
+            (-2, 0),
+            (5, 1),
+            (5, -1),
+        ] {
+            let z = GaussInt::from_i64(a, b);
+            assert_eq!(z.format_with(&opts), z.to_string());
+        }
+    }
+
+    #[test]
+    fn test_engineering_and_options() {
+        let eng = FormatOptions::engineering();
+        let f = |a, b, o: &FormatOptions| GaussInt::from_i64(a, b).format_with(o);
+        assert_eq!(f(3, 4, &eng), "3 + j4");
+        assert_eq!(f(3, 1, &eng), "3 + j1");
+        assert_eq!(f(0, 1, &eng), "j");
+        assert_eq!(f(0, -4, &eng), "-j4");
+        assert_eq!(f(7, 0, &eng), "7");
+
+        let both = FormatOptions::new().show_both_parts(true);
+        assert_eq!(f(0, 1, &both), "0+1i");
+        assert_eq!(f(5, 0, &both), "5+0i");
+        assert_eq!(f(0, 0, &both.clone().imaginary_unit("j")), "0+0j");
+
+        let paren = FormatOptions::new().parenthesize(true);
+        assert_eq!(f(-1, -2, &paren), "(-1-2i)");
+        // A single term needs no parentheses
+        assert_eq!(f(0, -2, &paren), "-2i");
+        assert_eq!(f(0, 2, &both.parenthesize(true)), "(0+2i)");
+    }
+
+    #[test]
+    fn test_digit_grouping() {
+        let opts = FormatOptions::new().group_digits("_", 3);
+        assert_eq!(
+            opts.format_big_int(&BigInt::new(1234567890)),
+            "1_234_567_890"
+        );
+        assert_eq!(opts.format_big_int(&BigInt::new(-123456)), "-123_456");
+        assert_eq!(opts.format_big_int(&BigInt::new(999)), "999");
+        assert_eq!(BigInt::new(0).format_with(&opts), "0");
+        let commas = FormatOptions::new().group_digits(",", 3).spaced(true);
+        let z = GaussInt::from_i64(1234567, -7654321);
+        assert_eq!(z.format_with(&commas), "1,234,567 - 7,654,321i");
+        let fours = FormatOptions::new().group_digits(" ", 4);
+        assert_eq!(
+            BigInt::new(10).pow(12).format_with(&fours),
+            "1 0000 0000 0000"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "digit group size must be positive")]
+    fn test_zero_group_size_panics() {
+        let _ = FormatOptions::new().group_digits(",", 0);
+    }
+
+    #[test]
+    fn test_scientific_and_engineering_notation() {
+        let x = BigInt::from_string("123456789012345678901234567890").unwrap();
+        assert_eq!(x.to_scientific(5), "1.2346e+29");
+        assert_eq!((-&x).to_scientific(1), "-1e+29");
+        assert_eq!(x.to_engineering(5), "123.46e+27");
+        assert_eq!(BigInt::new(12345).to_engineering(1), "10e+3");
+        assert_eq!(BigInt::new(12345).to_engineering(2), "12e+3");
+        assert_eq!(BigInt::new(7).to_scientific(3), "7.00e+0");
+        assert_eq!(BigInt::new(0).to_scientific(3), "0.00e+0");
+        assert_eq!(BigInt::new(0).to_engineering(1), "0e+0");
+        // Rounding can carry into a new leading digit
+        assert_eq!(BigInt::new(99960).to_scientific(3), "1.00e+5");
+        assert_eq!(BigInt::new(999_600).to_engineering(3), "1.00e+6");
+        assert_eq!(BigInt::new(-15).to_scientific(1), "-2e+1");
+        let huge = BigInt::new(7).pow(5000);
+        let exact = huge.to_string();
+        let sci = huge.to_scientific(3);
+        assert!(sci.ends_with(&format!("e+{}", exact.len() - 1)));
+        assert_eq!(&sci[..1], &exact[..1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "sig_figs must be positive")]
+    fn test_zero_sig_figs_panics() {
+        let _ = BigInt::new(5).to_scientific(0);
+    }
+
+    #[test]
+    fn test_latex_output() {
+        let f = |a, b| GaussInt::from_i64(a, b).to_latex();
+        assert_eq!(f(3, 4), "3 + 4\\,i");
+        assert_eq!(f(3, -1), "3 - 1\\,i");
+        assert_eq!(f(-3, 0), "-3");
+        assert_eq!(f(0, 1), "i");
+        assert_eq!(f(0, -7), "-7\\,i");
+        assert_eq!(BigInt::new(-42).to_latex(), "-42");
+
+        let eng = FormatOptions::engineering().parenthesize(true);
+        assert_eq!(
+            eng.latex(&GaussInt::from_i64(3, 4)),
+            "\\left(3 + j\\,4\\right)"
+        );
+
+        let sci = FormatOptions::new().scientific(3);
+        let big = BigInt::from_string("123456789012345678901234567890").unwrap();
+        let z = GaussInt::new(big.clone(), BigInt::new(-5));
+        assert_eq!(sci.latex(&z), "1.23 \\cdot 10^{29} - 5.00\\,i");
+        assert_eq!(sci.latex_big_int(&big), "1.23 \\cdot 10^{29}");
+        assert_eq!(sci.format(&z), "1.23e+29-5.00e+0 i");
+    }
+
+    #[test]
+    fn test_mathml_output() {
+        assert_eq!(
+            GaussInt::from_i64(3, -4).to_mathml(),
+            "<math><mn>3</mn><mo>-</mo><mn>4</mn><mo>&#x2062;</mo><mi>i</mi></math>"
+        );
+        assert_eq!(
+            GaussInt::from_i64(0, 1).to_mathml(),
+            "<math><mi>i</mi></math>"
+        );
+        assert_eq!(
+            BigInt::new(-42).to_mathml(),
+            "<math><mo>-</mo><mn>42</mn></math>"
+        );
+        let sci = FormatOptions::new().scientific(2);
+        assert_eq!(
+            sci.mathml_big_int(&BigInt::new(12345)),
+            "<math><mrow><mn>1.2</mn><mo>&#x22C5;</mo><msup><mn>10</mn><mn>4</mn></msup></mrow></math>"
+        );
+        let paren = FormatOptions::new().parenthesize(true);
+        assert_eq!(
+            paren.mathml(&GaussInt::from_i64(1, 1)),
+            "<math><mrow><mo>(</mo><mn>1</mn><mo>+</mo><mn>1</mn><mo>&#x2062;</mo><mi>i</mi><mo>)</mo></mrow></math>"
+        );
+    }
+
+    #[test]
+    fn test_approx_string() {
+        let a = &BigInt::new(123) * &BigInt::new(10).pow(448);
+        let b = &BigInt::new(-456) * &BigInt::new(10).pow(447);
+        let z = GaussInt::new(a.clone(), b.clone());
+        assert_eq!(z.to_approx_string(3), "≈1.23e+450 - 4.56e+449 i");
+        assert_eq!(
+            GaussInt::new(BigInt::ZERO, a).to_approx_string(2),
+            "≈1.2e+450 i"
+        );
+        assert_eq!(
+            GaussInt::new(b, BigInt::ZERO).to_approx_string(1),
+            "≈-5e+449"
+        );
+        assert_eq!(GaussInt::from_i64(0, -1).to_approx_string(2), "≈-i");
+        assert_eq!(GaussInt::ZERO.to_approx_string(2), "≈0.0e+0");
+        // The exact form would be thousands of characters
+        let huge = GaussInt::from_i64(3, 7).pow_u32(3000);
+        assert!(huge.to_string().len() > 4000);
+        assert!(huge.to_approx_string(4).len() < 40);
+    }
+
+    #[test]
+    fn test_cardinal_words() {
+        let cases = [
+            (0i128, "zero"),
+            (7, "seven"),
+            (15, "fifteen"),
+            (40, "forty"),
+            (99, "ninety-nine"),
+            (100, "one hundred"),
+            (101, "one hundred one"),
+            (-342, "negative three hundred forty-two"),
+            (1_000, "one thousand"),
+            (1_000_010, "one million ten"),
+            (
+                12_000_000_000_000_000_345,
+                "twelve quintillion three hundred forty-five",
+            ),
+        ];
+        for (n, words) in cases {
+            assert_eq!(
+                cardinal_words(&BigInt::from(num_bigint::BigInt::from(n))).as_deref(),
+                Some(words)
+            );
+        }
+        let top = &BigInt::new(10).pow(66) - &BigInt::ONE;
+        let words = cardinal_words(&-&top).unwrap();
+        assert!(words.starts_with("negative nine hundred ninety-nine vigintillion"));
+        assert!(words.ends_with("thousand nine hundred ninety-nine"));
+        assert_eq!(cardinal_words(&BigInt::new(10).pow(66)), None);
+    }
+
+    #[test]
+    fn test_roman_numerals() {
+        for (n, roman) in [
+            (1, "I"),
+            (4, "IV"),
+            (14, "XIV"),
+            (1994, "MCMXCIV"),
+            (3999, "MMMCMXCIX"),
+        ] {
+            assert_eq!(to_roman(&BigInt::new(n)).as_deref(), Ok(roman));
+            assert_eq!(from_roman(roman), Ok(BigInt::new(n)));
+        }
+        for n in 1..=3999 {
+            let roman = to_roman(&BigInt::new(n)).unwrap();
+            assert_eq!(from_roman(&roman.to_lowercase()), Ok(BigInt::new(n)));
+        }
+        for n in [
+            BigInt::ZERO,
+            BigInt::new(-5),
+            BigInt::new(4000),
+            BigInt::new(10).pow(30),
+        ] {
+            assert!(matches!(to_roman(&n), Err(Error::DomainError(_))));
+        }
+        for (bad, offset) in [
+            ("", 0),
+            ("IIII", 3),
+            ("IC", 1),
+            ("VV", 1),
+            ("MMMM", 3),
+            ("XM", 1),
+            ("IIV", 2),
+            ("X I", 1),
+            ("Q", 0),
+            ("mcmxcivi", 7),
+        ] {
+            assert_eq!(
+                from_roman(bad),
+                Err(ParseBigIntError::at(bad, offset).into()),
+                "{}",
+                bad
+            );
+        }
+        // A long run of `M`s stops after the third
+        let many = "M".repeat(5_000_000);
+        assert_eq!(
+            from_roman(&many),
+            Err(ParseBigIntError::at(&many, 3).into())
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! The finite field GF(p²) as Gaussian integers modulo a prime `p ≡ 3 (mod 4)`.
+//!
+//! For such primes `x² + 1` is irreducible over GF(p), so `a + b·i` with
+//! `a, b ∈ GF(p)` is a field with p² elements and exactly the layout of a
+//! [`GaussInt`] with reduced components.
+
+use crate::{BigInt, GaussInt};
+use num_traits::One;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// An element `a + b·i` of GF(p²), with `0 <= a, b < p`.
+///
+/// Binary operators panic if the operands belong to different fields.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::{BigInt, GaussInt};
+/// use gauss_int::fp2::Fp2;
+///
+/// let p = BigInt::new(7);
+/// let z = Fp2::new(GaussInt::from_i64(2, 3), p.clone()).unwrap();
+/// let one = Fp2::new(GaussInt::from_i64(1, 0), p).unwrap();
+/// assert_eq!(&z * &z.inv().unwrap(), one);
+/// assert_eq!(z.frobenius().value().to_string(), "2+4i");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fp2 {
+    value: GaussInt,
+    p: BigInt,
+}
+
+impl Fp2 {
+    /// Creates `value mod p`.
+    ///
+    /// Returns `None` unless `p` is a prime with `p ≡ 3 (mod 4)`.
+    pub fn new(value: GaussInt, p: BigInt) -> Option<Self> {
+        if p.rem_euclid(&BigInt::new(4)) != BigInt::new(3) || !p.is_prime() {
+            return None;
+        }
+        Some(Fp2 {
+            value: reduce(&value, &p),
+            p,
+        })
+    }
+
+    /// Returns the canonical representative with components in `0..p`.
+    pub fn value(&self) -> &GaussInt {
+        &self.value
+    }
+
+    /// Returns the characteristic `p`.
+    pub fn characteristic(&self) -> &BigInt {
+        &self.p
+    }
+
+    /// Returns `true` for the additive identity of the field.
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// The Frobenius automorphism `z ↦ z^p`.
+    ///
+    /// Since `i^p = -i` for `p ≡ 3 (mod 4)`, this is complex conjugation.
+    pub fn frobenius(&self) -> Self {
+        self.with_value(reduce(&self.value.conjugate(), &self.p))
+    }
+
+    /// Returns the multiplicative inverse, or `None` for zero.
+    ///
+    /// Uses `z⁻¹ = conj(z) / N(z)`, where the norm lies in GF(p).
+    pub fn inv(&self) -> Option<Self> {
+        let norm = self.value.norm() % self.p.clone();
+        let norm_inv = norm.mod_inv(&self.p)?;
+        let conj = self.value.conjugate();
+        Some(self.with_value(reduce(
+            &GaussInt::new(conj.real() * &norm_inv, conj.imag() * &norm_inv),
+            &self.p,
+        )))
+    }
+
+    /// Raises to the power `exp` by square-and-multiply.
+    ///
+    /// Negative exponents invert first; returns `None` for zero to a
+    /// negative power.
+    pub fn pow(&self, exp: &BigInt) -> Option<Self> {
+        if exp.is_negative() {
+            return self.inv()?.pow(&-exp);
+        }
+        let mut result = self.with_value(GaussInt::one());
+        let mut base = self.clone();
+        let mut e = exp.clone();
+        let two = BigInt::new(2);
+        while !e.is_zero() {
+            let (q, r) = e.div_rem(&two);
+            if !r.is_zero() {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            e = q;
+        }
+        Some(result)
+    }
+
+    fn with_value(&self, value: GaussInt) -> Self {
+        Fp2 {
+            value,
+            p: self.p.clone(),
+        }
+    }
+
+    fn check_field(&self, other: &Self) {
+        assert!(
+            self.p == other.p,
+            "field mismatch: GF({}²) vs GF({}²)",
+            self.p,
+            other.p
+        );
+    }
+}
+
+/// Reduces both components of `z` into `0..p`.
+fn reduce(z: &GaussInt, p: &BigInt) -> GaussInt {
+    GaussInt::new(z.real().rem_euclid(p), z.imag().rem_euclid(p))
+}
+
+impl fmt::Display for Fp2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.p)
+    }
+}
+
+// --- Neg ---
+
+impl Neg for &Fp2 {
+    type Output = Fp2;
+
+    fn neg(self) -> Fp2 {
+        self.with_value(reduce(&-&self.value, &self.p))
+    }
+}
+
+impl Neg for Fp2 {
+    type Output = Fp2;
+
+    fn neg(self) -> Fp2 {
+        -&self
+    }
+}
+
+// --- Add ---
+
+impl Add for &Fp2 {
+    type Output = Fp2;
+
+    fn add(self, other: &Fp2) -> Fp2 {
+        self.check_field(other);
+        self.with_value(reduce(&(&self.value + &other.value), &self.p))
+    }
+}
+
+impl Add for Fp2 {
+    type Output = Fp2;
+
+    fn add(self, other: Fp2) -> Fp2 {
+        &self + &other
+    }
+}
+
+impl Add<&Fp2> for Fp2 {
+    type Output = Fp2;
+
+    fn add(self, other: &Fp2) -> Fp2 {
+        &self + other
+    }
+}
+
+impl Add<Fp2> for &Fp2 {
+    type Output = Fp2;
+
+    fn add(self, other: Fp2) -> Fp2 {
+        self + &other
+    }
+}
+
+// --- Sub ---
+
+impl Sub for &Fp2 {
+    type Output = Fp2;
+
+    fn sub(self, other: &Fp2) -> Fp2 {
+        self.check_field(other);
+        self.with_value(reduce(&(&self.value - &other.value), &self.p))
+    }
+}
+
+impl Sub for Fp2 {
+    type Output = Fp2;
+
+    fn sub(self, other: Fp2) -> Fp2 {
+        &self - &other
+    }
+}
+
+impl Sub<&Fp2> for Fp2 {
+    type Output = Fp2;
+
+    fn sub(self, other: &Fp2) -> Fp2 {
+        &self - other
+    }
+}
+
+impl Sub<Fp2> for &Fp2 {
+    type Output = Fp2;
+
+    fn sub(self, other: Fp2) -> Fp2 {
+        self - &other
+    }
+}
+
+// --- Mul ---
+
+impl Mul for &Fp2 {
+    type Output = Fp2;
+
+    fn mul(self, other: &Fp2) -> Fp2 {
+        self.check_field(other);
+        self.with_value(reduce(&(&self.value * &other.value), &self.p))
+    }
+}
+
+impl Mul for Fp2 {
+    type Output = Fp2;
+
+    fn mul(self, other: Fp2) -> Fp2 {
+        &self * &other
+    }
+}
+
+impl Mul<&Fp2> for Fp2 {
+    type Output = Fp2;
+
+    fn mul(self, other: &Fp2) -> Fp2 {
+        &self * other
+    }
+}
+
+impl Mul<Fp2> for &Fp2 {
+    type Output = Fp2;
+
+    fn mul(self, other: Fp2) -> Fp2 {
+        self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(a: i64, b: i64, p: i64) -> Fp2 {
+        Fp2::new(GaussInt::from_i64(a, b), BigInt::new(p)).unwrap()
+    }
+
+    #[test]
+    fn test_fp2_requires_prime_3_mod_4() {
+        let z = GaussInt::from_i64(1, 1);
+        assert!(Fp2::new(z.clone(), BigInt::new(7)).is_some());
+        assert!(Fp2::new(z.clone(), BigInt::new(5)).is_none()); // 5 ≡ 1 mod 4
+        assert!(Fp2::new(z.clone(), BigInt::new(15)).is_none()); // not prime
+        assert!(Fp2::new(z, BigInt::new(-7)).is_none());
+    }
+
+    #[test]
+    fn test_fp2_reduces_components() {
+        assert_eq!(*f(-1, 10, 7).value(), GaussInt::from_i64(6, 3));
+    }
+
+    #[test]
+    fn test_fp2_arithmetic() {
+        let a = f(2, 5, 11);
+        let b = f(7, 9, 11);
+        assert_eq!(&a + &b, f(9, 3, 11));
+        assert_eq!(&a - &b, f(6, 7, 11));
+        // (2+5i)(7+9i) = 14 - 45 + (18 + 35)i = -31 + 53i
+        assert_eq!(&a * &b, f(-31, 53, 11));
+        assert_eq!(&a + &(-&a), f(0, 0, 11));
+    }
+
+    #[test]
+    fn test_fp2_every_nonzero_element_is_invertible() {
+        let one = f(1, 0, 7);
+        for a in 0..7 {
+            for b in 0..7 {
+                let z = f(a, b, 7);
+                match z.inv() {
+                    Some(inv) => assert_eq!(&z * &inv, one),
+                    None => assert!(z.is_zero()),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fp2_frobenius_matches_pow_p() {
+        let p = BigInt::new(19);
+        for (a, b) in [(3, 4), (0, 1), (18, 7), (5, 0)] {
+            let z = f(a, b, 19);
+            assert_eq!(z.frobenius(), z.pow(&p).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_fp2_multiplicative_group_order() {
+        // The multiplicative group has order p² - 1.
+        let z = f(3, 7, 23);
+        assert_eq!(z.pow(&BigInt::new(23 * 23 - 1)).unwrap(), f(1, 0, 23));
+        assert_eq!(
+            &z.pow(&BigInt::new(-3)).unwrap() * &z.pow(&BigInt::new(3)).unwrap(),
+            f(1, 0, 23)
+        );
+        assert!(f(0, 0, 23).pow(&BigInt::new(-1)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "field mismatch")]
+    fn test_fp2_mismatched_fields() {
+        let _ = f(1, 1, 7) * f(1, 1, 11);
+    }
+}
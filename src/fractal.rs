@@ -0,0 +1,181 @@
+//! Mandelbrot and Julia iteration at arbitrary precision.
+//!
+//! Points are fixed-point complex numbers: a [`GaussInt`] whose components
+//! are scaled by `2^frac_bits`, so `frac_bits` sets the zoom depth that can
+//! be resolved. With `frac_bits = 0` the iteration is exact arithmetic on
+//! Gaussian integers. Each step rounds `z²` back to the working precision,
+//! to nearest with ties away from zero, and adds `c` exactly.
+//!
+//! # Examples
+//!
+//! ```
+//! use gauss_int::fractal::escape_time;
+//! use gauss_int::{BigInt, GaussInt};
+//!
+//! // c = -0.5 + 0.25i with 8 fractional bits; the bailout is |z|² > 4
+//! let c = GaussInt::from_i64(-128, 64);
+//! assert_eq!(escape_time(&c, 8, 100, &BigInt::new(4)), None);
+//! // c = 0.5 + 0.5i escapes after five steps
+//! let c = GaussInt::from_i64(128, 128);
+//! assert_eq!(escape_time(&c, 8, 100, &BigInt::new(4)), Some(5));
+//! ```
+
+use crate::gauss_int::round_div;
+use crate::{BigInt, GaussInt};
+
+/// The orbit `z ← z² + c` of a single point, advanced one step at a time.
+///
+/// Buffers are reused between steps through
+/// [`GaussInt::mul_into`] and [`GaussInt::add_into`].
+#[derive(Debug, Clone)]
+pub struct Orbit {
+    z: GaussInt,
+    c: GaussInt,
+    frac_bits: u32,
+    scale: BigInt,
+    square: GaussInt,
+    iterations: u64,
+}
+
+impl Orbit {
+    /// The Mandelbrot orbit of `c`, starting from `z = 0`.
+    pub fn mandelbrot(c: GaussInt, frac_bits: u32) -> Self {
+        Orbit::julia(GaussInt::ZERO, c, frac_bits)
+    }
+
+    /// The orbit of `z0` for the Julia set with parameter `c`.
+    pub fn julia(z0: GaussInt, c: GaussInt, frac_bits: u32) -> Self {
+        Orbit {
+            z: z0,
+            c,
+            frac_bits,
+            scale: BigInt::TWO.pow(frac_bits),
+            square: GaussInt::ZERO,
+            iterations: 0,
+        }
+    }
+
+    /// The current point, scaled by `2^frac_bits`.
+    pub fn z(&self) -> &GaussInt {
+        &self.z
+    }
+
+    /// The parameter `c`, scaled like [`z`](Self::z).
+    pub fn c(&self) -> &GaussInt {
+        &self.c
+    }
+
+    /// The number of fractional bits in the fixed-point scale.
+    pub fn frac_bits(&self) -> u32 {
+        self.frac_bits
+    }
+
+    /// The number of steps taken so far.
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    /// Performs one step `z ← z² + c`.
+    pub fn step(&mut self) {
+        self.z.mul_into(&self.z, &mut self.square);
+        if self.frac_bits > 0 {
+            self.square = GaussInt::new(
+                round_div(self.square.real(), &self.scale),
+                round_div(self.square.imag(), &self.scale),
+            );
+        }
+        self.square.add_into(&self.c, &mut self.z);
+        self.iterations += 1;
+    }
+
+    /// Returns `true` if `|z|² > bailout_norm`, with `bailout_norm`
+    /// unscaled.
+    pub fn escaped(&self, bailout_norm: &BigInt) -> bool {
+        self.z.norm() > bailout_norm * &(&self.scale * &self.scale)
+    }
+
+    /// Steps until the orbit escapes or `max_iter` steps have been taken
+    /// in total, returning the step count at escape.
+    pub fn run(&mut self, max_iter: u64, bailout_norm: &BigInt) -> Option<u64> {
+        let threshold = bailout_norm * &(&self.scale * &self.scale);
+        while self.iterations < max_iter {
+            self.step();
+            if self.z.norm() > threshold {
+                return Some(self.iterations);
+            }
+        }
+        None
+    }
+}
+
+/// The number of Mandelbrot steps after which `|z|² > bailout_norm`, or
+/// `None` if the orbit of `c` stays bounded for `max_iter` steps.
+///
+/// `c` is scaled by `2^frac_bits`; `bailout_norm` is not.
+pub fn escape_time(
+    c: &GaussInt,
+    frac_bits: u32,
+    max_iter: u64,
+    bailout_norm: &BigInt,
+) -> Option<u64> {
+    Orbit::mandelbrot(c.clone(), frac_bits).run(max_iter, bailout_norm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_integer_orbits() {
+        let four = BigInt::new(4);
+        // -1 cycles 0, -1, 0, …; -2 is fixed after two steps; i cycles too
+        assert_eq!(escape_time(&GaussInt::MINUS_ONE, 0, 1000, &four), None);
+        assert_eq!(
+            escape_time(&GaussInt::from_i64(-2, 0), 0, 1000, &four),
+            None
+        );
+        assert_eq!(escape_time(&GaussInt::I, 0, 1000, &four), None);
+        assert_eq!(escape_time(&GaussInt::ONE, 0, 1000, &four), Some(3));
+
+        let mut orbit = Orbit::julia(GaussInt::from_i64(1, 1), GaussInt::MINUS_I, 0);
+        orbit.step();
+        assert_eq!(orbit.z(), &GaussInt::from_i64(0, 1));
+        assert!(!orbit.escaped(&BigInt::ONE));
+    }
+
+    #[test]
+    fn test_fixed_point_matches_f64() {
+        let bits = 40;
+        let scale = 2f64.powi(bits as i32);
+        for (re, im) in [(0.3, 0.5), (-1.3, 0.1), (0.26, 0.0), (-0.1, 0.9)] {
+            let c = GaussInt::new(
+                BigInt::from_f64(re * scale, crate::RoundingMode::NearestEven).unwrap(),
+                BigInt::from_f64(im * scale, crate::RoundingMode::NearestEven).unwrap(),
+            );
+            let (mut x, mut y, mut f64_time) = (0.0f64, 0.0f64, None);
+            for n in 1..=50 {
+                (x, y) = (x * x - y * y + re, 2.0 * x * y + im);
+                if x * x + y * y > 4.0 {
+                    f64_time = Some(n);
+                    break;
+                }
+            }
+            assert_eq!(
+                escape_time(&c, bits, 50, &BigInt::new(4)),
+                f64_time,
+                "{} {}",
+                re,
+                im
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_resumes() {
+        let c = GaussInt::from_i64(1, 0);
+        let mut orbit = Orbit::mandelbrot(c, 0);
+        assert_eq!(orbit.run(2, &BigInt::new(4)), None);
+        assert_eq!(orbit.iterations(), 2);
+        assert_eq!(orbit.run(10, &BigInt::new(4)), Some(3));
+    }
+}
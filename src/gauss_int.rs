@@ -1,7 +1,14 @@
-use crate::BigInt;
+use crate::error::{Error, ParseGaussIntError};
+use crate::fixed_point;
+use crate::matrix::Matrix;
+use crate::rational::{BigRational, ComplexRational};
+use crate::{BigInt, Exactness, RoundingMode};
+use num_bigint::Sign;
+use num_complex::Complex;
 use num_traits::{One, Zero};
+use std::cmp::Ordering;
 use std::fmt;
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
 
 /// A Gaussian integer a + bi where a, b ∈ ℤ (arbitrary precision integers).
 ///
@@ -9,17 +16,42 @@ use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 /// They form a Euclidean domain, supporting division with remainder and GCD
 /// via the Euclidean algorithm.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(compare(PartialEq), derive(Debug, PartialEq, Eq))
+)]
 pub struct GaussInt {
     real: BigInt,
     imag: BigInt,
 }
 
 impl GaussInt {
-    pub fn new(real: BigInt, imag: BigInt) -> Self {
+    pub const ZERO: GaussInt = GaussInt::from_i64(0, 0);
+    pub const ONE: GaussInt = GaussInt::from_i64(1, 0);
+    /// The imaginary unit `i`.
+    pub const I: GaussInt = GaussInt::from_i64(0, 1);
+    pub const MINUS_ONE: GaussInt = GaussInt::from_i64(-1, 0);
+    pub const MINUS_I: GaussInt = GaussInt::from_i64(0, -1);
+
+    pub const fn new(real: BigInt, imag: BigInt) -> Self {
         GaussInt { real, imag }
     }
 
-    pub fn from_i64(real: i64, imag: i64) -> Self {
+    /// Creates a Gaussian integer from `i64` components.
+    ///
+    /// This is a `const fn`, usable in constants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// const ONE_PLUS_I: GaussInt = GaussInt::from_i64(1, 1);
+    /// assert_eq!(&ONE_PLUS_I * &ONE_PLUS_I, GaussInt::from_i64(0, 2));
+    /// assert_eq!(&GaussInt::I * &GaussInt::I, GaussInt::MINUS_ONE);
+    /// ```
+    pub const fn from_i64(real: i64, imag: i64) -> Self {
         GaussInt {
             real: BigInt::new(real),
             imag: BigInt::new(imag),
@@ -57,31 +89,203 @@ impl GaussInt {
         self.norm() == BigInt::new(1)
     }
 
+    /// The four units `1, i, -1, -i`.
+    pub fn units() -> [Self; 4] {
+        GaussInt::one().associates()
+    }
+
+    /// Returns the unit `u` with `self = u·c`, where `c` is the canonical
+    /// associate from [`normalize_associate`](Self::normalize_associate).
+    /// The unit part of zero is 1.
+    pub fn unit_part(&self) -> Self {
+        // The inverse of a unit is its conjugate
+        self.normalize_associate().1.conjugate()
+    }
+
+    /// Multiplies by `i`: `(a + bi)·i = -b + ai`.
+    pub fn mul_i(&self) -> Self {
+        GaussInt {
+            real: -&self.imag,
+            imag: self.real.clone(),
+        }
+    }
+
+    /// Divides by `i`: `(a + bi)/i = b - ai`.
+    pub fn div_i(&self) -> Self {
+        GaussInt {
+            real: self.imag.clone(),
+            imag: -&self.real,
+        }
+    }
+
+    /// Rotates by `k` quarter turns counter-clockwise (multiplication by
+    /// `i^k`); negative `k` rotates clockwise.
+    pub fn rotate_quarter(&self, k: i32) -> Self {
+        match k.rem_euclid(4) {
+            0 => self.clone(),
+            1 => self.mul_i(),
+            2 => -self,
+            _ => self.div_i(),
+        }
+    }
+
+    /// Writes `self + other` into `out`, reusing its component buffers.
+    pub fn add_into(&self, other: &Self, out: &mut Self) {
+        self.real.add_into(&other.real, &mut out.real);
+        self.imag.add_into(&other.imag, &mut out.imag);
+    }
+
+    /// Writes `self - other` into `out`, reusing its component buffers.
+    pub fn sub_into(&self, other: &Self, out: &mut Self) {
+        self.real.sub_into(&other.real, &mut out.real);
+        self.imag.sub_into(&other.imag, &mut out.imag);
+    }
+
+    /// Writes `self * other` into `out`, reusing its component buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// // Iterate z ← z² + c without a fresh GaussInt per step
+    /// let c = GaussInt::from_i64(-1, 1);
+    /// let (mut z, mut square) = (GaussInt::from_i64(0, 0), GaussInt::from_i64(0, 0));
+    /// for _ in 0..3 {
+    ///     z.mul_into(&z, &mut square);
+    ///     square.add_into(&c, &mut z);
+    /// }
+    /// assert_eq!(z, GaussInt::from_i64(-1, 3));
+    /// ```
+    pub fn mul_into(&self, other: &Self, out: &mut Self) {
+        if self.component_bits().min(other.component_bits()) >= KARATSUBA_THRESHOLD_BITS {
+            *out = self * other;
+            return;
+        }
+        // real = ac - bd, imag = ad + bc
+        self.real.mul_into(&other.real, &mut out.real);
+        out.real -= &(&self.imag * &other.imag);
+        self.real.mul_into(&other.imag, &mut out.imag);
+        out.imag += &(&self.imag * &other.real);
+    }
+
+    /// Returns `self²`, computed as `(a+b)(a-b) + 2abi` with two
+    /// multiplications instead of the four of a general product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// let z = GaussInt::from_i64(3, -2);
+    /// assert_eq!(z.square(), GaussInt::from_i64(5, -12));
+    /// ```
+    pub fn square(&self) -> Self {
+        let ab = &self.real * &self.imag;
+        GaussInt {
+            real: &(&self.real + &self.imag) * &(&self.real - &self.imag),
+            imag: &ab + &ab,
+        }
+    }
+
     /// Raises to a non-negative integer power using exponentiation by squaring.
+    ///
+    /// Multiplies into the running result in place and squares with
+    /// [`square`](Self::square), so no operand is cloned inside the loop.
     pub fn pow_u32(&self, exp: u32) -> Self {
-        if exp == 0 {
-            return GaussInt::one();
-        }
         let mut result = GaussInt::one();
         let mut base = self.clone();
         let mut e = exp;
         while e > 0 {
             if e & 1 == 1 {
-                result = result * base.clone();
+                result *= &base;
             }
-            base = base.clone() * base;
             e >>= 1;
+            if e > 0 {
+                base = base.square();
+            }
         }
         result
     }
+
+    /// Raises to a `BigInt` power using exponentiation by squaring.
+    ///
+    /// Zero and the units are handled directly, so arbitrarily large
+    /// exponents are cheap for them. Returns `None` if `exp` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{BigInt, GaussInt};
+    ///
+    /// let z = GaussInt::from_i64(1, 1);
+    /// assert_eq!(z.pow_big(&BigInt::new(4)), Some(GaussInt::from_i64(-4, 0)));
+    /// let huge = BigInt::new(10).pow(40) + BigInt::new(1);
+    /// assert_eq!(GaussInt::from_i64(0, 1).pow_big(&huge), Some(GaussInt::from_i64(0, 1)));
+    /// assert_eq!(z.pow_big(&BigInt::new(-1)), None);
+    /// ```
+    pub fn pow_big(&self, exp: &BigInt) -> Option<Self> {
+        if exp.is_negative() {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(if exp.is_zero() {
+                GaussInt::one()
+            } else {
+                GaussInt::zero()
+            });
+        }
+        if self.is_unit() {
+            let k = exp.rem_euclid(&BigInt::new(4)).to_u64_lossy();
+            return Some(self.pow_u32(k as u32));
+        }
+        let mut result = GaussInt::one();
+        let mut base = self.clone();
+        let mut e = exp.clone();
+        let two = BigInt::new(2);
+        while !e.is_zero() {
+            let (q, r) = e.div_rem(&two);
+            if !r.is_zero() {
+                result *= &base;
+            }
+            e = q;
+            if !e.is_zero() {
+                base = base.square();
+            }
+        }
+        Some(result)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedGaussInt {
+    /// Returns the archived real part.
+    pub fn real(&self) -> &crate::big_int::ArchivedBigInt {
+        &self.real
+    }
+
+    /// Returns the archived imaginary part.
+    pub fn imag(&self) -> &crate::big_int::ArchivedBigInt {
+        &self.imag
+    }
+
+    /// Copies the archived value out into a [`GaussInt`].
+    pub fn to_gauss_int(&self) -> GaussInt {
+        GaussInt::new(self.real.to_big_int(), self.imag.to_big_int())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for GaussInt {
+    fn zeroize(&mut self) {
+        self.real.zeroize();
+        self.imag.zeroize();
+    }
 }
 
 impl Zero for GaussInt {
     fn zero() -> Self {
-        GaussInt {
-            real: BigInt::zero(),
-            imag: BigInt::zero(),
-        }
+        GaussInt::ZERO
     }
 
     fn is_zero(&self) -> bool {
@@ -91,10 +295,7 @@ impl Zero for GaussInt {
 
 impl One for GaussInt {
     fn one() -> Self {
-        GaussInt {
-            real: BigInt::one(),
-            imag: BigInt::zero(),
-        }
+        GaussInt::ONE
     }
 }
 
@@ -202,36 +403,68 @@ impl Sub<GaussInt> for &GaussInt {
     }
 }
 
+// --- In-place Add/Sub ---
+
+impl AddAssign<&GaussInt> for GaussInt {
+    fn add_assign(&mut self, other: &GaussInt) {
+        self.real += &other.real;
+        self.imag += &other.imag;
+    }
+}
+
+impl SubAssign<&GaussInt> for GaussInt {
+    fn sub_assign(&mut self, other: &GaussInt) {
+        self.real -= &other.real;
+        self.imag -= &other.imag;
+    }
+}
+
 // --- Mul ---
 
+/// Component size, in bits, from which `GaussInt::mul` uses three big
+/// multiplications instead of four.
+const KARATSUBA_THRESHOLD_BITS: u64 = 2048;
+
+impl GaussInt {
+    fn component_bits(&self) -> u64 {
+        self.real.bits().max(self.imag.bits())
+    }
+}
+
 impl Mul for GaussInt {
     type Output = GaussInt;
 
     fn mul(self, other: GaussInt) -> GaussInt {
-        // (a+bi)*(c+di) = (ac - bd) + (ad + bc)i
-        let ac = self.real.clone() * other.real.clone();
-        let bd = self.imag.clone() * other.imag.clone();
-        let ad = self.real * other.imag;
-        let bc = self.imag * other.real;
-        GaussInt {
-            real: ac - bd,
-            imag: ad + bc,
-        }
+        &self * &other
     }
 }
 
 impl Mul for &GaussInt {
     type Output = GaussInt;
 
+    /// Multiplies `(a+bi)(c+di) = (ac - bd) + (ad + bc)i`.
+    ///
+    /// Once both operands have components of at least
+    /// `KARATSUBA_THRESHOLD_BITS` bits, the imaginary part is computed as
+    /// `(a+b)(c+d) - ac - bd`, trading one big multiplication for three
+    /// additions.
     fn mul(self, other: &GaussInt) -> GaussInt {
-        // (a+bi)*(c+di) = (ac - bd) + (ad + bc)i
         let ac = &self.real * &other.real;
         let bd = &self.imag * &other.imag;
-        let ad = &self.real * &other.imag;
-        let bc = &self.imag * &other.real;
+        let imag = if self.component_bits().min(other.component_bits()) >= KARATSUBA_THRESHOLD_BITS
+        {
+            let mut cross = &(&self.real + &self.imag) * &(&other.real + &other.imag);
+            cross -= &ac;
+            cross -= &bd;
+            cross
+        } else {
+            let mut ad = &self.real * &other.imag;
+            ad += &(&self.imag * &other.real);
+            ad
+        };
         GaussInt {
             real: ac - bd,
-            imag: ad + bc,
+            imag,
         }
     }
 }
@@ -252,24 +485,57 @@ impl Mul<GaussInt> for &GaussInt {
     }
 }
 
+impl MulAssign<&GaussInt> for GaussInt {
+    /// Multiplies in place, reusing the component buffers of `self`.
+    fn mul_assign(&mut self, other: &GaussInt) {
+        if self.component_bits().min(other.component_bits()) >= KARATSUBA_THRESHOLD_BITS {
+            *self = &*self * other;
+            return;
+        }
+        // real = ac - bd, imag = bc + ad
+        let mut ad = self.real.clone();
+        ad *= &other.imag;
+        self.real *= &other.real;
+        self.real -= &(&self.imag * &other.imag);
+        self.imag *= &other.real;
+        self.imag += &ad;
+    }
+}
+
 // --- Division helpers and implementations ---
 
 /// Integer division rounding to nearest, ties away from zero.
-fn round_div(a: &BigInt, b: &BigInt) -> BigInt {
-    let q = a / b;
-    let r = a % b;
-    let two_r = BigInt::new(2) * r.abs();
-    let b_abs = b.abs();
-
-    if two_r >= b_abs {
+pub(crate) fn round_div(a: &BigInt, b: &BigInt) -> BigInt {
+    // A single truncating division; the remainder has the sign of `a`
+    let (mut q, r) = a.div_rem(b);
+    if (&r + &r).cmp_abs(b) != Ordering::Less {
         // Round away from zero
-        if (a.is_negative() && b.is_negative()) || (!a.is_negative() && !b.is_negative()) {
-            q + BigInt::one()
+        if a.is_negative() == b.is_negative() {
+            q += &BigInt::one();
         } else {
-            q - BigInt::one()
+            q -= &BigInt::one();
+        }
+    }
+    q
+}
+
+/// Integer division `a / b` for positive `b`, rounded by `mode`.
+fn div_with_mode(a: &BigInt, b: &BigInt, mode: RoundingMode) -> BigInt {
+    match mode {
+        RoundingMode::Floor => a.div_floor(b),
+        RoundingMode::Ceil => a.div_ceil(b),
+        RoundingMode::TowardZero => a / b,
+        RoundingMode::NearestAway => round_div(a, b),
+        RoundingMode::NearestEven => {
+            let q = a.div_floor(b);
+            let r = a - &(&q * b);
+            match (&r + &r).cmp(b) {
+                Ordering::Less => q,
+                Ordering::Greater => &q + &BigInt::one(),
+                Ordering::Equal if (&q % &BigInt::TWO).is_zero() => q,
+                Ordering::Equal => &q + &BigInt::one(),
+            }
         }
-    } else {
-        q
     }
 }
 
@@ -283,17 +549,149 @@ impl GaussInt {
             return None;
         }
 
-        let conj = other.conjugate();
-        let numerator = self * conj; // GaussInt
-        let denominator = other.norm(); // BigInt, always positive
+        let numerator = self * &other.conjugate();
+        let denominator = other.norm(); // always positive
 
         let q_real = round_div(numerator.real(), &denominator);
         let q_imag = round_div(numerator.imag(), &denominator);
         let q = GaussInt::new(q_real, q_imag);
-        let r = self - &q * other;
+        let r = self - &(&q * other);
 
         Some((q, r))
     }
+
+    /// The quotient `self / other`, with each component of the exact
+    /// quotient rounded by `mode`. Returns `None` if `other` is zero.
+    ///
+    /// The nearest modes leave a remainder of norm at most `N(other)/2`, as
+    /// the Euclidean algorithm needs; [`div_rem`](Self::div_rem) uses
+    /// [`RoundingMode::NearestAway`]. The directed modes pick a fixed
+    /// corner of the unit square around the exact quotient, which lattice
+    /// reduction relies on for consistent cell assignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{GaussInt, RoundingMode};
+    ///
+    /// // (7 + 5i) / 2 = 3.5 + 2.5i
+    /// let z = GaussInt::from_i64(7, 5);
+    /// let two = GaussInt::from_i64(2, 0);
+    /// assert_eq!(z.div_rounded(&two, RoundingMode::Floor), Some(GaussInt::from_i64(3, 2)));
+    /// assert_eq!(z.div_rounded(&two, RoundingMode::Ceil), Some(GaussInt::from_i64(4, 3)));
+    /// assert_eq!(z.div_rounded(&two, RoundingMode::NearestEven), Some(GaussInt::from_i64(4, 2)));
+    /// ```
+    pub fn div_rounded(&self, other: &Self, mode: RoundingMode) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let numerator = self * &other.conjugate();
+        let denominator = other.norm();
+        Some(GaussInt::new(
+            div_with_mode(numerator.real(), &denominator, mode),
+            div_with_mode(numerator.imag(), &denominator, mode),
+        ))
+    }
+
+    /// The quotient `self / divisor` if it is a Gaussian integer, otherwise
+    /// `None`; also `None` if `divisor` is zero.
+    ///
+    /// Multiplies by the conjugate `conj(divisor)` and divides both
+    /// components by the norm `N(divisor)`, so there is no rounding and no
+    /// remainder to form. [`div_exact`](Self::div_exact) is the `Result`
+    /// version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// let p = GaussInt::from_i64(2, 1);
+    /// let q = GaussInt::from_i64(2, -1);
+    /// let five = GaussInt::from_i64(5, 0);
+    /// assert_eq!(five.div_exact_complex(&p), Some(q.clone()));
+    /// assert_eq!(p.div_exact_complex(&q), None);
+    /// ```
+    pub fn div_exact_complex(&self, divisor: &Self) -> Option<Self> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let numerator = self * &divisor.conjugate();
+        let norm = divisor.norm();
+        let (real, real_rem) = numerator.real().div_rem(&norm);
+        let (imag, imag_rem) = numerator.imag().div_rem(&norm);
+        (real_rem.is_zero() && imag_rem.is_zero()).then(|| GaussInt::new(real, imag))
+    }
+
+    /// Returns the canonical representative of `self` in `Z[i]/(modulus)`.
+    ///
+    /// The modulus is first replaced by its canonical associate (see
+    /// [`normalize_associate`](Self::normalize_associate)), and the
+    /// quotient rounds each component with ties toward +∞. The remainder
+    /// then lies in the half-open square `modulus·[-1/2, 1/2)²`, so
+    /// congruent inputs, and associate moduli, always give the same result,
+    /// and `N(r) <= N(modulus)/2`.
+    ///
+    /// Returns `None` if `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// let m = GaussInt::from_i64(3, 1);
+    /// let z = GaussInt::from_i64(7, 2);
+    /// let r = z.reduce_mod(&m).unwrap();
+    /// assert_eq!(r, GaussInt::from_i64(1, 0));
+    /// assert_eq!((&z + &(&m * &GaussInt::from_i64(5, -4))).reduce_mod(&m), Some(r));
+    /// ```
+    pub fn reduce_mod(&self, modulus: &Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        let (m, _) = modulus.normalize_associate();
+        let numerator = self * &m.conjugate();
+        let norm = m.norm();
+        let two_n = &norm + &norm;
+        let round = |x: &BigInt| (&(x + x) + &norm).div_floor(&two_n);
+        let q = GaussInt::new(round(numerator.real()), round(numerator.imag()));
+        Some(self - &(&q * &m))
+    }
+}
+
+impl GaussInt {
+    /// Like [`div_rem`](Self::div_rem), but fails with
+    /// [`Error::DivisionByZero`].
+    pub fn try_div_rem(&self, other: &Self) -> Result<(Self, Self), Error> {
+        self.div_rem(other).ok_or(Error::DivisionByZero)
+    }
+
+    /// Like [`reduce_mod`](Self::reduce_mod), but fails with
+    /// [`Error::DivisionByZero`].
+    pub fn try_reduce_mod(&self, modulus: &Self) -> Result<Self, Error> {
+        self.reduce_mod(modulus).ok_or(Error::DivisionByZero)
+    }
+
+    /// Divides by `other`, failing with [`Error::NotExactlyDivisible`] unless
+    /// `other` divides this number in `Z[i]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{Error, GaussInt};
+    ///
+    /// let a = GaussInt::from_i64(2, 1);
+    /// let b = GaussInt::from_i64(3, -2);
+    /// assert_eq!((&a * &b).div_exact(&b), Ok(a));
+    /// assert_eq!(b.div_exact(&GaussInt::from_i64(2, 0)), Err(Error::NotExactlyDivisible));
+    /// ```
+    pub fn div_exact(&self, other: &Self) -> Result<Self, Error> {
+        if other.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        self.div_exact_complex(other)
+            .ok_or(Error::NotExactlyDivisible)
+    }
 }
 
 impl Div for &GaussInt {
@@ -310,82 +708,812 @@ impl Div for GaussInt {
     fn div(self, other: Self) -> GaussInt {
         self.div_rem(&other).expect("division by zero").0
     }
-}
-
-impl Rem for &GaussInt {
-    type Output = GaussInt;
+}
+
+impl Rem for &GaussInt {
+    type Output = GaussInt;
+
+    fn rem(self, other: Self) -> GaussInt {
+        self.div_rem(other).expect("division by zero").1
+    }
+}
+
+impl Rem for GaussInt {
+    type Output = GaussInt;
+
+    fn rem(self, other: Self) -> GaussInt {
+        self.div_rem(&other).expect("division by zero").1
+    }
+}
+
+impl GaussInt {
+    /// Compares lexicographically: by real part, then by imaginary part.
+    ///
+    /// This is the order used by [`Ord`]. It is a total order for sorting
+    /// and ordered containers, with no arithmetic meaning; Gaussian
+    /// integers have no ordering compatible with multiplication.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = GaussInt::from_i64(1, 5);
+    /// assert_eq!(a.cmp_lex(&GaussInt::from_i64(2, -3)), Ordering::Less);
+    /// assert_eq!(a.cmp_lex(&GaussInt::from_i64(1, 4)), Ordering::Greater);
+    /// ```
+    pub fn cmp_lex(&self, other: &Self) -> Ordering {
+        self.real
+            .cmp(&other.real)
+            .then_with(|| self.imag.cmp(&other.imag))
+    }
+}
+
+impl PartialOrd for GaussInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lexicographic order, as [`GaussInt::cmp_lex`].
+impl Ord for GaussInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_lex(other)
+    }
+}
+
+impl fmt::Display for GaussInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+/// Parses the `Display` form as [`GaussInt::try_from_string`] does,
+/// failing with [`Error::ParseGaussInt`].
+impl std::str::FromStr for GaussInt {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(GaussInt::try_from_string(s)?)
+    }
+}
+
+impl GaussInt {
+    /// Writes the `Display` text to a [`fmt::Write`] sink, streaming each
+    /// component through [`BigInt::write_to`].
+    pub fn write_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        if self.imag.is_zero() {
+            self.real.write_to(w)
+        } else if self.real.is_zero() {
+            if self.imag == BigInt::one() {
+                w.write_str("i")
+            } else if self.imag == -BigInt::one() {
+                w.write_str("-i")
+            } else {
+                self.imag.write_to(w)?;
+                w.write_char('i')
+            }
+        } else {
+            self.real.write_to(w)?;
+            if self.imag.is_positive() {
+                w.write_char('+')?;
+            }
+            self.imag.write_to(w)?;
+            w.write_char('i')
+        }
+    }
+
+    /// Like [`write_to`](Self::write_to), for an [`io::Write`](std::io::Write) sink.
+    pub fn write_to_io<W: std::io::Write + ?Sized>(&self, w: &mut W) -> std::io::Result<()> {
+        crate::big_int::IoSink::run(w, |sink| self.write_to(sink))
+    }
+
+    /// Parses the `Display` form, such as `3-4i`, `-7i`, `i` or `5`.
+    ///
+    /// Returns `None` if the string is not a valid Gaussian integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// assert_eq!(GaussInt::from_string("3-4i"), Some(GaussInt::from_i64(3, -4)));
+    /// assert_eq!(GaussInt::from_string("-i"), Some(GaussInt::MINUS_I));
+    /// assert_eq!(GaussInt::from_string("3+4"), None);
+    /// ```
+    pub fn from_string(s: &str) -> Option<Self> {
+        GaussInt::try_from_string(s).ok()
+    }
+
+    /// Like [`from_string`](Self::from_string), but reports where the
+    /// input went wrong.
+    ///
+    /// A `+` may also lead the real part and `1` may be written before
+    /// `i`; whitespace is not allowed anywhere. Digits may be grouped with
+    /// underscores, as in `12_345+6_789i`, following
+    /// [`BigInt::try_from_string`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// let err = GaussInt::try_from_string("12+5j").unwrap_err();
+    /// assert_eq!((err.offset(), err.found()), (4, Some('j')));
+    /// ```
+    pub fn try_from_string(s: &str) -> Result<Self, ParseGaussIntError> {
+        let mut scanner = crate::big_int::Scanner::new(s);
+        let error =
+            |scanner: &crate::big_int::Scanner<'_>| ParseGaussIntError::at(s, scanner.pos());
+        let (first, first_imag) = scan_term(&mut scanner).ok_or_else(|| error(&scanner))?;
+        let z = if first_imag {
+            GaussInt::new(BigInt::ZERO, first)
+        } else if matches!(scanner.peek(), Some(b'+' | b'-')) {
+            match scan_term(&mut scanner).ok_or_else(|| error(&scanner))? {
+                (imag, true) => GaussInt::new(first, imag),
+                (_, false) => return Err(error(&scanner)),
+            }
+        } else {
+            GaussInt::new(first, BigInt::ZERO)
+        };
+        if !scanner.at_end() {
+            return Err(error(&scanner));
+        }
+        Ok(z)
+    }
+
+    /// Parses the `Display` form, such as `3-4i`, `-7i`, `i` or `5`, from a
+    /// reader in chunks; see [`BigInt::read_from`] for the whitespace and
+    /// error conventions. A `+` may also lead the real part and `1` may be
+    /// written before `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{BigInt, GaussInt};
+    ///
+    /// let z = GaussInt::new(BigInt::new(10).pow(300), -BigInt::new(3).pow(500));
+    /// let text = z.to_string();
+    /// assert_eq!(GaussInt::read_from(text.as_bytes()).unwrap(), z);
+    /// assert_eq!(GaussInt::read_from("-i\n".as_bytes()).unwrap(), GaussInt::MINUS_I);
+    /// assert!(GaussInt::read_from("3+4".as_bytes()).is_err());
+    /// ```
+    pub fn read_from<R: std::io::Read>(reader: R) -> std::io::Result<Self> {
+        let mut text = crate::big_int::TextReader::for_gauss_int(reader);
+        text.skip_whitespace()?;
+        let (first, first_imag) = read_term(&mut text)?;
+        let z = if first_imag {
+            GaussInt::new(BigInt::ZERO, first)
+        } else if matches!(text.peek()?, Some(b'+' | b'-')) {
+            match read_term(&mut text)? {
+                (imag, true) => GaussInt::new(first, imag),
+                (_, false) => return Err(text.invalid()),
+            }
+        } else {
+            GaussInt::new(first, BigInt::ZERO)
+        };
+        text.finish()?;
+        Ok(z)
+    }
+
+    /// Formats with custom layout options; see
+    /// [`FormatOptions`](crate::format::FormatOptions).
+    pub fn format_with(&self, options: &crate::format::FormatOptions) -> String {
+        options.format(self)
+    }
+
+    /// Renders as LaTeX math-mode source, e.g. `3 + 4\,i`. Use
+    /// [`FormatOptions::latex`](crate::format::FormatOptions::latex) for
+    /// `\cdot 10^{k}` notation and other layouts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// assert_eq!(GaussInt::from_i64(3, 4).to_latex(), "3 + 4\\,i");
+    /// assert_eq!(GaussInt::from_i64(0, -1).to_latex(), "-i");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        crate::format::FormatOptions::new().latex(self)
+    }
+
+    /// Renders as a presentation MathML `<math>` element.
+    pub fn to_mathml(&self) -> String {
+        crate::format::FormatOptions::new().mathml(self)
+    }
+
+    /// A short approximate rendering with `sig_figs` significant digits
+    /// per component, for values whose exact form is unwieldy.
+    ///
+    /// Only the leading digits of each component are converted to text.
+    /// Panics if `sig_figs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{BigInt, GaussInt};
+    ///
+    /// let scale = BigInt::new(10).pow(448);
+    /// let z = GaussInt::new(&BigInt::new(123) * &scale, &BigInt::new(46) * &scale);
+    /// assert_eq!(z.to_approx_string(3), "≈1.23e+450 + 4.60e+449 i");
+    /// ```
+    pub fn to_approx_string(&self, sig_figs: u32) -> String {
+        let options = crate::format::FormatOptions::new()
+            .scientific(sig_figs)
+            .spaced(true);
+        format!("≈{}", options.format(self))
+    }
+}
+
+/// The string counterpart of [`read_term`]; on `None` the scanner is left
+/// at the offending byte.
+fn scan_term(scanner: &mut crate::big_int::Scanner<'_>) -> Option<(BigInt, bool)> {
+    let negative = scanner.minus_sign();
+    let digits = scanner.digits().ok()?;
+    let imag = scanner.eat(b'i');
+    let magnitude = match digits {
+        Some(m) => BigInt::from(num_bigint::BigInt::from(m)),
+        None if imag => BigInt::ONE,
+        None => return None,
+    };
+    Some((if negative { -magnitude } else { magnitude }, imag))
+}
+
+/// One signed term of a Gaussian integer, with `true` if it ends in `i`;
+/// a bare `i` has coefficient one.
+fn read_term<R: std::io::Read>(
+    text: &mut crate::big_int::TextReader<R>,
+) -> std::io::Result<(BigInt, bool)> {
+    let negative = text.minus_sign()?;
+    let digits = text.digits()?;
+    let imag = text.peek()? == Some(b'i');
+    if imag {
+        text.bump();
+    }
+    let magnitude = match digits {
+        Some(m) => BigInt::from(num_bigint::BigInt::from(m)),
+        None if imag => BigInt::ONE,
+        None => return Err(text.invalid()),
+    };
+    Ok((if negative { -magnitude } else { magnitude }, imag))
+}
+
+// --- Floating-point conversion ---
+
+impl GaussInt {
+    /// Converts to a `Complex<f64>`, rounding each component to the nearest
+    /// `f64`, and reports whether both conversions were exact.
+    ///
+    /// Components beyond `f64::MAX` become infinities; see
+    /// [`BigInt::to_f64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{BigInt, GaussInt};
+    /// use num_complex::Complex;
+    ///
+    /// assert_eq!(GaussInt::from_i64(3, -4).to_complex_f64(), (Complex::new(3.0, -4.0), true));
+    ///
+    /// let z = GaussInt::new(BigInt::new(10).pow(30), BigInt::ONE);
+    /// assert_eq!(z.to_complex_f64(), (Complex::new(1e30, 1.0), false));
+    /// ```
+    pub fn to_complex_f64(&self) -> (Complex<f64>, bool) {
+        let (re, re_exact) = self.real.to_f64();
+        let (im, im_exact) = self.imag.to_f64();
+        let exact = re_exact == Exactness::Exact && im_exact == Exactness::Exact;
+        (Complex::new(re, im), exact)
+    }
+
+    /// Returns the Gaussian integer nearest to `z`, rounding each component
+    /// to the nearest integer with ties away from zero, like [`f64::round`].
+    ///
+    /// Fails with [`Error::DomainError`] if either component is NaN or
+    /// infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    /// use num_complex::Complex;
+    ///
+    /// let z = GaussInt::from_complex_f64_rounded(Complex::new(2.49, -0.5)).unwrap();
+    /// assert_eq!(z, GaussInt::from_i64(2, -1));
+    /// assert!(GaussInt::from_complex_f64_rounded(Complex::new(f64::NAN, 0.0)).is_err());
+    /// ```
+    pub fn from_complex_f64_rounded(z: Complex<f64>) -> Result<Self, Error> {
+        Ok(GaussInt::new(
+            BigInt::from_f64(z.re, RoundingMode::NearestAway)?,
+            BigInt::from_f64(z.im, RoundingMode::NearestAway)?,
+        ))
+    }
+
+    /// Returns the Gaussian integer nearest to the exact value `z`, with the
+    /// same tie-breaking as [`from_complex_f64_rounded`](Self::from_complex_f64_rounded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::rational::ComplexRational;
+    /// use gauss_int::GaussInt;
+    ///
+    /// let a = GaussInt::from_i64(27, 23);
+    /// let b = GaussInt::from_i64(8, 1);
+    /// let q = ComplexRational::quotient(&a, &b).unwrap();
+    /// assert_eq!(GaussInt::round_from_rational(&q), (&a / &b));
+    /// ```
+    pub fn round_from_rational(z: &ComplexRational) -> Self {
+        let round = |x: &BigRational| round_div(&x.numer(), &x.denom());
+        GaussInt::new(round(z.re()), round(z.im()))
+    }
+}
+
+// --- Canonicalize and GCD ---
+
+impl GaussInt {
+    /// Returns the four associates `self·u` for `u = 1, i, -1, -i`, in
+    /// that order.
+    pub fn associates(&self) -> [Self; 4] {
+        [self.clone(), self.mul_i(), -self, self.div_i()]
+    }
+
+    /// Returns the canonical associate together with the unit `u` such
+    /// that `self·u` is that associate.
+    ///
+    /// The canonical associate is the unique one with real > 0 and
+    /// imag >= 0. Zero is its own canonical associate, with `u = 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// let z = GaussInt::from_i64(4, -3);
+    /// let (canonical, unit) = z.normalize_associate();
+    /// assert_eq!(canonical, GaussInt::from_i64(3, 4));
+    /// assert_eq!(unit, GaussInt::from_i64(0, 1));
+    /// assert_eq!(&z * &unit, canonical);
+    /// ```
+    pub fn normalize_associate(&self) -> (Self, Self) {
+        let k = self.quadrant().map_or(0, |q| -(q as i32));
+        (self.rotate_quarter(k), GaussInt::one().rotate_quarter(k))
+    }
+
+    /// Computes the greatest common divisor using the Euclidean algorithm.
+    ///
+    /// Returns the canonical GCD, as given by
+    /// [`normalize_associate`](Self::normalize_associate).
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+
+        while !b.is_zero() {
+            let r = a.div_rem(&b).unwrap().1;
+            a = b;
+            b = r;
+        }
+
+        a.normalize_associate().0
+    }
+
+    /// Returns true if `self` divides `other` in `Z[i]`.
+    ///
+    /// Uses [`div_exact_complex`](Self::div_exact_complex). Zero divides
+    /// only zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// let p = GaussInt::from_i64(2, 1);
+    /// assert!(p.divides(&GaussInt::from_i64(5, 0)));
+    /// assert!(!p.divides(&GaussInt::from_i64(3, 0)));
+    /// assert!(GaussInt::from_i64(5, 0).is_multiple_of(&p));
+    /// ```
+    pub fn divides(&self, other: &Self) -> bool {
+        if self.is_zero() {
+            return other.is_zero();
+        }
+        other.div_exact_complex(self).is_some()
+    }
+
+    /// Returns true if `self` is a multiple of `other`, i.e.
+    /// `other.divides(self)`.
+    pub fn is_multiple_of(&self, other: &Self) -> bool {
+        other.divides(self)
+    }
+
+    /// Returns the content `gcd(|real|, |imag|)`, the largest rational
+    /// integer dividing `self`. The content of zero is zero.
+    pub fn content(&self) -> BigInt {
+        self.real.gcd(&self.imag)
+    }
+
+    /// Returns `self` divided by its [`content`](Self::content), so that
+    /// the components are coprime. Zero is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{BigInt, GaussInt};
+    ///
+    /// let z = GaussInt::from_i64(12, -18);
+    /// assert_eq!(z.content(), BigInt::new(6));
+    /// assert_eq!(z.primitive_part(), GaussInt::from_i64(2, -3));
+    /// ```
+    pub fn primitive_part(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        self.div_exact_by(&self.content())
+            .expect("content divides both components")
+    }
+}
+
+// --- 2-D vector operations ---
+
+impl GaussInt {
+    /// Euclidean inner product, treating `a + bi` as the vector `(a, b)`.
+    pub fn dot(&self, other: &Self) -> BigInt {
+        &(&self.real * &other.real) + &(&self.imag * &other.imag)
+    }
+
+    /// The z-component of the 2-D cross product; positive when `other` is
+    /// counter-clockwise from `self`.
+    pub fn cross(&self, other: &Self) -> BigInt {
+        &(&self.real * &other.imag) - &(&self.imag * &other.real)
+    }
+
+    /// Compares norms `a² + b²` exactly, i.e. distances from the origin,
+    /// without taking square roots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    /// use std::cmp::Ordering;
+    ///
+    /// let z = GaussInt::from_i64(3, 4);
+    /// assert_eq!(z.cmp_norm(&GaussInt::from_i64(0, -5)), Ordering::Equal);
+    /// assert_eq!(z.cmp_norm(&GaussInt::from_i64(4, 4)), Ordering::Less);
+    /// ```
+    pub fn cmp_norm(&self, other: &Self) -> Ordering {
+        self.norm().cmp(&other.norm())
+    }
+
+    /// Returns true if `self` is strictly closer to the origin than `other`.
+    pub fn is_closer_to_origin_than(&self, other: &Self) -> bool {
+        self.cmp_norm(other) == Ordering::Less
+    }
+
+    /// Returns true if the two vectors are parallel or anti-parallel
+    /// (including when either is zero).
+    pub fn is_collinear_with(&self, other: &Self) -> bool {
+        self.cross(other).is_zero()
+    }
+
+    /// Returns the quadrant `0..=3` of the half-open sector
+    /// `[k·90°, (k+1)·90°)` containing this vector, or `None` for zero.
+    pub fn quadrant(&self) -> Option<u8> {
+        if self.is_zero() {
+            return None;
+        }
+        let (re, im) = (&self.real, &self.imag);
+        Some(if re.is_positive() && !im.is_negative() {
+            0
+        } else if !re.is_positive() && im.is_positive() {
+            1
+        } else if re.is_negative() && !im.is_positive() {
+            2
+        } else {
+            3
+        })
+    }
+
+    /// Returns the midpoint `(self + other) / 2`, or `None` if it is not a
+    /// Gaussian integer (the components of the sum are not both even).
+    pub fn midpoint(&self, other: &Self) -> Option<Self> {
+        (self + other).div_exact_by(&BigInt::new(2))
+    }
+
+    /// Linear interpolation `self + t·(other - self)` for rational `t`.
+    ///
+    /// Returns `None` if the result is not a Gaussian integer.
+    pub fn lerp_rational(&self, other: &Self, t: &BigRational) -> Option<Self> {
+        let (n, d) = (t.numer(), t.denom());
+        let weighted = GaussInt::new(
+            &(&(&d - &n) * &self.real) + &(&n * &other.real),
+            &(&(&d - &n) * &self.imag) + &(&n * &other.imag),
+        );
+        weighted.div_exact_by(&d)
+    }
+
+    /// Divides both components by `d`, if both divide exactly.
+    pub(crate) fn div_exact_by(&self, d: &BigInt) -> Option<Self> {
+        let (re, re_rem) = self.real.div_rem(d);
+        let (im, im_rem) = self.imag.div_rem(d);
+        (re_rem.is_zero() && im_rem.is_zero()).then(|| GaussInt::new(re, im))
+    }
+
+    /// Counter-clockwise quarter turns from this vector's quadrant to
+    /// `other`'s, in `0..=3`; `None` if either vector is zero.
+    pub fn angle_between_quadrants(&self, other: &Self) -> Option<u8> {
+        Some((other.quadrant()? + 4 - self.quadrant()?) % 4)
+    }
+}
+
+// --- Matrix representation ---
+
+impl GaussInt {
+    /// Returns the real 2×2 matrix `[[a, -b], [b, a]]` of multiplication by
+    /// `a + bi`, so that products of Gaussian integers map to matrix products.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// let (z, w) = (GaussInt::from_i64(3, -4), GaussInt::from_i64(-2, 7));
+    /// assert_eq!(GaussInt::from_matrix(&z.to_matrix()), Some(z.clone()));
+    /// let product = &z.to_matrix() * &w.to_matrix();
+    /// assert_eq!(GaussInt::from_matrix(&product), Some(&z * &w));
+    /// ```
+    pub fn to_matrix(&self) -> Matrix<BigInt> {
+        Matrix::new(
+            2,
+            2,
+            vec![
+                self.real.clone(),
+                -&self.imag,
+                self.imag.clone(),
+                self.real.clone(),
+            ],
+        )
+        .expect("2x2 data")
+    }
+
+    /// Inverse of [`to_matrix`](Self::to_matrix).
+    ///
+    /// Returns `None` unless `m` is 2×2 of the form `[[a, -b], [b, a]]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::matrix::Matrix;
+    /// use gauss_int::{BigInt, GaussInt};
+    ///
+    /// let rows = |r: [[i64; 2]; 2]| {
+    ///     Matrix::from_rows(r.iter().map(|row| row.map(BigInt::new).to_vec()).collect()).unwrap()
+    /// };
+    /// let z = GaussInt::from_matrix(&rows([[5, -2], [2, 5]])).unwrap();
+    /// assert_eq!(z, GaussInt::from_i64(5, 2));
+    /// assert_eq!(z.to_matrix(), rows([[5, -2], [2, 5]]));
+    /// assert_eq!(GaussInt::from_matrix(&rows([[5, 2], [2, 5]])), None);
+    /// ```
+    pub fn from_matrix(m: &Matrix<BigInt>) -> Option<Self> {
+        if m.rows() != 2 || m.cols() != 2 || m[(0, 0)] != m[(1, 1)] || m[(0, 1)] != -&m[(1, 0)] {
+            return None;
+        }
+        Some(GaussInt::new(m[(0, 0)].clone(), m[(1, 0)].clone()))
+    }
+}
+
+// --- Binary encoding ---
+
+impl GaussInt {
+    /// Version byte written at the start of [`to_bytes`](Self::to_bytes).
+    pub const ENCODING_VERSION: u8 = 1;
+
+    /// Encodes as a compact, versioned byte string for storage or transfer.
+    ///
+    /// The layout is the version byte [`ENCODING_VERSION`](Self::ENCODING_VERSION)
+    /// followed by the real and then the imaginary part, each as:
+    ///
+    /// - one sign byte, `0` for non-negative and `1` for negative;
+    /// - the magnitude length in bytes, as a big-endian `u32`;
+    /// - the magnitude, big-endian with no leading zero bytes (empty for zero).
+    ///
+    /// Panics if a component's magnitude exceeds `u32::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// let z = GaussInt::from_i64(3, -260);
+    /// assert_eq!(z.to_bytes(), [1, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 2, 1, 4]);
+    /// assert_eq!(GaussInt::from_bytes(&z.to_bytes()), Some(z));
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = vec![Self::ENCODING_VERSION];
+        for part in [&self.real, &self.imag] {
+            let (sign, magnitude) = part.to_bytes_be();
+            let magnitude = if sign == Sign::NoSign {
+                Vec::new()
+            } else {
+                magnitude
+            };
+            let len = u32::try_from(magnitude.len()).expect("component too large to encode");
+            out.push(u8::from(sign == Sign::Minus));
+            out.extend_from_slice(&len.to_be_bytes());
+            out.extend_from_slice(&magnitude);
+        }
+        out
+    }
+
+    /// Inverse of [`to_bytes`](Self::to_bytes).
+    ///
+    /// Returns `None` for an unknown version, truncated or trailing data,
+    /// or a non-canonical encoding (leading zero bytes, negative zero, or a
+    /// sign byte other than `0` or `1`).
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&version, mut rest) = bytes.split_first()?;
+        if version != Self::ENCODING_VERSION {
+            return None;
+        }
+        let mut parts = [BigInt::zero(), BigInt::zero()];
+        for part in &mut parts {
+            let (&sign, tail) = rest.split_first()?;
+            let (len, tail) = tail.split_first_chunk::<4>()?;
+            let len = usize::try_from(u32::from_be_bytes(*len)).ok()?;
+            if tail.len() < len {
+                return None;
+            }
+            let (magnitude, tail) = tail.split_at(len);
+            let sign = match (sign, magnitude.first()) {
+                (0, None) => Sign::NoSign,
+                (0, Some(&lead)) if lead != 0 => Sign::Plus,
+                (1, Some(&lead)) if lead != 0 => Sign::Minus,
+                _ => return None,
+            };
+            *part = BigInt::from_bytes_be(sign, magnitude);
+            rest = tail;
+        }
+        if !rest.is_empty() {
+            return None;
+        }
+        let [real, imag] = parts;
+        Some(GaussInt::new(real, imag))
+    }
+}
+
+// --- Base (−1+i) representation ---
+
+impl GaussInt {
+    /// Returns the digits of this number in base `−1+i`, least significant
+    /// first.
+    ///
+    /// Every Gaussian integer has exactly one representation
+    /// `Σ dₖ·(−1+i)ᵏ` with digits `dₖ ∈ {0, 1}`, so this is the complex
+    /// analogue of binary. Zero gives no digits, and the last digit is
+    /// otherwise always `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// // i = (−1+i) + 1 and 2 = (−1+i)³ + (−1+i)²
+    /// assert_eq!(GaussInt::I.to_base_minus_one_plus_i(), vec![1, 1]);
+    /// assert_eq!(GaussInt::from_i64(2, 0).to_base_minus_one_plus_i(), vec![0, 0, 1, 1]);
+    /// ```
+    pub fn to_base_minus_one_plus_i(&self) -> Vec<u8> {
+        let mut digits = Vec::new();
+        let (mut a, mut b) = (self.real.clone(), self.imag.clone());
+        while !(a.is_zero() && b.is_zero()) {
+            // −1+i divides a+bi exactly when a+b is even
+            let digit = u8::from(!(&(&a + &b) % &BigInt::TWO).is_zero());
+            if digit == 1 {
+                a -= &BigInt::one();
+            }
+            // (a+bi)/(−1+i) = ((b−a) − (a+b)i)/2, now exactly
+            (a, b) = (&(&b - &a) / &BigInt::TWO, -(&(&a + &b) / &BigInt::TWO));
+            digits.push(digit);
+        }
+        digits
+    }
 
-    fn rem(self, other: Self) -> GaussInt {
-        self.div_rem(other).expect("division by zero").1
+    /// Rebuilds a Gaussian integer from base `−1+i` digits, least
+    /// significant first, as produced by
+    /// [`to_base_minus_one_plus_i`](Self::to_base_minus_one_plus_i).
+    ///
+    /// Trailing zero digits are allowed. Returns `None` if a digit is not
+    /// `0` or `1`.
+    pub fn from_base_minus_one_plus_i(digits: &[u8]) -> Option<Self> {
+        let base = GaussInt::from_i64(-1, 1);
+        let mut z = GaussInt::ZERO;
+        for &digit in digits.iter().rev() {
+            if digit > 1 {
+                return None;
+            }
+            z = &z * &base;
+            if digit == 1 {
+                z.real += &BigInt::one();
+            }
+        }
+        Some(z)
     }
 }
 
-impl Rem for GaussInt {
-    type Output = GaussInt;
+// --- Roots of unity ---
 
-    fn rem(self, other: Self) -> GaussInt {
-        self.div_rem(&other).expect("division by zero").1
-    }
+/// The `n`-th roots of unity, as returned by [`GaussInt::roots_of_unity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RootsOfUnity {
+    /// All roots are Gaussian integers (`n` is 1, 2 or 4).
+    Exact(Vec<GaussInt>),
+    /// Each root `e^(2πik/n)` rounded after scaling by `2^precision`.
+    Scaled {
+        precision: u32,
+        roots: Vec<GaussInt>,
+    },
 }
 
-impl fmt::Display for GaussInt {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.imag.is_zero() {
-            write!(f, "{}", self.real)
-        } else if self.real.is_zero() {
-            if self.imag == BigInt::one() {
-                write!(f, "i")
-            } else if self.imag == -BigInt::one() {
-                write!(f, "-i")
-            } else {
-                write!(f, "{}i", self.imag)
-            }
-        } else {
-            let sign = if self.imag.is_positive() { "+" } else { "" };
-            write!(f, "{}{}{}i", self.real, sign, self.imag)
+impl RootsOfUnity {
+    pub fn roots(&self) -> &[GaussInt] {
+        match self {
+            RootsOfUnity::Exact(roots) | RootsOfUnity::Scaled { roots, .. } => roots,
         }
     }
-}
 
-// --- Canonicalize and GCD ---
+    pub fn is_exact(&self) -> bool {
+        matches!(self, RootsOfUnity::Exact(_))
+    }
+}
 
 impl GaussInt {
-    /// Returns the canonical associate of this Gaussian integer:
-    /// the one in the first quadrant (real > 0, or real == 0 and imag > 0).
-    fn canonicalize(&self) -> Self {
-        if self.is_zero() {
-            return self.clone();
-        }
-        let i = GaussInt::from_i64(0, 1);
-        let units = [GaussInt::one(), -GaussInt::one(), i.clone(), -i];
-        let mut best = &units[0] * self;
-        for u in &units[1..] {
-            let candidate = u * self;
-            let real_pos = candidate.real().is_positive();
-            let real_zero_imag_pos = candidate.real().is_zero() && candidate.imag().is_positive();
-            let best_real_pos = best.real().is_positive();
-            let best_real_zero_imag_pos = best.real().is_zero() && best.imag().is_positive();
-            if (real_pos || real_zero_imag_pos) && !(best_real_pos || best_real_zero_imag_pos) {
-                best = candidate;
+    /// Returns the `n` roots `e^(2πik/n)` for `k = 0..n`, in counter-clockwise
+    /// order starting from 1.
+    ///
+    /// For `n` in {1, 2, 4} the roots are the exact units. Otherwise each root
+    /// is approximated as `round(2^precision · e^(2πik/n))`, with both
+    /// components within one unit of the true scaled value. Returns `None`
+    /// for `n = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// let fourth = GaussInt::roots_of_unity(4, 0).unwrap();
+    /// assert!(fourth.is_exact());
+    /// assert_eq!(fourth.roots()[1], GaussInt::from_i64(0, 1));
+    ///
+    /// // Cube roots scaled by 2^10: ω ≈ (-512 + 887i) / 1024
+    /// let cube = GaussInt::roots_of_unity(3, 10).unwrap();
+    /// assert_eq!(cube.roots()[1], GaussInt::from_i64(-512, 887));
+    /// ```
+    pub fn roots_of_unity(n: u32, precision: u32) -> Option<RootsOfUnity> {
+        match n {
+            0 => None,
+            1 | 2 | 4 => {
+                let step = 4 / n as i32;
+                let one = GaussInt::one();
+                Some(RootsOfUnity::Exact(
+                    (0..n as i32)
+                        .map(|k| one.rotate_quarter(k * step))
+                        .collect(),
+                ))
+            }
+            _ => {
+                let roots = fixed_point::cos_sin_turns(n as u64, n as u64, precision)
+                    .into_iter()
+                    .map(|(c, s)| GaussInt::new(c, s))
+                    .collect();
+                Some(RootsOfUnity::Scaled { precision, roots })
             }
         }
-        best
     }
 
-    /// Computes the greatest common divisor using the Euclidean algorithm.
+    /// Returns true if some power of `self` equals 1.
     ///
-    /// Returns the canonical GCD (first quadrant).
-    pub fn gcd(&self, other: &Self) -> Self {
-        let mut a = self.clone();
-        let mut b = other.clone();
-
-        while !b.is_zero() {
-            let r = a.div_rem(&b).unwrap().1;
-            a = b;
-            b = r;
-        }
-
-        a.canonicalize()
+    /// The only Gaussian integers of finite multiplicative order are the
+    /// units ±1 and ±i.
+    pub fn is_root_of_unity(&self) -> bool {
+        self.is_unit()
     }
 }
 
@@ -436,6 +1564,18 @@ mod tests {
         assert_eq!(&a * &b, GaussInt::from_i64(-5, 10));
     }
 
+    #[test]
+    fn test_gauss_int_in_place_operators() {
+        let (a, b) = (GaussInt::from_i64(3, -4), GaussInt::from_i64(-2, 7));
+        let mut x = a.clone();
+        x *= &b;
+        assert_eq!(x, &a * &b);
+        x -= &a;
+        assert_eq!(x, &(&a * &b) - &a);
+        x += &a;
+        assert_eq!(x, &a * &b);
+    }
+
     #[test]
     fn test_gauss_int_neg() {
         assert_eq!(-GaussInt::from_i64(3, 4), GaussInt::from_i64(-3, -4));
@@ -492,7 +1632,7 @@ mod tests {
         assert_eq!(&z + &(-&z), GaussInt::zero());
 
         // z * conj(z) = N(z) as a real number
-        let product = GaussInt::new(z.real().clone(), z.imag().clone()) * z.conjugate();
+        let product = &z * &z.conjugate();
         assert!(product.is_real());
         assert_eq!(product.real, BigInt::new(25));
     }
@@ -635,6 +1775,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_round_div_large_operands() {
+        // Operands on both sides of the inline i64 range
+        let big = BigInt::new(10).pow(30);
+        let half = &big / &BigInt::new(2);
+        let b = &big + &BigInt::new(1);
+        assert_eq!(round_div(&(&big * &BigInt::new(7)), &b), BigInt::new(7));
+        assert_eq!(round_div(&(&half + &BigInt::new(1)), &b), BigInt::new(1));
+        assert_eq!(round_div(&-&half, &b), BigInt::new(0));
+        assert_eq!(round_div(&-&(&big * &big), &-&big), big);
+        assert_eq!(round_div(&BigInt::new(i64::MIN), &-&big), BigInt::new(0));
+        let (x, y) = (BigInt::new(i64::MAX), BigInt::new(i64::MIN));
+        assert_eq!(round_div(&x, &y), BigInt::new(-1));
+    }
+
     #[test]
     fn test_gauss_int_gcd_coprime() {
         // 3+4i and 3-4i should be coprime (gcd is a unit)
@@ -679,4 +1834,710 @@ mod tests {
         assert!(a.div_rem(&g).unwrap().1.is_zero(), "gcd should divide a");
         assert!(b.div_rem(&g).unwrap().1.is_zero(), "gcd should divide b");
     }
+
+    #[test]
+    fn test_gauss_int_matrix_round_trip() {
+        let a = GaussInt::from_i64(3, -4);
+        let b = GaussInt::from_i64(-2, 7);
+        assert_eq!(a.to_matrix().to_string(), "[3, 4]\n[-4, 3]");
+        assert_eq!(GaussInt::from_matrix(&a.to_matrix()), Some(a.clone()));
+        assert_eq!(&a.to_matrix() * &b.to_matrix(), (&a * &b).to_matrix());
+        assert_eq!(a.to_matrix().determinant().unwrap(), a.norm());
+        assert_eq!(a.to_matrix().transpose(), a.conjugate().to_matrix());
+    }
+
+    #[test]
+    fn test_gauss_int_from_matrix_rejects_invalid() {
+        let m = |rows: &[&[i64]]| {
+            Matrix::from_rows(
+                rows.iter()
+                    .map(|row| row.iter().map(|&x| BigInt::new(x)).collect())
+                    .collect(),
+            )
+            .unwrap()
+        };
+        assert!(GaussInt::from_matrix(&m(&[&[1, 2], &[3, 1]])).is_none());
+        assert!(GaussInt::from_matrix(&m(&[&[1, -2], &[2, 5]])).is_none());
+        assert!(GaussInt::from_matrix(&m(&[&[1, -2, 0], &[2, 1, 0]])).is_none());
+        assert_eq!(
+            GaussInt::from_matrix(&m(&[&[5, 0], &[0, 5]])),
+            Some(GaussInt::from_i64(5, 0))
+        );
+    }
+
+    #[test]
+    fn test_gauss_int_vector_ops() {
+        let a = GaussInt::from_i64(3, 4);
+        let b = GaussInt::from_i64(-2, 5);
+        assert_eq!(a.dot(&b), BigInt::new(14));
+        assert_eq!(a.cross(&b), BigInt::new(23));
+        assert_eq!(b.cross(&a), BigInt::new(-23));
+        assert_eq!(a.dot(&a), a.norm());
+        assert!(a.is_collinear_with(&GaussInt::from_i64(-6, -8)));
+        assert!(!a.is_collinear_with(&b));
+        assert!(a.is_collinear_with(&GaussInt::zero()));
+    }
+
+    #[test]
+    fn test_gauss_int_quadrants() {
+        let q = |re, im| GaussInt::from_i64(re, im).quadrant();
+        assert_eq!(q(1, 0), Some(0));
+        assert_eq!(q(2, 3), Some(0));
+        assert_eq!(q(0, 1), Some(1));
+        assert_eq!(q(-1, 0), Some(2));
+        assert_eq!(q(0, -1), Some(3));
+        assert_eq!(q(0, 0), None);
+        let a = GaussInt::from_i64(5, 1);
+        assert_eq!(
+            a.angle_between_quadrants(&GaussInt::from_i64(-1, 5)),
+            Some(1)
+        );
+        assert_eq!(
+            a.angle_between_quadrants(&GaussInt::from_i64(1, -5)),
+            Some(3)
+        );
+        assert_eq!(
+            GaussInt::from_i64(1, -5).angle_between_quadrants(&a),
+            Some(1)
+        );
+        assert_eq!(a.angle_between_quadrants(&GaussInt::zero()), None);
+    }
+
+    #[test]
+    fn test_gauss_int_midpoint_and_lerp() {
+        let a = GaussInt::from_i64(1, -3);
+        let b = GaussInt::from_i64(5, 7);
+        assert_eq!(a.midpoint(&b), Some(GaussInt::from_i64(3, 2)));
+        assert_eq!(a.midpoint(&GaussInt::from_i64(2, -3)), None);
+        let t = |n, d| BigRational::new(BigInt::new(n), BigInt::new(d)).unwrap();
+        assert_eq!(a.lerp_rational(&b, &t(0, 1)), Some(a.clone()));
+        assert_eq!(a.lerp_rational(&b, &t(1, 1)), Some(b.clone()));
+        assert_eq!(a.lerp_rational(&b, &t(1, 2)), a.midpoint(&b));
+        assert_eq!(
+            a.lerp_rational(&b, &t(3, 2)),
+            Some(GaussInt::from_i64(7, 12))
+        );
+        assert_eq!(
+            a.lerp_rational(&b, &t(-1, 2)),
+            Some(GaussInt::from_i64(-1, -8))
+        );
+        assert_eq!(a.lerp_rational(&b, &t(1, 3)), None);
+    }
+
+    #[test]
+    fn test_gauss_int_quarter_rotations() {
+        let z = GaussInt::from_i64(3, -7);
+        let i = GaussInt::from_i64(0, 1);
+        assert_eq!(z.mul_i(), &z * &i);
+        assert_eq!(z.div_i().mul_i(), z);
+        assert_eq!(z.div_i(), &z * &-&i);
+        for k in -9..=9 {
+            assert_eq!(z.rotate_quarter(k), &z * &i.pow_u32(k.rem_euclid(4) as u32));
+        }
+        assert_eq!(z.rotate_quarter(i32::MIN), z);
+        assert_eq!(z.rotate_quarter(-1), z.div_i());
+    }
+
+    #[test]
+    fn test_gauss_int_roots_of_unity() {
+        assert_eq!(GaussInt::roots_of_unity(0, 8), None);
+        let units = |v: &[(i64, i64)]| v.iter().map(|&(a, b)| GaussInt::from_i64(a, b)).collect();
+        assert_eq!(
+            GaussInt::roots_of_unity(1, 8),
+            Some(RootsOfUnity::Exact(units(&[(1, 0)])))
+        );
+        assert_eq!(
+            GaussInt::roots_of_unity(2, 8),
+            Some(RootsOfUnity::Exact(units(&[(1, 0), (-1, 0)])))
+        );
+        let fourth = GaussInt::roots_of_unity(4, 8).unwrap();
+        assert_eq!(
+            fourth.roots(),
+            &units(&[(1, 0), (0, 1), (-1, 0), (0, -1)])[..]
+        );
+        assert!(fourth.roots().iter().all(GaussInt::is_root_of_unity));
+
+        // Eighth roots at scale 2^16: √2/2·65536 ≈ 46340.95
+        let eighth = GaussInt::roots_of_unity(8, 16).unwrap();
+        assert!(!eighth.is_exact());
+        assert_eq!(eighth.roots().len(), 8);
+        assert_eq!(eighth.roots()[0], GaussInt::from_i64(65536, 0));
+        assert_eq!(eighth.roots()[1], GaussInt::from_i64(46341, 46341));
+        assert_eq!(eighth.roots()[6], GaussInt::from_i64(0, -65536));
+
+        // Scaled roots have norm close to 4^precision
+        let scale_sq = BigInt::new(1 << 40);
+        for root in GaussInt::roots_of_unity(7, 20).unwrap().roots() {
+            let err = (&root.norm() - &scale_sq).abs();
+            assert!(err <= BigInt::new(1 << 22), "{}", root);
+        }
+
+        assert!(!GaussInt::from_i64(1, 1).is_root_of_unity());
+        assert!(!GaussInt::zero().is_root_of_unity());
+    }
+
+    #[test]
+    fn test_gauss_int_pow_big() {
+        let z = GaussInt::from_i64(2, -3);
+        for e in 0..12u32 {
+            assert_eq!(z.pow_big(&BigInt::new(e as i64)), Some(z.pow_u32(e)));
+        }
+        assert_eq!(z.pow_big(&BigInt::new(-2)), None);
+        let huge = BigInt::new(2).pow(100);
+        assert_eq!(
+            GaussInt::zero().pow_big(&BigInt::zero()),
+            Some(GaussInt::one())
+        );
+        assert_eq!(GaussInt::zero().pow_big(&huge), Some(GaussInt::zero()));
+        let i = GaussInt::from_i64(0, 1);
+        assert_eq!(i.pow_big(&huge), Some(GaussInt::one()));
+        assert_eq!(i.pow_big(&(&huge + &BigInt::new(3))), Some(-&i));
+        assert_eq!(
+            GaussInt::from_i64(-1, 0).pow_big(&(&huge + &BigInt::one())),
+            Some(GaussInt::from_i64(-1, 0))
+        );
+    }
+
+    #[test]
+    fn test_gauss_int_reduce_mod() {
+        let m = GaussInt::from_i64(4, -2);
+        assert_eq!(GaussInt::from_i64(1, 1).reduce_mod(&GaussInt::zero()), None);
+        let associates = [m.clone(), m.mul_i(), -&m, m.div_i()];
+        let mut seen = Vec::new();
+        for a in -6..=6 {
+            for b in -6..=6 {
+                let z = GaussInt::from_i64(a, b);
+                let r = z.reduce_mod(&m).unwrap();
+                assert!((&z - &r).div_rem(&m).unwrap().1.is_zero());
+                assert!(&r.norm() * &BigInt::new(2) <= m.norm());
+                for u in &associates {
+                    assert_eq!(z.reduce_mod(u).as_ref(), Some(&r));
+                }
+                let shifted = &z + &(&m * &GaussInt::from_i64(b, -a));
+                assert_eq!(shifted.reduce_mod(&m).as_ref(), Some(&r));
+                if !seen.contains(&r) {
+                    seen.push(r);
+                }
+            }
+        }
+        // Z[i]/(m) has N(m) residue classes
+        assert_eq!(seen.len(), 20);
+    }
+
+    #[test]
+    fn test_gauss_int_associates_and_normalization() {
+        let z = GaussInt::from_i64(2, 5);
+        let associates = z.associates();
+        assert_eq!(
+            associates,
+            [
+                GaussInt::from_i64(2, 5),
+                GaussInt::from_i64(-5, 2),
+                GaussInt::from_i64(-2, -5),
+                GaussInt::from_i64(5, -2),
+            ]
+        );
+        for a in &associates {
+            let (canonical, unit) = a.normalize_associate();
+            assert_eq!(canonical, z);
+            assert!(unit.is_unit());
+            assert_eq!(a * &unit, canonical);
+        }
+        // Axis points normalize onto the positive real axis
+        for a in GaussInt::from_i64(0, -7).associates() {
+            assert_eq!(a.normalize_associate().0, GaussInt::from_i64(7, 0));
+        }
+        assert_eq!(
+            GaussInt::zero().normalize_associate(),
+            (GaussInt::zero(), GaussInt::one())
+        );
+    }
+
+    #[test]
+    fn test_gauss_int_gcd_is_deterministic() {
+        let a = GaussInt::from_i64(12, 18);
+        let b = GaussInt::from_i64(6, 8);
+        let g = a.gcd(&b);
+        assert_eq!(g, g.normalize_associate().0);
+        for x in a.associates() {
+            for y in b.associates() {
+                assert_eq!(x.gcd(&y), g);
+                assert_eq!(y.gcd(&x), g);
+            }
+        }
+        assert_eq!(
+            GaussInt::from_i64(4, -3).gcd(&GaussInt::zero()),
+            GaussInt::from_i64(3, 4)
+        );
+    }
+
+    #[test]
+    fn test_gauss_int_unit_helpers() {
+        assert_eq!(
+            GaussInt::units(),
+            [
+                GaussInt::one(),
+                GaussInt::I,
+                GaussInt::MINUS_ONE,
+                GaussInt::MINUS_I
+            ]
+        );
+        assert!(GaussInt::units().iter().all(GaussInt::is_unit));
+        assert_eq!(&GaussInt::I * &GaussInt::I, GaussInt::MINUS_ONE);
+        for z in GaussInt::from_i64(3, -8).associates() {
+            let u = z.unit_part();
+            assert!(u.is_unit());
+            assert_eq!(&u * &z.normalize_associate().0, z);
+        }
+        assert_eq!(GaussInt::from_i64(0, -4).unit_part(), GaussInt::MINUS_I);
+        assert_eq!(GaussInt::zero().unit_part(), GaussInt::one());
+        for u in GaussInt::units() {
+            assert_eq!(u.unit_part(), u);
+        }
+    }
+
+    #[test]
+    fn test_gauss_int_content_and_primitive_part() {
+        let cases = [
+            ((12, -18), 6),
+            ((-7, 0), 7),
+            ((0, 5), 5),
+            ((3, 4), 1),
+            ((0, 0), 0),
+        ];
+        for ((a, b), c) in cases {
+            let z = GaussInt::from_i64(a, b);
+            assert_eq!(z.content(), BigInt::new(c));
+            let p = z.primitive_part();
+            assert_eq!(&GaussInt::new(z.content(), BigInt::zero()) * &p, z);
+            if !z.is_zero() {
+                assert!(p.content().is_one());
+            }
+        }
+        assert_eq!(
+            GaussInt::from_i64(-4, 0).primitive_part(),
+            GaussInt::from_i64(-1, 0)
+        );
+    }
+
+    #[test]
+    fn test_gauss_int_divisibility() {
+        let a = GaussInt::from_i64(3, -2);
+        let b = GaussInt::from_i64(-4, 7);
+        let ab = &a * &b;
+        assert!(a.divides(&ab) && b.divides(&ab));
+        assert!(ab.is_multiple_of(&a));
+        assert!(!ab.divides(&a));
+        for u in GaussInt::units() {
+            assert!(u.divides(&a));
+            assert!((&a * &u).divides(&ab));
+        }
+        assert!(!a.divides(&(&ab + &GaussInt::one())));
+        // 1 + i divides exactly those a + bi with a + b even
+        let p = GaussInt::from_i64(1, 1);
+        for x in -3..=3 {
+            for y in -3..=3 {
+                let z = GaussInt::from_i64(x, y);
+                assert_eq!(p.divides(&z), (x + y) % 2 == 0);
+                assert_eq!(p.divides(&z), (&z % &p).is_zero());
+            }
+        }
+        assert!(GaussInt::zero().divides(&GaussInt::zero()));
+        assert!(!GaussInt::zero().divides(&a));
+        assert!(a.divides(&GaussInt::zero()));
+    }
+
+    #[test]
+    fn test_gauss_int_mul_large_components() {
+        // Components well above KARATSUBA_THRESHOLD_BITS take the 3-mult path
+        let big = BigInt::new(3).pow(1500);
+        let x = GaussInt::new(&big + &BigInt::new(17), -&(&big * &BigInt::new(5)));
+        let y = GaussInt::new(-&(&big - &BigInt::new(1)), &big * &big);
+        let schoolbook = GaussInt::new(
+            &(x.real() * y.real()) - &(x.imag() * y.imag()),
+            &(x.real() * y.imag()) + &(x.imag() * y.real()),
+        );
+        assert!(x.component_bits() >= KARATSUBA_THRESHOLD_BITS);
+        assert_eq!(&x * &y, schoolbook);
+        assert_eq!(x.clone() * y.clone(), schoolbook);
+        let mut z = x.clone();
+        z *= &y;
+        assert_eq!(z, schoolbook);
+        assert_eq!((&x * &y).norm(), &x.norm() * &y.norm());
+    }
+
+    #[test]
+    fn test_gauss_int_into_methods() {
+        let a = GaussInt::from_i64(7, -3);
+        let b = GaussInt::from_i64(-2, 9);
+        let mut out = GaussInt::from_i64(123456, -654321);
+        a.add_into(&b, &mut out);
+        assert_eq!(out, &a + &b);
+        a.sub_into(&b, &mut out);
+        assert_eq!(out, &a - &b);
+        a.mul_into(&b, &mut out);
+        assert_eq!(out, &a * &b);
+        a.mul_into(&a, &mut out);
+        assert_eq!(out, &a * &a);
+
+        let big = GaussInt::new(BigInt::new(5).pow(1000), BigInt::new(-7).pow(900));
+        big.mul_into(&big, &mut out);
+        assert_eq!(out, &big * &big);
+
+        let mut n = BigInt::new(1);
+        BigInt::new(6).mul_into(&BigInt::new(-7), &mut n);
+        assert_eq!(n, BigInt::new(-42));
+        BigInt::new(6).sub_into(&BigInt::new(-7), &mut n);
+        assert_eq!(n, BigInt::new(13));
+    }
+
+    #[test]
+    fn test_square_and_pow_match_repeated_multiplication() {
+        let big = BigInt::new(3).pow(1500);
+        for z in [
+            GaussInt::from_i64(0, 0),
+            GaussInt::from_i64(-7, 3),
+            GaussInt::new(big.clone(), &BigInt::new(5) - &big),
+        ] {
+            assert_eq!(z.square(), &z * &z);
+            let mut expected = GaussInt::one();
+            for e in 0..12 {
+                assert_eq!(z.pow_u32(e), expected);
+                assert_eq!(z.pow_big(&BigInt::new(e as i64)), Some(expected.clone()));
+                expected = &expected * &z;
+            }
+        }
+    }
+
+    #[test]
+    fn test_constants() {
+        const THREE_PLUS_I: GaussInt = GaussInt::from_i64(3, 1);
+        assert_eq!(GaussInt::ZERO, GaussInt::zero());
+        assert_eq!(GaussInt::ONE, GaussInt::one());
+        assert_eq!(GaussInt::I, GaussInt::from_i64(0, 1));
+        assert_eq!(
+            [
+                GaussInt::ONE,
+                GaussInt::I,
+                GaussInt::MINUS_ONE,
+                GaussInt::MINUS_I
+            ],
+            GaussInt::units()
+        );
+        assert_eq!(&THREE_PLUS_I * &GaussInt::I, GaussInt::from_i64(-1, 3));
+        assert_eq!(
+            (BigInt::ZERO, BigInt::ONE, BigInt::TWO),
+            (BigInt::new(0), BigInt::new(1), BigInt::new(2))
+        );
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_table_access() {
+        use rkyv::rancor::Error;
+
+        let big = BigInt::from_string("98765432109876543210987654321").unwrap();
+        let table = vec![
+            GaussInt::from_i64(3, -4),
+            GaussInt::new(big.clone(), -big),
+            GaussInt::ZERO,
+        ];
+        let bytes = rkyv::to_bytes::<Error>(&table).unwrap();
+        let archived =
+            rkyv::access::<rkyv::vec::ArchivedVec<ArchivedGaussInt>, Error>(&bytes).unwrap();
+        assert_eq!(archived[1], table[1]);
+        assert!(archived[1].imag().is_negative());
+        assert_eq!(archived[0].to_gauss_int(), table[0]);
+        assert_eq!(
+            rkyv::deserialize::<Vec<GaussInt>, Error>(archived).unwrap(),
+            table
+        );
+    }
+
+    #[test]
+    fn test_byte_encoding_round_trip() {
+        let big = BigInt::from_string("-123456789012345678901234567890").unwrap();
+        let values = [
+            GaussInt::ZERO,
+            GaussInt::MINUS_I,
+            GaussInt::from_i64(i64::MIN, i64::MAX),
+            GaussInt::new(big.clone(), -big),
+        ];
+        for z in values {
+            assert_eq!(GaussInt::from_bytes(&z.to_bytes()), Some(z));
+        }
+        assert_eq!(GaussInt::ZERO.to_bytes(), [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_byte_encoding_rejects_malformed() {
+        let bytes = GaussInt::from_i64(5, -7).to_bytes();
+        assert!(GaussInt::from_bytes(&[]).is_none());
+        assert!(GaussInt::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+        assert!(GaussInt::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_none());
+
+        let mut wrong_version = bytes.clone();
+        wrong_version[0] = 2;
+        assert!(GaussInt::from_bytes(&wrong_version).is_none());
+        // Negative zero, a leading zero byte, and an unknown sign byte.
+        assert!(GaussInt::from_bytes(&[1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]).is_none());
+        assert!(GaussInt::from_bytes(&[1, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0]).is_none());
+        assert!(GaussInt::from_bytes(&[1, 2, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0]).is_none());
+    }
+
+    #[test]
+    fn test_lexicographic_order() {
+        use std::collections::BTreeSet;
+
+        let mut values = vec![
+            GaussInt::from_i64(1, 1),
+            GaussInt::from_i64(-2, 7),
+            GaussInt::from_i64(1, -1),
+            GaussInt::ZERO,
+            GaussInt::from_i64(1, 1),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            [
+                GaussInt::from_i64(-2, 7),
+                GaussInt::ZERO,
+                GaussInt::from_i64(1, -1),
+                GaussInt::from_i64(1, 1),
+                GaussInt::from_i64(1, 1),
+            ]
+        );
+        let set: BTreeSet<GaussInt> = values.into_iter().collect();
+        assert_eq!(set.len(), 4);
+        assert!(GaussInt::I > GaussInt::ZERO && GaussInt::ONE > GaussInt::I);
+    }
+
+    #[test]
+    fn test_norm_comparisons() {
+        let z = GaussInt::from_i64(-3, 4);
+        assert_eq!(z.cmp_norm(&GaussInt::from_i64(5, 0)), Ordering::Equal);
+        assert_eq!(z.cmp_norm(&GaussInt::from_i64(1, 1)), Ordering::Greater);
+        assert!(GaussInt::I.is_closer_to_origin_than(&z));
+        assert!(!z.is_closer_to_origin_than(&GaussInt::from_i64(0, -5)));
+        assert!(!GaussInt::ZERO.is_closer_to_origin_than(&GaussInt::ZERO));
+    }
+
+    #[test]
+    fn test_fallible_division() {
+        use crate::Error;
+        let a = GaussInt::from_i64(7, 2);
+        let m = GaussInt::from_i64(3, 1);
+        assert_eq!(a.try_div_rem(&m), Ok(a.div_rem(&m).unwrap()));
+        assert_eq!(a.try_div_rem(&GaussInt::ZERO), Err(Error::DivisionByZero));
+        assert_eq!(a.try_reduce_mod(&m), Ok(GaussInt::from_i64(1, 0)));
+        assert_eq!(
+            a.try_reduce_mod(&GaussInt::ZERO),
+            Err(Error::DivisionByZero)
+        );
+        // 1 + i divides 2 = -i(1 + i)^2
+        assert_eq!(
+            GaussInt::from_i64(2, 0).div_exact(&GaussInt::from_i64(1, 1)),
+            Ok(GaussInt::from_i64(1, -1))
+        );
+        assert_eq!(a.div_exact(&m), Err(Error::NotExactlyDivisible));
+    }
+
+    #[test]
+    fn test_to_complex_f64() {
+        let huge = BigInt::new(3).pow(700);
+        let (z, exact) = GaussInt::new(-&huge, BigInt::new(7)).to_complex_f64();
+        assert_eq!((z.re, z.im, exact), (f64::NEG_INFINITY, 7.0, false));
+        let (z, exact) = GaussInt::from_i64(1 << 53, -(1 << 52)).to_complex_f64();
+        assert_eq!((z.re, z.im, exact), (2f64.powi(53), -(2f64.powi(52)), true));
+        let (z, _) = GaussInt::new(BigInt::new(3).pow(40), BigInt::ZERO).to_complex_f64();
+        assert_eq!(z.re, 3f64.powi(40));
+    }
+
+    #[test]
+    fn test_nearest_gauss_int() {
+        use num_complex::Complex;
+        let nearest = |re, im| GaussInt::from_complex_f64_rounded(Complex::new(re, im)).unwrap();
+        assert_eq!(nearest(-2.5, 0.49999), GaussInt::from_i64(-3, 0));
+        assert_eq!(
+            nearest(1e20, -7.6),
+            GaussInt::new(BigInt::new(10).pow(20), BigInt::new(-8))
+        );
+        assert!(GaussInt::from_complex_f64_rounded(Complex::new(0.0, f64::INFINITY)).is_err());
+
+        let q = |n, d| BigRational::new(BigInt::new(n), BigInt::new(d)).unwrap();
+        let z = ComplexRational::new(q(-5, 2), q(7, 3));
+        assert_eq!(GaussInt::round_from_rational(&z), GaussInt::from_i64(-3, 2));
+        let w = GaussInt::from_i64(4, -9);
+        assert_eq!(
+            GaussInt::round_from_rational(&ComplexRational::from(w.clone())),
+            w
+        );
+        assert!(ComplexRational::quotient(&w, &GaussInt::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_base_minus_one_plus_i() {
+        assert!(GaussInt::ZERO.to_base_minus_one_plus_i().is_empty());
+        assert_eq!(GaussInt::ONE.to_base_minus_one_plus_i(), vec![1]);
+        assert_eq!(
+            GaussInt::MINUS_ONE.to_base_minus_one_plus_i(),
+            vec![1, 0, 1, 1, 1]
+        );
+        for a in -20..=20 {
+            for b in -20..=20 {
+                let z = GaussInt::from_i64(a, b);
+                let digits = z.to_base_minus_one_plus_i();
+                assert_ne!(digits.last(), Some(&0));
+                assert_eq!(GaussInt::from_base_minus_one_plus_i(&digits), Some(z));
+            }
+        }
+        let big = GaussInt::new(BigInt::new(3).pow(80), -BigInt::new(7).pow(50));
+        let digits = big.to_base_minus_one_plus_i();
+        assert_eq!(GaussInt::from_base_minus_one_plus_i(&digits), Some(big));
+        assert_eq!(
+            GaussInt::from_base_minus_one_plus_i(&[1, 0, 0]),
+            Some(GaussInt::ONE)
+        );
+        assert_eq!(GaussInt::from_base_minus_one_plus_i(&[2]), None);
+    }
+
+    #[test]
+    fn test_write_to_matches_display() {
+        let big = BigInt::new(3).pow(2000);
+        for z in [
+            GaussInt::ZERO,
+            GaussInt::I,
+            GaussInt::MINUS_I,
+            GaussInt::from_i64(0, -5),
+            GaussInt::new(big.clone(), -&big),
+            GaussInt::new(-&big, BigInt::new(2)),
+        ] {
+            let mut text = String::new();
+            z.write_to(&mut text).unwrap();
+            assert_eq!(text, z.to_string());
+            let mut bytes = Vec::new();
+            z.write_to_io(&mut bytes).unwrap();
+            assert_eq!(bytes, text.into_bytes());
+        }
+    }
+
+    #[test]
+    fn test_read_from_round_trips_display() {
+        let big = BigInt::new(7).pow(900);
+        for z in [
+            GaussInt::ZERO,
+            GaussInt::I,
+            GaussInt::MINUS_I,
+            GaussInt::from_i64(0, -12),
+            GaussInt::from_i64(5, 1),
+            GaussInt::from_i64(-5, -1),
+            GaussInt::new(-&big, big.clone()),
+        ] {
+            let text = format!(" {}\n", z);
+            assert_eq!(GaussInt::read_from(text.as_bytes()).unwrap(), z);
+        }
+        assert_eq!(
+            GaussInt::read_from("+3-i".as_bytes()).unwrap(),
+            GaussInt::from_i64(3, -1)
+        );
+        for bad in ["", "+", "3+", "3+4", "3i+4i", "3 +4i", "ii", "2+-3i"] {
+            let err = GaussInt::read_from(bad.as_bytes()).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData, "{:?}", bad);
+            let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+            assert!(matches!(*inner, Error::ParseGaussInt(_)), "{:?}", bad);
+        }
+        let err = GaussInt::read_from("3+4".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid Gaussian integer: unexpected end of input at byte 3"
+        );
+    }
+
+    #[test]
+    fn test_try_from_string_positions() {
+        let big = BigInt::new(7).pow(90);
+        for z in [
+            GaussInt::ZERO,
+            GaussInt::I,
+            GaussInt::from_i64(0, -12),
+            GaussInt::from_i64(5, -1),
+            GaussInt::new(big.clone(), -&big),
+        ] {
+            assert_eq!(GaussInt::try_from_string(&z.to_string()), Ok(z.clone()));
+            assert_eq!(z.to_string().parse::<GaussInt>(), Ok(z));
+        }
+        for (text, offset, found) in [
+            ("", 0, None),
+            ("3+", 2, None),
+            ("3+4", 3, None),
+            ("3i+4i", 2, Some('+')),
+            (" 3", 0, Some(' ')),
+            ("2+-3i", 2, Some('-')),
+            ("ii", 1, Some('i')),
+            ("1_i", 2, Some('i')),
+            ("3+_4i", 2, Some('_')),
+        ] {
+            let err = GaussInt::try_from_string(text).unwrap_err();
+            assert_eq!((err.offset(), err.found()), (offset, found), "{:?}", text);
+        }
+        assert_eq!(
+            "x".parse::<GaussInt>(),
+            Err(Error::ParseGaussInt(ParseGaussIntError::at("x", 0)))
+        );
+        assert_eq!(
+            GaussInt::try_from_string("12_345+6_789i"),
+            Ok(GaussInt::from_i64(12_345, 6_789))
+        );
+        assert_eq!(
+            GaussInt::read_from("-12_345-6_789i".as_bytes()).unwrap(),
+            GaussInt::from_i64(-12_345, -6_789)
+        );
+    }
+
+    #[test]
+    fn test_div_rounded_matches_f64_rounding() {
+        use RoundingMode::*;
+        let divisors = [(2, 0), (1, 1), (3, -4), (0, -6), (5, 2)];
+        for (a, b) in [(7, 5), (-7, 5), (13, -9), (-20, -1), (0, 0), (9, 4)] {
+            let z = GaussInt::from_i64(a, b);
+            for (c, d) in divisors {
+                let w = GaussInt::from_i64(c, d);
+                let norm = (c * c + d * d) as f64;
+                let re = (a * c + b * d) as f64 / norm;
+                let im = (b * c - a * d) as f64 / norm;
+                for mode in [Floor, Ceil, TowardZero, NearestEven, NearestAway] {
+                    let expected = GaussInt::new(
+                        BigInt::from_f64(re, mode).unwrap(),
+                        BigInt::from_f64(im, mode).unwrap(),
+                    );
+                    assert_eq!(z.div_rounded(&w, mode), Some(expected), "{:?}", mode);
+                }
+                assert_eq!(
+                    z.div_rounded(&w, NearestAway),
+                    z.div_rem(&w).map(|(q, _)| q)
+                );
+            }
+        }
+        assert_eq!(GaussInt::ONE.div_rounded(&GaussInt::ZERO, Floor), None);
+    }
+
+    #[test]
+    fn test_div_exact_complex() {
+        let big = GaussInt::new(BigInt::new(7).pow(40), -BigInt::new(3).pow(55));
+        let divisor = GaussInt::from_i64(-11, 4);
+        let product = &big * &divisor;
+        assert_eq!(product.div_exact_complex(&divisor), Some(big.clone()));
+        assert_eq!(product.div_exact_complex(&big), Some(divisor.clone()));
+        // Divisible by the norm's factors but not by the element itself
+        let p = GaussInt::from_i64(1, 2);
+        assert_eq!(p.div_exact_complex(&p.conjugate()), None);
+        assert_eq!((&p + &GaussInt::ONE).div_exact_complex(&p), None);
+        assert_eq!(GaussInt::ZERO.div_exact_complex(&p), Some(GaussInt::ZERO));
+        assert_eq!(p.div_exact_complex(&GaussInt::ZERO), None);
+        assert_eq!(p.div_exact(&GaussInt::ZERO), Err(Error::DivisionByZero));
+        assert_eq!(p.div_exact(&p.conjugate()), Err(Error::NotExactlyDivisible));
+        for u in GaussInt::units() {
+            assert_eq!(big.div_exact_complex(&u), Some(&big * &u.conjugate()));
+        }
+    }
 }
@@ -0,0 +1,323 @@
+//! Residues modulo a Gaussian integer, carrying their modulus.
+//!
+//! `GaussResidue` is the analogue of [`ModInt`](crate::mod_int::ModInt) for
+//! the ring `Z[i]/(m)`. Values are kept in the canonical form produced by
+//! [`GaussInt::reduce_mod`], and the modulus is stored as its associate with
+//! real > 0 and imag >= 0, so associate moduli describe the same ring.
+
+use crate::{BigInt, GaussInt};
+use num_traits::{One, Zero};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// An element of `Z[i]/(m)` together with its modulus `m`.
+///
+/// Binary operators panic if the operands have different moduli.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::{BigInt, GaussInt};
+/// use gauss_int::gauss_residue::GaussResidue;
+///
+/// let m = GaussInt::from_i64(3, 2); // N(m) = 13, a Gaussian prime
+/// let a = GaussResidue::new(GaussInt::from_i64(5, 1), m).unwrap();
+/// let one = GaussInt::from_i64(1, 0);
+/// assert_eq!((&a * &a.inv().unwrap()).value(), &one);
+/// assert_eq!(a.pow(&BigInt::new(12)).unwrap().value(), &one);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GaussResidue {
+    value: GaussInt,
+    modulus: GaussInt,
+}
+
+impl GaussResidue {
+    /// Creates the residue of `value` modulo `modulus`.
+    ///
+    /// Returns `None` if `modulus` is zero.
+    pub fn new(value: GaussInt, modulus: GaussInt) -> Option<Self> {
+        let (modulus, _) = modulus.normalize_associate();
+        let value = value.reduce_mod(&modulus)?;
+        Some(GaussResidue { value, modulus })
+    }
+
+    /// Returns the canonical representative, as given by
+    /// [`GaussInt::reduce_mod`].
+    pub fn value(&self) -> &GaussInt {
+        &self.value
+    }
+
+    /// Returns the modulus, normalized to real > 0 and imag >= 0.
+    pub fn modulus(&self) -> &GaussInt {
+        &self.modulus
+    }
+
+    /// Returns `true` if the value is a multiple of the modulus.
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Returns the multiplicative inverse, or `None` if the value is not a
+    /// unit of `Z[i]/(m)`, i.e. shares a non-unit factor with the modulus.
+    pub fn inv(&self) -> Option<Self> {
+        // Extended Euclid: old_s·value ≡ old_r (mod m) throughout
+        let (mut old_r, mut r) = (self.value.clone(), self.modulus.clone());
+        let (mut old_s, mut s) = (GaussInt::one(), GaussInt::zero());
+        while !r.is_zero() {
+            let (q, rem) = old_r.div_rem(&r)?;
+            old_r = std::mem::replace(&mut r, rem);
+            let next_s = &old_s - &(&q * &s);
+            old_s = std::mem::replace(&mut s, next_s);
+        }
+        if !old_r.is_unit() {
+            return None;
+        }
+        // The inverse of a unit is its conjugate
+        Some(self.reduced(&old_s * &old_r.conjugate()))
+    }
+
+    /// Raises to the power `exp`.
+    ///
+    /// Negative exponents invert first; returns `None` if that inverse does
+    /// not exist.
+    pub fn pow(&self, exp: &BigInt) -> Option<Self> {
+        if exp.is_negative() {
+            return self.inv()?.pow(&-exp);
+        }
+        let mut result = self.reduced(GaussInt::one());
+        let mut base = self.clone();
+        let mut e = exp.clone();
+        let two = BigInt::new(2);
+        while !e.is_zero() {
+            let (q, r) = e.div_rem(&two);
+            if !r.is_zero() {
+                result = &result * &base;
+            }
+            e = q;
+            if !e.is_zero() {
+                base = &base * &base;
+            }
+        }
+        Some(result)
+    }
+
+    /// Reduces a value and attaches this modulus.
+    fn reduced(&self, value: GaussInt) -> Self {
+        GaussResidue {
+            value: value.reduce_mod(&self.modulus).expect("nonzero modulus"),
+            modulus: self.modulus.clone(),
+        }
+    }
+
+    fn check_modulus(&self, other: &Self) {
+        assert!(
+            self.modulus == other.modulus,
+            "modulus mismatch: {} vs {}",
+            self.modulus,
+            other.modulus
+        );
+    }
+}
+
+impl fmt::Display for GaussResidue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.modulus)
+    }
+}
+
+// --- Neg ---
+
+impl Neg for &GaussResidue {
+    type Output = GaussResidue;
+
+    fn neg(self) -> GaussResidue {
+        self.reduced(-&self.value)
+    }
+}
+
+impl Neg for GaussResidue {
+    type Output = GaussResidue;
+
+    fn neg(self) -> GaussResidue {
+        -&self
+    }
+}
+
+// --- Add ---
+
+impl Add for &GaussResidue {
+    type Output = GaussResidue;
+
+    fn add(self, other: &GaussResidue) -> GaussResidue {
+        self.check_modulus(other);
+        self.reduced(&self.value + &other.value)
+    }
+}
+
+impl Add for GaussResidue {
+    type Output = GaussResidue;
+
+    fn add(self, other: GaussResidue) -> GaussResidue {
+        &self + &other
+    }
+}
+
+impl Add<&GaussResidue> for GaussResidue {
+    type Output = GaussResidue;
+
+    fn add(self, other: &GaussResidue) -> GaussResidue {
+        &self + other
+    }
+}
+
+impl Add<GaussResidue> for &GaussResidue {
+    type Output = GaussResidue;
+
+    fn add(self, other: GaussResidue) -> GaussResidue {
+        self + &other
+    }
+}
+
+// --- Sub ---
+
+impl Sub for &GaussResidue {
+    type Output = GaussResidue;
+
+    fn sub(self, other: &GaussResidue) -> GaussResidue {
+        self.check_modulus(other);
+        self.reduced(&self.value - &other.value)
+    }
+}
+
+impl Sub for GaussResidue {
+    type Output = GaussResidue;
+
+    fn sub(self, other: GaussResidue) -> GaussResidue {
+        &self - &other
+    }
+}
+
+impl Sub<&GaussResidue> for GaussResidue {
+    type Output = GaussResidue;
+
+    fn sub(self, other: &GaussResidue) -> GaussResidue {
+        &self - other
+    }
+}
+
+impl Sub<GaussResidue> for &GaussResidue {
+    type Output = GaussResidue;
+
+    fn sub(self, other: GaussResidue) -> GaussResidue {
+        self - &other
+    }
+}
+
+// --- Mul ---
+
+impl Mul for &GaussResidue {
+    type Output = GaussResidue;
+
+    fn mul(self, other: &GaussResidue) -> GaussResidue {
+        self.check_modulus(other);
+        self.reduced(&self.value * &other.value)
+    }
+}
+
+impl Mul for GaussResidue {
+    type Output = GaussResidue;
+
+    fn mul(self, other: GaussResidue) -> GaussResidue {
+        &self * &other
+    }
+}
+
+impl Mul<&GaussResidue> for GaussResidue {
+    type Output = GaussResidue;
+
+    fn mul(self, other: &GaussResidue) -> GaussResidue {
+        &self * other
+    }
+}
+
+impl Mul<GaussResidue> for &GaussResidue {
+    type Output = GaussResidue;
+
+    fn mul(self, other: GaussResidue) -> GaussResidue {
+        self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(a: i64, b: i64, m: &GaussInt) -> GaussResidue {
+        GaussResidue::new(GaussInt::from_i64(a, b), m.clone()).unwrap()
+    }
+
+    #[test]
+    fn test_gauss_residue_creation() {
+        assert!(GaussResidue::new(GaussInt::from_i64(1, 1), GaussInt::zero()).is_none());
+        let m = GaussInt::from_i64(-2, 3);
+        let x = r(9, -4, &m);
+        assert_eq!(x.modulus(), &GaussInt::from_i64(3, 2));
+        assert_eq!(
+            x.value(),
+            &GaussInt::from_i64(9, -4).reduce_mod(&m).unwrap()
+        );
+        // Associate moduli give the same ring
+        assert_eq!(x, r(9, -4, &m.mul_i()));
+        // Congruent values are equal
+        assert_eq!(x, r(9 + 3, -4 + 2, &m));
+        assert_eq!(x.to_string(), format!("{} (mod 3+2i)", x.value()));
+    }
+
+    #[test]
+    fn test_gauss_residue_ring_operations() {
+        let m = GaussInt::from_i64(4, 2);
+        let (x, y) = (r(7, -3, &m), r(-5, 11, &m));
+        let lift = |a: i64, b: i64| GaussInt::from_i64(a, b);
+        assert_eq!(&x + &y, r(2, 8, &m));
+        assert_eq!(&x - &y, r(12, -14, &m));
+        assert_eq!(
+            &x * &y,
+            GaussResidue::new(&lift(7, -3) * &lift(-5, 11), m.clone()).unwrap()
+        );
+        assert!((&x + &-&x).is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus mismatch")]
+    fn test_gauss_residue_mismatch_panics() {
+        let _ = r(1, 0, &GaussInt::from_i64(3, 0)) + r(1, 0, &GaussInt::from_i64(2, 1));
+    }
+
+    #[test]
+    fn test_gauss_residue_inverse_and_pow() {
+        // 4 + 2i = (1 + i)(3 - i), so Z[i]/(m) has zero divisors
+        let m = GaussInt::from_i64(4, 2);
+        let mut units = 0;
+        for a in -4..4 {
+            for b in -4..4 {
+                let x = r(a, b, &m);
+                match x.inv() {
+                    Some(inv) => {
+                        assert!((&x * &inv).value().is_one());
+                        units += 1;
+                    }
+                    None => assert!(!GaussInt::from_i64(a, b).gcd(&m).is_unit()),
+                }
+            }
+        }
+        assert!(units > 0);
+        // Fermat in Z[i]/(π) for the prime π = 3 + 2i: x^(N(π)-1) = 1
+        let p = GaussInt::from_i64(3, 2);
+        let x = r(2, 5, &p);
+        assert!(x.pow(&BigInt::new(12)).unwrap().value().is_one());
+        assert_eq!(x.pow(&BigInt::new(-1)), x.inv());
+        assert_eq!(x.pow(&BigInt::zero()).unwrap(), r(1, 0, &p));
+        assert_eq!(r(1, 1, &m).pow(&BigInt::new(-1)), None);
+    }
+}
@@ -0,0 +1,419 @@
+//! Exact computational-geometry predicates on Gaussian integers.
+//!
+//! Each `GaussInt` `x + yi` is treated as the point `(x, y)`. Because the
+//! coordinates are arbitrary-precision integers, the predicates evaluate
+//! their determinants exactly and never suffer from floating-point
+//! misclassification.
+
+use crate::{BigInt, GaussInt};
+use std::cmp::Ordering;
+
+/// Orientation of the triangle `(a, b, c)`.
+///
+/// Returns `Greater` if `c` lies to the left of the directed line `a → b`
+/// (counter-clockwise turn), `Less` if it lies to the right (clockwise),
+/// and `Equal` if the three points are collinear.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::geometry::orient2d;
+/// use std::cmp::Ordering;
+///
+/// let a = GaussInt::from_i64(0, 0);
+/// let b = GaussInt::from_i64(4, 0);
+/// assert_eq!(orient2d(&a, &b, &GaussInt::from_i64(1, 3)), Ordering::Greater);
+/// assert_eq!(orient2d(&a, &b, &GaussInt::from_i64(1, -3)), Ordering::Less);
+/// assert_eq!(orient2d(&a, &b, &GaussInt::from_i64(9, 0)), Ordering::Equal);
+/// ```
+pub fn orient2d(a: &GaussInt, b: &GaussInt, c: &GaussInt) -> Ordering {
+    (b - a).cross(&(c - a)).cmp(&BigInt::new(0))
+}
+
+/// Position of `d` relative to the circle through `a`, `b` and `c`.
+///
+/// For counter-clockwise `(a, b, c)` this returns `Greater` if `d` is
+/// strictly inside the circle, `Less` if strictly outside and `Equal` if it
+/// lies on it; the result is reversed for clockwise triangles, and `Equal`
+/// whenever `a`, `b`, `c` are collinear and `d` is on their line.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::geometry::in_circle;
+/// use std::cmp::Ordering;
+///
+/// // Circle of radius 5 about the origin, traversed counter-clockwise
+/// let a = GaussInt::from_i64(5, 0);
+/// let b = GaussInt::from_i64(3, 4);
+/// let c = GaussInt::from_i64(-4, -3);
+/// assert_eq!(in_circle(&a, &b, &c, &GaussInt::from_i64(1, 1)), Ordering::Greater);
+/// assert_eq!(in_circle(&a, &b, &c, &GaussInt::from_i64(0, -5)), Ordering::Equal);
+/// assert_eq!(in_circle(&a, &b, &c, &GaussInt::from_i64(6, 0)), Ordering::Less);
+/// ```
+pub fn in_circle(a: &GaussInt, b: &GaussInt, c: &GaussInt, d: &GaussInt) -> Ordering {
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    let det = &(&(&ad.norm() * &bd.cross(&cd)) + &(&bd.norm() * &cd.cross(&ad)))
+        + &(&cd.norm() * &ad.cross(&bd));
+    det.cmp(&BigInt::new(0))
+}
+
+/// Computes the convex hull with Andrew's monotone chain.
+///
+/// Returns the hull vertices in counter-clockwise order, starting from the
+/// point with the smallest real part (smallest imaginary part on ties).
+/// Duplicate points and points in the interior of hull edges are dropped.
+/// Fewer than three distinct points, or all-collinear input, yield the
+/// distinct extreme points.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::geometry::convex_hull;
+///
+/// let points: Vec<GaussInt> = [(0, 0), (2, 0), (1, 1), (2, 2), (0, 2), (1, 0)]
+///     .iter()
+///     .map(|&(x, y)| GaussInt::from_i64(x, y))
+///     .collect();
+/// let hull: Vec<String> = convex_hull(&points).iter().map(|p| p.to_string()).collect();
+/// assert_eq!(hull, ["0", "2", "2+2i", "2i"]);
+/// ```
+pub fn convex_hull(points: &[GaussInt]) -> Vec<GaussInt> {
+    let mut sorted: Vec<&GaussInt> = points.iter().collect();
+    sorted.sort();
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted.into_iter().cloned().collect();
+    }
+
+    let mut lower = half_hull(sorted.iter().copied());
+    let mut upper = half_hull(sorted.iter().rev().copied());
+    lower.pop();
+    upper.pop();
+    lower.into_iter().chain(upper).cloned().collect()
+}
+
+/// One monotone chain: keeps only strict counter-clockwise turns.
+fn half_hull<'a>(points: impl Iterator<Item = &'a GaussInt>) -> Vec<&'a GaussInt> {
+    let mut chain: Vec<&GaussInt> = Vec::new();
+    for p in points {
+        while chain.len() >= 2
+            && orient2d(chain[chain.len() - 2], chain[chain.len() - 1], p) != Ordering::Greater
+        {
+            chain.pop();
+        }
+        chain.push(p);
+    }
+    chain
+}
+
+/// Twice the signed area of a simple polygon, by the shoelace formula.
+///
+/// The result is positive for counter-clockwise vertex order and negative
+/// for clockwise; doubling keeps it an exact integer.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::{BigInt, GaussInt};
+/// use gauss_int::geometry::polygon_area_doubled;
+///
+/// let triangle = [GaussInt::from_i64(0, 0), GaussInt::from_i64(3, 0), GaussInt::from_i64(0, 1)];
+/// assert_eq!(polygon_area_doubled(&triangle), BigInt::new(3)); // area 3/2
+/// ```
+pub fn polygon_area_doubled(polygon: &[GaussInt]) -> BigInt {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .fold(BigInt::new(0), |acc, (a, b)| &acc + &a.cross(b))
+}
+
+/// The centroid (arithmetic mean) of `points`.
+///
+/// Returns `None` for an empty slice or when the mean is not a Gaussian
+/// integer.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::geometry::centroid;
+///
+/// let pts = [GaussInt::from_i64(0, 0), GaussInt::from_i64(6, 0), GaussInt::from_i64(0, 3)];
+/// assert_eq!(centroid(&pts), Some(GaussInt::from_i64(2, 1)));
+/// assert_eq!(centroid(&pts[..2]), Some(GaussInt::from_i64(3, 0)));
+/// assert_eq!(centroid(&[]), None);
+/// ```
+pub fn centroid(points: &[GaussInt]) -> Option<GaussInt> {
+    if points.is_empty() {
+        return None;
+    }
+    let sum = points
+        .iter()
+        .fold(GaussInt::from_i64(0, 0), |acc, p| &acc + p);
+    sum.div_exact_by(&BigInt::new(points.len() as i64))
+}
+
+/// Returns the value of largest norm, i.e. farthest from the origin; the
+/// first one on ties, and `None` for an empty slice.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::geometry::{max_by_norm, min_by_norm};
+///
+/// let values = [GaussInt::from_i64(3, 4), GaussInt::from_i64(1, -1), GaussInt::from_i64(-5, 0)];
+/// assert_eq!(max_by_norm(&values), Some(&values[0]));
+/// assert_eq!(min_by_norm(&values), Some(&values[1]));
+/// ```
+pub fn max_by_norm(values: &[GaussInt]) -> Option<&GaussInt> {
+    extreme_by_norm(values, Ordering::Greater)
+}
+
+/// Returns the value of smallest norm, i.e. closest to the origin; the
+/// first one on ties, and `None` for an empty slice.
+pub fn min_by_norm(values: &[GaussInt]) -> Option<&GaussInt> {
+    extreme_by_norm(values, Ordering::Less)
+}
+
+/// The first value whose norm compares as `wanted` against every earlier
+/// candidate, computing each norm once.
+fn extreme_by_norm(values: &[GaussInt], wanted: Ordering) -> Option<&GaussInt> {
+    let mut iter = values.iter();
+    let first = iter.next()?;
+    let mut best = (first.norm(), first);
+    for v in iter {
+        let norm = v.norm();
+        if norm.cmp(&best.0) == wanted {
+            best = (norm, v);
+        }
+    }
+    Some(best.1)
+}
+
+/// Returns every candidate at the minimal distance from `target`, in input
+/// order.
+///
+/// Distances are compared as exact squared norms `N(c - target)`, so ties
+/// are detected exactly. Returns an empty vector if there are no candidates.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::geometry::nearest;
+///
+/// let candidates = [GaussInt::from_i64(3, 0), GaussInt::from_i64(0, 2), GaussInt::from_i64(-2, 0)];
+/// let closest = nearest(&GaussInt::from_i64(0, 0), &candidates);
+/// assert_eq!(closest, [&candidates[1], &candidates[2]]);
+/// ```
+pub fn nearest<'a>(target: &GaussInt, candidates: &'a [GaussInt]) -> Vec<&'a GaussInt> {
+    let mut best: Option<BigInt> = None;
+    let mut closest = Vec::new();
+    for c in candidates {
+        let dist = (c - target).norm();
+        match best.as_ref().map(|b| dist.cmp(b)) {
+            Some(Ordering::Greater) => {}
+            Some(Ordering::Equal) => closest.push(c),
+            _ => {
+                best = Some(dist);
+                closest.clear();
+                closest.push(c);
+            }
+        }
+    }
+    closest
+}
+
+/// Returns the `k` candidates closest to `target`, nearest first.
+///
+/// Equidistant candidates keep their input order, so the result is
+/// deterministic. Returns all candidates if there are fewer than `k`.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::geometry::k_nearest;
+///
+/// let candidates = [
+///     GaussInt::from_i64(5, 5),
+///     GaussInt::from_i64(1, 0),
+///     GaussInt::from_i64(0, -1),
+///     GaussInt::from_i64(2, 2),
+/// ];
+/// let found = k_nearest(&GaussInt::from_i64(0, 0), &candidates, 3);
+/// assert_eq!(found, [&candidates[1], &candidates[2], &candidates[3]]);
+/// ```
+pub fn k_nearest<'a>(target: &GaussInt, candidates: &'a [GaussInt], k: usize) -> Vec<&'a GaussInt> {
+    let mut by_distance: Vec<(BigInt, &GaussInt)> = candidates
+        .iter()
+        .map(|c| ((c - target).norm(), c))
+        .collect();
+    by_distance.sort_by(|a, b| a.0.cmp(&b.0));
+    by_distance.into_iter().take(k).map(|(_, c)| c).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: i64, y: i64) -> GaussInt {
+        GaussInt::from_i64(x, y)
+    }
+
+    #[test]
+    fn test_orient2d_is_antisymmetric() {
+        let (a, b, c) = (p(-3, 7), p(10, 2), p(4, 12));
+        assert_eq!(orient2d(&a, &b, &c), Ordering::Greater);
+        assert_eq!(orient2d(&b, &a, &c), Ordering::Less);
+        assert_eq!(orient2d(&b, &c, &a), Ordering::Greater);
+        assert_eq!(orient2d(&a, &a, &c), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_orient2d_near_degenerate() {
+        // Points that a double-precision evaluation misclassifies: the
+        // coordinates exceed 2^53, and c is one unit off the line a→b.
+        let big = GaussInt::new(BigInt::new(1 << 60), BigInt::new((1 << 60) + 1));
+        let a = p(0, 0);
+        let on_line = &big + &big;
+        let off_line = &on_line + &p(0, 1);
+        assert_eq!(orient2d(&a, &big, &on_line), Ordering::Equal);
+        assert_eq!(orient2d(&a, &big, &off_line), Ordering::Greater);
+        assert_eq!(orient2d(&a, &big, &(&on_line - &p(0, 1))), Ordering::Less);
+    }
+
+    #[test]
+    fn test_in_circle_orientation_and_cocircular() {
+        let (a, b, c) = (p(5, 0), p(0, 5), p(-5, 0));
+        let inside = p(0, 0);
+        assert_eq!(in_circle(&a, &b, &c, &inside), Ordering::Greater);
+        // Clockwise triangle flips the sign
+        assert_eq!(in_circle(&c, &b, &a, &inside), Ordering::Less);
+        for on in [p(0, -5), p(3, -4), p(-4, 3)] {
+            assert_eq!(in_circle(&a, &b, &c, &on), Ordering::Equal);
+        }
+        // Collinear triangle with d on the same line
+        assert_eq!(
+            in_circle(&p(0, 0), &p(1, 1), &p(2, 2), &p(7, 7)),
+            Ordering::Equal
+        );
+    }
+
+    fn points(coords: &[(i64, i64)]) -> Vec<GaussInt> {
+        coords.iter().map(|&(x, y)| p(x, y)).collect()
+    }
+
+    #[test]
+    fn test_convex_hull_drops_interior_and_collinear() {
+        let pts = points(&[
+            (0, 0),
+            (4, 0),
+            (2, 0),
+            (4, 4),
+            (0, 4),
+            (2, 2),
+            (1, 3),
+            (0, 2),
+            (4, 4),
+        ]);
+        assert_eq!(convex_hull(&pts), points(&[(0, 0), (4, 0), (4, 4), (0, 4)]));
+    }
+
+    #[test]
+    fn test_convex_hull_degenerate_inputs() {
+        assert!(convex_hull(&[]).is_empty());
+        assert_eq!(convex_hull(&points(&[(1, 1), (1, 1)])), points(&[(1, 1)]));
+        assert_eq!(
+            convex_hull(&points(&[(3, 3), (1, 1), (2, 2), (0, 0)])),
+            points(&[(0, 0), (3, 3)])
+        );
+    }
+
+    #[test]
+    fn test_convex_hull_is_convex_and_contains_all_points() {
+        let mut state = 12345u64;
+        let pts: Vec<GaussInt> = (0..200)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                p(
+                    ((state >> 33) % 1000) as i64 - 500,
+                    ((state >> 13) % 1000) as i64 - 500,
+                )
+            })
+            .collect();
+        let hull = convex_hull(&pts);
+        let n = hull.len();
+        assert!(n >= 3);
+        for i in 0..n {
+            let (a, b) = (&hull[i], &hull[(i + 1) % n]);
+            assert_eq!(orient2d(a, b, &hull[(i + 2) % n]), Ordering::Greater);
+            for q in &pts {
+                assert_ne!(orient2d(a, b, q), Ordering::Less);
+            }
+        }
+        assert!(polygon_area_doubled(&hull).is_positive());
+    }
+
+    #[test]
+    fn test_polygon_area_doubled() {
+        let square = points(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(polygon_area_doubled(&square), BigInt::new(32));
+        let clockwise: Vec<GaussInt> = square.iter().rev().cloned().collect();
+        assert_eq!(polygon_area_doubled(&clockwise), BigInt::new(-32));
+        assert_eq!(polygon_area_doubled(&[]), BigInt::new(0));
+        assert_eq!(polygon_area_doubled(&points(&[(5, 5)])), BigInt::new(0));
+    }
+
+    #[test]
+    fn test_centroid_exactness() {
+        assert_eq!(centroid(&points(&[(1, 1), (2, 2)])), None);
+        assert_eq!(centroid(&points(&[(1, 1), (2, 2), (3, 3)])), Some(p(2, 2)));
+        assert_eq!(centroid(&points(&[(-7, 4)])), Some(p(-7, 4)));
+    }
+
+    #[test]
+    fn test_nearest_reports_all_ties() {
+        let target = p(1, 1);
+        let cands = points(&[(4, 4), (1, 3), (3, 1), (-1, 1), (1, -1), (2, 3)]);
+        let found = nearest(&target, &cands);
+        assert_eq!(found, vec![&cands[1], &cands[2], &cands[3], &cands[4]]);
+        assert!(nearest(&target, &[]).is_empty());
+        assert_eq!(nearest(&target, &points(&[(1, 1)])), vec![&p(1, 1)]);
+    }
+
+    #[test]
+    fn test_k_nearest_orders_by_distance_then_input() {
+        let target = p(0, 0);
+        let cands = points(&[(3, 0), (0, 1), (2, 2), (-1, 0), (0, -3)]);
+        let found = k_nearest(&target, &cands, 4);
+        assert_eq!(found, vec![&cands[1], &cands[3], &cands[2], &cands[0]]);
+        assert_eq!(k_nearest(&target, &cands, 10).len(), 5);
+        assert!(k_nearest(&target, &cands, 0).is_empty());
+        // Squared distances that differ only beyond f64 precision
+        let far = GaussInt::new(BigInt::new(1 << 60), BigInt::new(0));
+        let cands = vec![&far + &p(1, 0), &far + &p(0, 1)];
+        assert_eq!(nearest(&far, &cands).len(), 2);
+        assert_eq!(k_nearest(&p(0, 0), &cands, 1), vec![&cands[1]]);
+    }
+
+    #[test]
+    fn test_extremes_by_norm() {
+        let values = [
+            GaussInt::from_i64(0, 5),
+            GaussInt::from_i64(2, 2),
+            GaussInt::from_i64(-3, -4),
+            GaussInt::from_i64(2, -2),
+        ];
+        // Ties go to the first occurrence.
+        assert_eq!(max_by_norm(&values), Some(&values[0]));
+        assert_eq!(min_by_norm(&values), Some(&values[1]));
+        assert_eq!(max_by_norm(&[]), None);
+        assert_eq!(min_by_norm(&values[2..3]), Some(&values[2]));
+    }
+}
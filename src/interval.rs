@@ -0,0 +1,397 @@
+//! Interval arithmetic with BigInt endpoints.
+//!
+//! An [`Interval`] `[lo, hi]` encloses an unknown real value; every operation
+//! returns an interval guaranteed to contain all possible results. This makes
+//! it possible to mix exact integer arithmetic with truncating operations
+//! such as integer square roots without losing rigour. [`ComplexInterval`]
+//! is the rectangle variant for Gaussian integers.
+
+use crate::{BigInt, GaussInt};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A closed interval `[lo, hi]` of real numbers with integer endpoints.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::interval::Interval;
+///
+/// let x = Interval::new(BigInt::new(-2), BigInt::new(3)).unwrap();
+/// let y = Interval::new(BigInt::new(4), BigInt::new(5)).unwrap();
+/// assert_eq!((&x * &y).to_string(), "[-10, 15]");
+/// assert_eq!(x.square().to_string(), "[0, 9]");
+/// // √[10, 20] ⊆ [3, 5]
+/// let s = Interval::new(BigInt::new(10), BigInt::new(20)).unwrap().sqrt().unwrap();
+/// assert_eq!(s.to_string(), "[3, 5]");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interval {
+    lo: BigInt,
+    hi: BigInt,
+}
+
+impl Interval {
+    /// Creates `[lo, hi]`, or returns `None` if `lo > hi`.
+    pub fn new(lo: BigInt, hi: BigInt) -> Option<Self> {
+        if lo > hi {
+            return None;
+        }
+        Some(Interval { lo, hi })
+    }
+
+    /// The degenerate interval `[x, x]`.
+    pub fn point(x: BigInt) -> Self {
+        Interval {
+            lo: x.clone(),
+            hi: x,
+        }
+    }
+
+    /// Returns the lower endpoint.
+    pub fn lo(&self) -> &BigInt {
+        &self.lo
+    }
+
+    /// Returns the upper endpoint.
+    pub fn hi(&self) -> &BigInt {
+        &self.hi
+    }
+
+    /// Returns `hi - lo`.
+    pub fn width(&self) -> BigInt {
+        &self.hi - &self.lo
+    }
+
+    /// Returns `true` if the interval holds a single value.
+    pub fn is_point(&self) -> bool {
+        self.lo == self.hi
+    }
+
+    /// Returns `true` if `lo <= x <= hi`.
+    pub fn contains(&self, x: &BigInt) -> bool {
+        &self.lo <= x && x <= &self.hi
+    }
+
+    /// Returns `true` if zero lies in the interval.
+    pub fn contains_zero(&self) -> bool {
+        !self.lo.is_positive() && !self.hi.is_negative()
+    }
+
+    /// The smallest interval containing both `self` and `other`.
+    pub fn hull(&self, other: &Self) -> Self {
+        Interval {
+            lo: self.lo.clone().min(other.lo.clone()),
+            hi: self.hi.clone().max(other.hi.clone()),
+        }
+    }
+
+    /// The intersection, or `None` if the intervals are disjoint.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        Self::new(
+            self.lo.clone().max(other.lo.clone()),
+            self.hi.clone().min(other.hi.clone()),
+        )
+    }
+
+    /// Encloses `{x² : x ∈ self}`, which is tighter than `self * self` when
+    /// the interval straddles zero.
+    pub fn square(&self) -> Self {
+        let lo_sq = &self.lo * &self.lo;
+        let hi_sq = &self.hi * &self.hi;
+        if self.contains_zero() {
+            Interval {
+                lo: BigInt::new(0),
+                hi: lo_sq.max(hi_sq),
+            }
+        } else {
+            Interval {
+                lo: lo_sq.clone().min(hi_sq.clone()),
+                hi: lo_sq.max(hi_sq),
+            }
+        }
+    }
+
+    /// Encloses `{√x : x ∈ self, x >= 0}` with integer endpoints.
+    ///
+    /// The lower bound rounds down and the upper bound rounds up. Returns
+    /// `None` if the interval is entirely negative.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.hi.is_negative() {
+            return None;
+        }
+        let lo = if self.lo.is_positive() {
+            self.lo.sqrt()?
+        } else {
+            BigInt::new(0)
+        };
+        let (root, rem) = self.hi.sqrt_rem()?;
+        let hi = if rem.is_zero() {
+            root
+        } else {
+            &root + &BigInt::new(1)
+        };
+        Some(Interval { lo, hi })
+    }
+}
+
+impl From<BigInt> for Interval {
+    fn from(x: BigInt) -> Self {
+        Interval::point(x)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl Neg for &Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        Interval {
+            lo: -&self.hi,
+            hi: -&self.lo,
+        }
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        -&self
+    }
+}
+
+impl Add for &Interval {
+    type Output = Interval;
+
+    fn add(self, other: &Interval) -> Interval {
+        Interval {
+            lo: &self.lo + &other.lo,
+            hi: &self.hi + &other.hi,
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    fn add(self, other: Interval) -> Interval {
+        &self + &other
+    }
+}
+
+impl Sub for &Interval {
+    type Output = Interval;
+
+    fn sub(self, other: &Interval) -> Interval {
+        Interval {
+            lo: &self.lo - &other.hi,
+            hi: &self.hi - &other.lo,
+        }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    fn sub(self, other: Interval) -> Interval {
+        &self - &other
+    }
+}
+
+impl Mul for &Interval {
+    type Output = Interval;
+
+    fn mul(self, other: &Interval) -> Interval {
+        let products = [
+            &self.lo * &other.lo,
+            &self.lo * &other.hi,
+            &self.hi * &other.lo,
+            &self.hi * &other.hi,
+        ];
+        let lo = products.iter().min().expect("four products").clone();
+        let hi = products.iter().max().expect("four products").clone();
+        Interval { lo, hi }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+
+    fn mul(self, other: Interval) -> Interval {
+        &self * &other
+    }
+}
+
+/// A rectangle `real + imag·i` in the complex plane, with interval sides.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::interval::ComplexInterval;
+///
+/// let z = ComplexInterval::from(GaussInt::from_i64(3, 4));
+/// assert_eq!(z.abs().to_string(), "[5, 5]");
+/// let w = ComplexInterval::from(GaussInt::from_i64(1, 1));
+/// // |1 + i| = √2 ∈ [1, 2]
+/// assert_eq!(w.abs().to_string(), "[1, 2]");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplexInterval {
+    real: Interval,
+    imag: Interval,
+}
+
+impl ComplexInterval {
+    /// Creates the rectangle `real × imag`.
+    pub fn new(real: Interval, imag: Interval) -> Self {
+        ComplexInterval { real, imag }
+    }
+
+    /// Returns the interval of real parts.
+    pub fn real(&self) -> &Interval {
+        &self.real
+    }
+
+    /// Returns the interval of imaginary parts.
+    pub fn imag(&self) -> &Interval {
+        &self.imag
+    }
+
+    /// Returns `true` if `z` lies in the rectangle.
+    pub fn contains(&self, z: &GaussInt) -> bool {
+        self.real.contains(z.real()) && self.imag.contains(z.imag())
+    }
+
+    /// Encloses the norm `a² + b²` over the rectangle.
+    pub fn norm(&self) -> Interval {
+        &self.real.square() + &self.imag.square()
+    }
+
+    /// Encloses the magnitude `√(a² + b²)` over the rectangle.
+    pub fn abs(&self) -> Interval {
+        self.norm().sqrt().expect("norm is non-negative")
+    }
+}
+
+impl From<GaussInt> for ComplexInterval {
+    fn from(z: GaussInt) -> Self {
+        ComplexInterval {
+            real: Interval::point(z.real().clone()),
+            imag: Interval::point(z.imag().clone()),
+        }
+    }
+}
+
+impl fmt::Display for ComplexInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}i", self.real, self.imag)
+    }
+}
+
+impl Add for &ComplexInterval {
+    type Output = ComplexInterval;
+
+    fn add(self, other: &ComplexInterval) -> ComplexInterval {
+        ComplexInterval {
+            real: &self.real + &other.real,
+            imag: &self.imag + &other.imag,
+        }
+    }
+}
+
+impl Sub for &ComplexInterval {
+    type Output = ComplexInterval;
+
+    fn sub(self, other: &ComplexInterval) -> ComplexInterval {
+        ComplexInterval {
+            real: &self.real - &other.real,
+            imag: &self.imag - &other.imag,
+        }
+    }
+}
+
+impl Mul for &ComplexInterval {
+    type Output = ComplexInterval;
+
+    fn mul(self, other: &ComplexInterval) -> ComplexInterval {
+        ComplexInterval {
+            real: &(&self.real * &other.real) - &(&self.imag * &other.imag),
+            imag: &(&self.real * &other.imag) + &(&self.imag * &other.real),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iv(lo: i64, hi: i64) -> Interval {
+        Interval::new(BigInt::new(lo), BigInt::new(hi)).unwrap()
+    }
+
+    #[test]
+    fn test_interval_construction() {
+        assert!(Interval::new(BigInt::new(3), BigInt::new(2)).is_none());
+        let x = iv(-1, 4);
+        assert_eq!(x.width(), BigInt::new(5));
+        assert!(x.contains(&BigInt::new(0)));
+        assert!(!x.contains(&BigInt::new(5)));
+        assert!(Interval::point(BigInt::new(7)).is_point());
+        assert_eq!(x.hull(&iv(6, 8)), iv(-1, 8));
+        assert_eq!(x.intersect(&iv(2, 8)), Some(iv(2, 4)));
+        assert_eq!(x.intersect(&iv(5, 8)), None);
+    }
+
+    #[test]
+    fn test_interval_arithmetic_encloses_samples() {
+        let bounds = [(-3, 2), (1, 4), (-5, -2), (0, 0)];
+        for &(x_lo, x_hi) in &bounds {
+            for &(y_lo, y_hi) in &bounds {
+                let (x, y) = (iv(x_lo, x_hi), iv(y_lo, y_hi));
+                let (sum, diff, prod) = (&x + &y, &x - &y, &x * &y);
+                for a in x_lo..=x_hi {
+                    for b in y_lo..=y_hi {
+                        assert!(sum.contains(&BigInt::new(a + b)));
+                        assert!(diff.contains(&BigInt::new(a - b)));
+                        assert!(prod.contains(&BigInt::new(a * b)));
+                    }
+                }
+            }
+        }
+        assert_eq!(&iv(-3, 2) * &iv(-5, -2), iv(-10, 15));
+        assert_eq!(-iv(-3, 2), iv(-2, 3));
+    }
+
+    #[test]
+    fn test_interval_square_and_sqrt() {
+        assert_eq!(iv(-3, 2).square(), iv(0, 9));
+        assert_eq!(iv(-5, -2).square(), iv(4, 25));
+        assert_eq!(iv(16, 25).sqrt(), Some(iv(4, 5)));
+        assert_eq!(iv(17, 24).sqrt(), Some(iv(4, 5)));
+        assert_eq!(iv(-4, 9).sqrt(), Some(iv(0, 3)));
+        assert_eq!(iv(-4, -1).sqrt(), None);
+    }
+
+    #[test]
+    fn test_complex_interval() {
+        let z = ComplexInterval::new(iv(1, 2), iv(-1, 1));
+        assert!(z.contains(&GaussInt::from_i64(2, -1)));
+        assert!(!z.contains(&GaussInt::from_i64(0, 0)));
+        assert_eq!(z.norm(), iv(1, 5));
+        assert_eq!(z.abs(), iv(1, 3));
+        let w = ComplexInterval::from(GaussInt::from_i64(0, 1));
+        // Multiplying by i rotates the rectangle
+        assert_eq!(&z * &w, ComplexInterval::new(iv(-1, 1), iv(1, 2)));
+        let sum = &z + &w;
+        assert_eq!(sum, ComplexInterval::new(iv(1, 2), iv(0, 2)));
+        assert_eq!(&sum - &w, z);
+    }
+}
@@ -0,0 +1,229 @@
+//! Rank-2 lattices in Z[i].
+//!
+//! Two linearly independent Gaussian integers `b1`, `b2` span the lattice
+//! `{ m·b1 + n·b2 : m, n ∈ ℤ }`, treating each Gaussian integer as an exact
+//! 2-D vector. Lagrange (Gauss) reduction turns any basis into one of
+//! shortest vectors, which keeps coefficients small and makes closest-point
+//! queries a local search.
+
+use crate::gauss_int::round_div;
+use crate::{BigInt, GaussInt};
+
+/// A lattice stored by its Lagrange-reduced basis.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::lattice::Lattice;
+///
+/// let lattice = Lattice::new(
+///     GaussInt::from_i64(1, 0),
+///     GaussInt::from_i64(100, 1),
+/// ).unwrap();
+/// let (b1, b2) = lattice.basis();
+/// assert_eq!(b1.norm().to_string(), "1");
+/// assert_eq!(b2.norm().to_string(), "1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lattice {
+    b1: GaussInt,
+    b2: GaussInt,
+}
+
+impl Lattice {
+    /// Builds the lattice spanned by `b1` and `b2`, reducing the basis.
+    ///
+    /// Returns `None` if the vectors are linearly dependent over ℝ.
+    pub fn new(b1: GaussInt, b2: GaussInt) -> Option<Self> {
+        let (b1, b2) = reduce_basis(&b1, &b2)?;
+        Some(Lattice { b1, b2 })
+    }
+
+    /// Returns the reduced basis `(b1, b2)` with `N(b1) <= N(b2)`.
+    pub fn basis(&self) -> (&GaussInt, &GaussInt) {
+        (&self.b1, &self.b2)
+    }
+
+    /// Returns the lattice point nearest to `target` in Euclidean distance.
+    ///
+    /// Rounds the exact coordinates of `target` in the reduced basis and
+    /// searches the neighbouring cells; for a reduced basis the closest point
+    /// is always among them. Ties go to the first candidate found.
+    pub fn closest_lattice_point(&self, target: &GaussInt) -> GaussInt {
+        let det = self.b1.cross(&self.b2);
+        let alpha = round_div(&target.cross(&self.b2), &det);
+        let beta = round_div(&self.b1.cross(target), &det);
+
+        let mut best: Option<(BigInt, GaussInt)> = None;
+        for da in -1..=1 {
+            for db in -1..=1 {
+                let m = &alpha + &BigInt::new(da);
+                let n = &beta + &BigInt::new(db);
+                let point = &scale(&self.b1, &m) + &scale(&self.b2, &n);
+                let dist = (target - &point).norm();
+                if best.as_ref().is_none_or(|(d, _)| dist < *d) {
+                    best = Some((dist, point));
+                }
+            }
+        }
+        best.expect("at least one candidate").1
+    }
+}
+
+impl GaussInt {
+    /// Reduces this point modulo the lattice spanned by `b1` and `b2`,
+    /// returning the congruent point `s·b1 + t·b2` with `s, t ∈ [0, 1)`,
+    /// the fundamental parallelogram of the basis as given.
+    ///
+    /// This is the periodic wrap-around of a torus whose sides are `b1` and
+    /// `b2`; for `b2 = i·b1` it is [`reduce_mod`](Self::reduce_mod) with a
+    /// different choice of representatives. Returns `None` if `b1` and `b2`
+    /// are linearly dependent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::GaussInt;
+    ///
+    /// // A 10 × 4 torus
+    /// let (b1, b2) = (GaussInt::from_i64(10, 0), GaussInt::from_i64(0, 4));
+    /// let z = GaussInt::from_i64(-3, 9);
+    /// assert_eq!(z.reduce_mod_lattice(&b1, &b2), Some(GaussInt::from_i64(7, 1)));
+    /// ```
+    pub fn reduce_mod_lattice(&self, b1: &GaussInt, b2: &GaussInt) -> Option<GaussInt> {
+        let det = b1.cross(b2);
+        if det.is_zero() {
+            return None;
+        }
+        // Cramer's rule gives the real coordinates in the basis
+        let m = self.cross(b2).div_floor(&det);
+        let n = b1.cross(self).div_floor(&det);
+        Some(&(self - &scale(b1, &m)) - &scale(b2, &n))
+    }
+}
+
+/// Lagrange-reduces the basis `(b1, b2)`.
+///
+/// Returns `(u, v)` spanning the same lattice with `N(u) <= N(v)` and
+/// `|⟨u, v⟩| <= N(u) / 2`, so `u` is a shortest non-zero lattice vector.
+/// Returns `None` if the vectors are linearly dependent.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::GaussInt;
+/// use gauss_int::lattice::reduce_basis;
+///
+/// let (u, v) = reduce_basis(&GaussInt::from_i64(5, 8), &GaussInt::from_i64(8, 13)).unwrap();
+/// assert_eq!(u.norm().to_string(), "1");
+/// assert_eq!(v.norm().to_string(), "1");
+/// ```
+pub fn reduce_basis(b1: &GaussInt, b2: &GaussInt) -> Option<(GaussInt, GaussInt)> {
+    if b1.cross(b2).is_zero() {
+        return None;
+    }
+    let (mut u, mut v) = if b1.norm() <= b2.norm() {
+        (b1.clone(), b2.clone())
+    } else {
+        (b2.clone(), b1.clone())
+    };
+    loop {
+        let mu = round_div(&u.dot(&v), &u.norm());
+        v = &v - &scale(&u, &mu);
+        if v.norm() >= u.norm() {
+            return Some((u, v));
+        }
+        std::mem::swap(&mut u, &mut v);
+    }
+}
+
+fn scale(z: &GaussInt, k: &BigInt) -> GaussInt {
+    GaussInt::new(z.real() * k, z.imag() * k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduce_basis_dependent() {
+        let a = GaussInt::from_i64(2, 3);
+        let b = GaussInt::from_i64(-4, -6);
+        assert!(reduce_basis(&a, &b).is_none());
+        assert!(Lattice::new(a, GaussInt::from_i64(0, 0)).is_none());
+    }
+
+    #[test]
+    fn test_reduce_basis_preserves_lattice() {
+        let b1 = GaussInt::from_i64(1001, 3);
+        let b2 = GaussInt::from_i64(2000, 7);
+        let (u, v) = reduce_basis(&b1, &b2).unwrap();
+
+        // Same covolume
+        assert_eq!(u.cross(&v).abs(), b1.cross(&b2).abs());
+        // Reduced: shortest first, and nearly orthogonal
+        assert!(u.norm() <= v.norm());
+        assert!(&u.dot(&v).abs() * &BigInt::new(2) <= u.norm());
+        // Both reduced vectors are integer combinations of the original basis
+        let det = b1.cross(&b2);
+        for w in [&u, &v] {
+            assert!((&w.cross(&b2) % &det).is_zero());
+            assert!((&b1.cross(w) % &det).is_zero());
+        }
+    }
+
+    #[test]
+    fn test_closest_lattice_point_matches_brute_force() {
+        let lattice = Lattice::new(GaussInt::from_i64(7, 2), GaussInt::from_i64(-3, 5)).unwrap();
+        let (b1, b2) = lattice.basis();
+        for (x, y) in [(0, 0), (13, -4), (-22, 17), (100, 101), (3, 3), (-50, -7)] {
+            let target = GaussInt::from_i64(x, y);
+            let found = lattice.closest_lattice_point(&target);
+            let best = (-40..=40)
+                .flat_map(|m| (-40..=40).map(move |n| (m, n)))
+                .map(|(m, n)| &scale(b1, &BigInt::new(m)) + &scale(b2, &BigInt::new(n)))
+                .map(|p| (&target - &p).norm())
+                .min()
+                .unwrap();
+            assert_eq!((&target - &found).norm(), best, "target {}", target);
+        }
+    }
+
+    #[test]
+    fn test_closest_lattice_point_on_lattice() {
+        let lattice = Lattice::new(GaussInt::from_i64(4, 1), GaussInt::from_i64(1, 4)).unwrap();
+        let point = GaussInt::from_i64(4 * 3 - 2, 3 - 2 * 4);
+        assert_eq!(lattice.closest_lattice_point(&point), point);
+    }
+
+    #[test]
+    fn test_reduce_mod_lattice_lands_in_parallelogram() {
+        let bases = [
+            (GaussInt::from_i64(7, 2), GaussInt::from_i64(-3, 5)),
+            (GaussInt::from_i64(-3, 5), GaussInt::from_i64(7, 2)),
+            (GaussInt::from_i64(1001, 3), GaussInt::from_i64(2000, 7)),
+        ];
+        for (b1, b2) in &bases {
+            let det = b1.cross(b2);
+            for x in -12..=12 {
+                for y in -12..=12 {
+                    let z = GaussInt::from_i64(x * 37, y * 41);
+                    let r = z.reduce_mod_lattice(b1, b2).unwrap();
+                    // Coordinates s = cross(r, b2)/det and t = cross(b1, r)/det in [0, 1)
+                    for coord in [r.cross(b2), b1.cross(&r)] {
+                        let scaled = if det.is_negative() { -coord } else { coord };
+                        assert!(!scaled.is_negative() && scaled < det.abs());
+                    }
+                    // z − r is a lattice vector
+                    let d = &z - &r;
+                    assert!((&d.cross(b2) % &det).is_zero());
+                    assert!((&b1.cross(&d) % &det).is_zero());
+                    assert_eq!(d.reduce_mod_lattice(b1, b2), Some(GaussInt::ZERO));
+                }
+            }
+        }
+        let a = GaussInt::from_i64(2, 3);
+        assert_eq!(GaussInt::ONE.reduce_mod_lattice(&a, &(&a + &a)), None);
+    }
+}
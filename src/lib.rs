@@ -1,9 +1,45 @@
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+pub mod batch;
 pub mod big_int;
+pub mod big_nat;
+pub mod cached;
+pub mod combinatorics;
+pub mod continued_fraction;
+pub mod curve;
+pub mod error;
+pub mod expr;
+pub mod fft;
+mod fixed_point;
+pub mod format;
+pub mod fp2;
+pub mod fractal;
 pub mod gauss_int;
+pub mod gauss_residue;
+pub mod geometry;
+pub mod interval;
+pub mod lattice;
+pub mod matrix;
+pub mod mod_int;
+mod ntt;
 pub mod number_theory;
+pub mod polynomial;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod quaternion;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod rational;
 
-pub use big_int::BigInt;
-pub use gauss_int::GaussInt;
+pub use big_int::{BigInt, Exactness, RoundingMode};
+pub use big_nat::BigNat;
+pub use error::{Error, ParseBigIntError, ParseGaussIntError};
+pub use expr::evaluate;
+pub use gauss_int::{GaussInt, RootsOfUnity};
 pub use number_theory::{
-    crt, euler_totient, factorize, is_gaussian_prime, is_prime, jacobi_symbol,
+    binomial_mod, carmichael_lambda, crt, euler_totient, factorial_mod, factorize, four_squares,
+    is_gaussian_prime, is_prime, jacobi_symbol, next_prime, prev_prime, sqrt_mod_composite,
+    sqrt_mod_prime,
 };
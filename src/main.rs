@@ -1,6 +1,8 @@
 use clap::{Parser, Subcommand};
 use gauss_int::{BigInt, GaussInt};
 
+mod repl;
+
 #[derive(Parser)]
 #[command(name = "gauss", about = "Gaussian integer and number theory CLI")]
 struct Cli {
@@ -35,6 +37,8 @@ enum Commands {
     Jacobi { a: String, n: String },
     /// Chinese Remainder Theorem
     Crt { pairs: Vec<String> },
+    /// Interactive calculator over big and Gaussian integers
+    Repl,
 }
 
 /// Parse a Gaussian integer string like "3+4i", "5", "-2i", "i", "-i"
@@ -223,5 +227,11 @@ fn main() {
                 None => println!("no solution"),
             }
         }
+        Commands::Repl => {
+            if let Err(e) = repl::run() {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
     }
 }
@@ -0,0 +1,466 @@
+//! Dense matrices over exact rings.
+//!
+//! `Matrix<T>` works over any [`MatrixEntry`] ring; the crate provides
+//! entries for [`BigInt`] and [`GaussInt`]. All arithmetic is exact: the
+//! determinant uses fraction-free Bareiss elimination, whose intermediate
+//! divisions are always exact, so no rationals are needed.
+
+use crate::{BigInt, GaussInt};
+use num_traits::{One, Zero};
+use std::fmt;
+use std::ops::{Add, Index, Mul, Neg, Sub};
+
+/// A commutative ring whose elements can be matrix entries.
+pub trait MatrixEntry:
+    Clone + PartialEq + Zero + One + Sub<Output = Self> + Neg<Output = Self>
+{
+    /// The ring's conjugation; the identity for real rings.
+    fn conj(&self) -> Self;
+
+    /// Divides by `divisor`, which is known to divide `self` exactly.
+    fn exact_div(&self, divisor: &Self) -> Self;
+}
+
+impl MatrixEntry for BigInt {
+    fn conj(&self) -> Self {
+        self.clone()
+    }
+
+    fn exact_div(&self, divisor: &Self) -> Self {
+        self / divisor
+    }
+}
+
+impl MatrixEntry for GaussInt {
+    fn conj(&self) -> Self {
+        self.conjugate()
+    }
+
+    fn exact_div(&self, divisor: &Self) -> Self {
+        self / divisor
+    }
+}
+
+/// A `rows × cols` matrix stored in row-major order.
+///
+/// Binary operators panic if the dimensions are incompatible.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::matrix::Matrix;
+///
+/// let m = Matrix::from_rows(vec![
+///     vec![BigInt::new(2), BigInt::new(-1), BigInt::new(0)],
+///     vec![BigInt::new(1), BigInt::new(3), BigInt::new(4)],
+///     vec![BigInt::new(0), BigInt::new(5), BigInt::new(-2)],
+/// ]).unwrap();
+/// assert_eq!(m.determinant().unwrap(), BigInt::new(-54));
+/// assert_eq!(&m * &Matrix::identity(3), m);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: MatrixEntry> Matrix<T> {
+    /// Creates a matrix from row-major `data`.
+    ///
+    /// Returns `None` if `data` does not have `rows * cols` entries.
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Option<Self> {
+        if rows.checked_mul(cols)? != data.len() {
+            return None;
+        }
+        Some(Matrix { rows, cols, data })
+    }
+
+    /// Creates a matrix from a list of rows.
+    ///
+    /// Returns `None` if the rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+        let n = rows.len();
+        Self::new(n, cols, rows.into_iter().flatten().collect())
+    }
+
+    /// The `rows × cols` zero matrix.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix {
+            rows,
+            cols,
+            data: vec![T::zero(); rows * cols],
+        }
+    }
+
+    /// The `n × n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m.data[i * n + i] = T::one();
+        }
+        m
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns `true` if there are as many rows as columns.
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns the entry at `(row, col)`, or `None` if out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.data.get(row * self.cols + col)
+    }
+
+    /// Returns the transpose, swapping rows and columns.
+    pub fn transpose(&self) -> Self {
+        self.map_transposed(T::clone)
+    }
+
+    /// Returns the conjugate (Hermitian) transpose.
+    pub fn conjugate_transpose(&self) -> Self {
+        self.map_transposed(T::conj)
+    }
+
+    /// Computes the determinant by fraction-free Bareiss elimination.
+    ///
+    /// Returns `None` if the matrix is not square.
+    pub fn determinant(&self) -> Option<T> {
+        if !self.is_square() {
+            return None;
+        }
+        let n = self.rows;
+        let mut m = self.data.clone();
+        let mut negate = false;
+        let mut prev = T::one();
+        for k in 0..n.saturating_sub(1) {
+            if m[k * n + k].is_zero() {
+                let Some(pivot) = (k + 1..n).find(|&i| !m[i * n + k].is_zero()) else {
+                    return Some(T::zero());
+                };
+                for j in 0..n {
+                    m.swap(k * n + j, pivot * n + j);
+                }
+                negate = !negate;
+            }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let value = m[i * n + j].clone() * m[k * n + k].clone()
+                        - m[i * n + k].clone() * m[k * n + j].clone();
+                    m[i * n + j] = value.exact_div(&prev);
+                }
+            }
+            prev = m[k * n + k].clone();
+        }
+        let det = match n {
+            0 => T::one(),
+            _ => m[n * n - 1].clone(),
+        };
+        Some(if negate { -det } else { det })
+    }
+
+    /// Raises a square matrix to the power `exp` by repeated squaring.
+    ///
+    /// Returns `None` if the matrix is not square.
+    pub fn pow(&self, exp: u32) -> Option<Self> {
+        if !self.is_square() {
+            return None;
+        }
+        let mut result = Self::identity(self.rows);
+        let mut base = self.clone();
+        let mut e = exp;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = &result * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        Some(result)
+    }
+
+    fn map_transposed(&self, f: impl Fn(&T) -> T) -> Self {
+        let data = (0..self.cols)
+            .flat_map(|j| (0..self.rows).map(move |i| (i, j)))
+            .map(|(i, j)| f(&self.data[i * self.cols + j]))
+            .collect();
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+
+    fn check_same_shape(&self, other: &Self) {
+        assert!(
+            self.rows == other.rows && self.cols == other.cols,
+            "dimension mismatch: {}x{} vs {}x{}",
+            self.rows,
+            self.cols,
+            other.rows,
+            other.cols
+        );
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    /// Formats one bracketed row per line, e.g. `[1, 2]\n[3, 4]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.data.chunks(self.cols.max(1)).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for (j, entry) in row.iter().enumerate() {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", entry)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: MatrixEntry> Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, other: &Matrix<T>) -> Matrix<T> {
+        self.check_same_shape(other);
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(a, b)| a.clone() + b.clone())
+                .collect(),
+        }
+    }
+}
+
+impl<T: MatrixEntry> Sub for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other: &Matrix<T>) -> Matrix<T> {
+        self.check_same_shape(other);
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(a, b)| a.clone() - b.clone())
+                .collect(),
+        }
+    }
+}
+
+impl<T: MatrixEntry> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        assert!(
+            self.cols == other.rows,
+            "dimension mismatch: {}x{} times {}x{}",
+            self.rows,
+            self.cols,
+            other.rows,
+            other.cols
+        );
+        let mut data = Vec::with_capacity(self.rows * other.cols);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                let mut sum = T::zero();
+                for k in 0..self.cols {
+                    sum = sum
+                        + self.data[i * self.cols + k].clone()
+                            * other.data[k * other.cols + j].clone();
+                }
+                data.push(sum);
+            }
+        }
+        Matrix {
+            rows: self.rows,
+            cols: other.cols,
+            data,
+        }
+    }
+}
+
+impl<T: MatrixEntry> Add for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, other: Matrix<T>) -> Matrix<T> {
+        &self + &other
+    }
+}
+
+impl<T: MatrixEntry> Sub for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other: Matrix<T>) -> Matrix<T> {
+        &self - &other
+    }
+}
+
+impl<T: MatrixEntry> Mul for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: Matrix<T>) -> Matrix<T> {
+        &self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_matrix(rows: &[&[i64]]) -> Matrix<BigInt> {
+        Matrix::from_rows(
+            rows.iter()
+                .map(|row| row.iter().map(|&x| BigInt::new(x)).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    /// Determinant by Laplace expansion along the first row.
+    fn laplace(m: &Matrix<BigInt>) -> BigInt {
+        let n = m.rows();
+        if n == 1 {
+            return m[(0, 0)].clone();
+        }
+        let mut det = BigInt::zero();
+        for c in 0..n {
+            let minor: Vec<Vec<BigInt>> = (1..n)
+                .map(|i| {
+                    (0..n)
+                        .filter(|&j| j != c)
+                        .map(|j| m[(i, j)].clone())
+                        .collect()
+                })
+                .collect();
+            let term = &m[(0, c)] * &laplace(&Matrix::from_rows(minor).unwrap());
+            det = if c % 2 == 0 {
+                &det + &term
+            } else {
+                &det - &term
+            };
+        }
+        det
+    }
+
+    #[test]
+    fn test_matrix_construction() {
+        assert!(Matrix::new(2, 2, vec![BigInt::one(); 3]).is_none());
+        assert!(Matrix::from_rows(vec![vec![BigInt::one()], vec![]]).is_none());
+        let m = int_matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m.get(1, 2), Some(&BigInt::new(6)));
+        assert_eq!(m.get(2, 0), None);
+        assert_eq!(m.to_string(), "[1, 2, 3]\n[4, 5, 6]");
+    }
+
+    #[test]
+    fn test_matrix_arithmetic() {
+        let a = int_matrix(&[&[1, 2], &[3, 4]]);
+        let b = int_matrix(&[&[0, 1], &[1, 0]]);
+        assert_eq!(&a + &b, int_matrix(&[&[1, 3], &[4, 4]]));
+        assert_eq!(&a - &b, int_matrix(&[&[1, 1], &[2, 4]]));
+        assert_eq!(&a * &b, int_matrix(&[&[2, 1], &[4, 3]]));
+        let c = int_matrix(&[&[1, 0, 2], &[0, 1, 1]]);
+        assert_eq!(&a * &c, int_matrix(&[&[1, 2, 4], &[3, 4, 10]]));
+        assert_eq!(c.transpose(), int_matrix(&[&[1, 0], &[0, 1], &[2, 1]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn test_matrix_mul_dimension_mismatch() {
+        let a = int_matrix(&[&[1, 2, 3]]);
+        let _ = &a * &a;
+    }
+
+    #[test]
+    fn test_determinant_matches_laplace() {
+        let m = int_matrix(&[
+            &[3, -1, 4, 1, 5],
+            &[9, 2, -6, 5, 3],
+            &[5, 8, 9, -7, 9],
+            &[3, 2, 3, 8, -4],
+            &[6, -2, 6, 4, 3],
+        ]);
+        assert_eq!(m.determinant().unwrap(), laplace(&m));
+        // Needs a pivot swap: the leading entry is zero
+        let m = int_matrix(&[&[0, 2, 1], &[3, 0, 4], &[1, 5, 0]]);
+        assert_eq!(m.determinant().unwrap(), laplace(&m));
+        // Singular
+        let m = int_matrix(&[&[1, 2, 3], &[2, 4, 6], &[0, 1, 7]]);
+        assert_eq!(m.determinant().unwrap(), BigInt::zero());
+        assert_eq!(
+            Matrix::<BigInt>::identity(0).determinant().unwrap(),
+            BigInt::one()
+        );
+        assert!(int_matrix(&[&[1, 2]]).determinant().is_none());
+    }
+
+    #[test]
+    fn test_gaussian_matrix() {
+        let z = GaussInt::from_i64;
+        let m = Matrix::from_rows(vec![vec![z(1, 1), z(2, 0)], vec![z(0, -3), z(4, 5)]]).unwrap();
+        // (1+i)(4+5i) - 2(-3i) = -1 + 9i + 6i
+        assert_eq!(m.determinant().unwrap(), z(-1, 15));
+        let h = m.conjugate_transpose();
+        assert_eq!(h[(0, 1)], z(0, 3));
+        assert_eq!(h[(1, 1)], z(4, -5));
+        // det(Mᴴ) = conj(det M)
+        assert_eq!(h.determinant().unwrap(), z(-1, -15));
+        // M·Mᴴ is Hermitian
+        let mh = &m * &h;
+        assert_eq!(mh.conjugate_transpose(), mh);
+    }
+
+    #[test]
+    fn test_matrix_pow_fibonacci() {
+        let fib = int_matrix(&[&[1, 1], &[1, 0]]);
+        let f = fib.pow(90).unwrap();
+        assert_eq!(f[(0, 1)].to_string(), "2880067194370816120");
+        assert_eq!(fib.pow(0).unwrap(), Matrix::identity(2));
+        assert!(int_matrix(&[&[1, 2]]).pow(2).is_none());
+    }
+}
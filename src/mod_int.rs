@@ -0,0 +1,319 @@
+//! Residues modulo a BigInt, carrying their modulus.
+//!
+//! `ModInt` keeps its value reduced into `0..modulus`, so chains of modular
+//! operations never need the modulus threaded through by hand.
+
+use crate::BigInt;
+use num_traits::One;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// An element of ℤ/mℤ: a value in `0..m` together with its modulus `m`.
+///
+/// Binary operators panic if the operands have different moduli.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::mod_int::ModInt;
+///
+/// let m = BigInt::new(11);
+/// let a = ModInt::new(BigInt::new(7), m.clone()).unwrap();
+/// let b = ModInt::new(BigInt::new(-3), m).unwrap();
+/// assert_eq!(b.value().to_string(), "8");
+/// assert_eq!((&a * &b).value().to_string(), "1"); // 56 mod 11
+/// assert_eq!(a.inv().unwrap(), b);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModInt {
+    value: BigInt,
+    modulus: BigInt,
+}
+
+impl ModInt {
+    /// Creates the residue of `value` modulo `modulus`.
+    ///
+    /// Returns `None` if `modulus` is not positive.
+    pub fn new(value: BigInt, modulus: BigInt) -> Option<Self> {
+        if !modulus.is_positive() {
+            return None;
+        }
+        Some(ModInt {
+            value: value.rem_euclid(&modulus),
+            modulus,
+        })
+    }
+
+    /// Returns the canonical representative in `0..modulus`.
+    pub fn value(&self) -> &BigInt {
+        &self.value
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    /// Returns `true` for the zero residue class.
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Returns the multiplicative inverse, or `None` if the value is not
+    /// coprime to the modulus.
+    pub fn inv(&self) -> Option<Self> {
+        if self.modulus.is_one() {
+            return Some(self.clone());
+        }
+        let inverse = self.value.mod_inv(&self.modulus)?;
+        Some(self.with_value(inverse))
+    }
+
+    /// Raises to the power `exp`.
+    ///
+    /// Negative exponents invert first; returns `None` if that inverse does
+    /// not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::BigInt;
+    /// use gauss_int::mod_int::ModInt;
+    ///
+    /// let a = ModInt::new(BigInt::new(3), BigInt::new(7)).unwrap();
+    /// assert_eq!(a.pow(&BigInt::new(6)).unwrap().value().to_string(), "1");
+    /// assert_eq!(a.pow(&BigInt::new(-1)).unwrap().value().to_string(), "5");
+    /// ```
+    pub fn pow(&self, exp: &BigInt) -> Option<Self> {
+        if exp.is_negative() {
+            return self.inv()?.pow(&-exp);
+        }
+        Some(self.with_value(self.value.mod_pow(exp, &self.modulus)))
+    }
+
+    /// Builds a residue with the same modulus from an already reduced value.
+    fn with_value(&self, value: BigInt) -> Self {
+        ModInt {
+            value,
+            modulus: self.modulus.clone(),
+        }
+    }
+
+    /// Reduces a value into range and attaches this modulus.
+    fn reduced(&self, value: BigInt) -> Self {
+        self.with_value(value.rem_euclid(&self.modulus))
+    }
+
+    fn check_modulus(&self, other: &Self) {
+        assert!(
+            self.modulus == other.modulus,
+            "modulus mismatch: {} vs {}",
+            self.modulus,
+            other.modulus
+        );
+    }
+}
+
+impl fmt::Display for ModInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.modulus)
+    }
+}
+
+// --- Neg ---
+
+impl Neg for &ModInt {
+    type Output = ModInt;
+
+    fn neg(self) -> ModInt {
+        self.reduced(-&self.value)
+    }
+}
+
+impl Neg for ModInt {
+    type Output = ModInt;
+
+    fn neg(self) -> ModInt {
+        -&self
+    }
+}
+
+// --- Add ---
+
+impl Add for &ModInt {
+    type Output = ModInt;
+
+    fn add(self, other: &ModInt) -> ModInt {
+        self.check_modulus(other);
+        let mut sum = &self.value + &other.value;
+        if sum >= self.modulus {
+            sum = sum - self.modulus.clone();
+        }
+        self.with_value(sum)
+    }
+}
+
+impl Add for ModInt {
+    type Output = ModInt;
+
+    fn add(self, other: ModInt) -> ModInt {
+        &self + &other
+    }
+}
+
+impl Add<&ModInt> for ModInt {
+    type Output = ModInt;
+
+    fn add(self, other: &ModInt) -> ModInt {
+        &self + other
+    }
+}
+
+impl Add<ModInt> for &ModInt {
+    type Output = ModInt;
+
+    fn add(self, other: ModInt) -> ModInt {
+        self + &other
+    }
+}
+
+// --- Sub ---
+
+impl Sub for &ModInt {
+    type Output = ModInt;
+
+    fn sub(self, other: &ModInt) -> ModInt {
+        self.check_modulus(other);
+        let mut diff = &self.value - &other.value;
+        if diff.is_negative() {
+            diff = diff + self.modulus.clone();
+        }
+        self.with_value(diff)
+    }
+}
+
+impl Sub for ModInt {
+    type Output = ModInt;
+
+    fn sub(self, other: ModInt) -> ModInt {
+        &self - &other
+    }
+}
+
+impl Sub<&ModInt> for ModInt {
+    type Output = ModInt;
+
+    fn sub(self, other: &ModInt) -> ModInt {
+        &self - other
+    }
+}
+
+impl Sub<ModInt> for &ModInt {
+    type Output = ModInt;
+
+    fn sub(self, other: ModInt) -> ModInt {
+        self - &other
+    }
+}
+
+// --- Mul ---
+
+impl Mul for &ModInt {
+    type Output = ModInt;
+
+    fn mul(self, other: &ModInt) -> ModInt {
+        self.check_modulus(other);
+        self.with_value(&(&self.value * &other.value) % &self.modulus)
+    }
+}
+
+impl Mul for ModInt {
+    type Output = ModInt;
+
+    fn mul(self, other: ModInt) -> ModInt {
+        &self * &other
+    }
+}
+
+impl Mul<&ModInt> for ModInt {
+    type Output = ModInt;
+
+    fn mul(self, other: &ModInt) -> ModInt {
+        &self * other
+    }
+}
+
+impl Mul<ModInt> for &ModInt {
+    type Output = ModInt;
+
+    fn mul(self, other: ModInt) -> ModInt {
+        self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::Zero;
+
+    fn m(value: i64, modulus: i64) -> ModInt {
+        ModInt::new(BigInt::new(value), BigInt::new(modulus)).unwrap()
+    }
+
+    #[test]
+    fn test_mod_int_creation_reduces() {
+        assert_eq!(*m(23, 7).value(), BigInt::new(2));
+        assert_eq!(*m(-1, 7).value(), BigInt::new(6));
+        assert_eq!(*m(0, 1).value(), BigInt::new(0));
+        assert!(ModInt::new(BigInt::new(1), BigInt::new(0)).is_none());
+        assert!(ModInt::new(BigInt::new(1), BigInt::new(-5)).is_none());
+    }
+
+    #[test]
+    fn test_mod_int_arithmetic() {
+        let a = m(5, 13);
+        let b = m(11, 13);
+        assert_eq!(&a + &b, m(3, 13));
+        assert_eq!(&a - &b, m(7, 13));
+        assert_eq!(&a * &b, m(3, 13));
+        assert_eq!(-&a, m(8, 13));
+        assert_eq!(-m(0, 13), m(0, 13));
+        assert_eq!(a.clone() + b.clone() - b, a);
+    }
+
+    #[test]
+    fn test_mod_int_inverse() {
+        for v in 1..17 {
+            let a = m(v, 17);
+            assert_eq!(&a * &a.inv().unwrap(), m(1, 17));
+        }
+        assert!(m(4, 12).inv().is_none());
+        assert!(m(0, 5).inv().is_none());
+    }
+
+    #[test]
+    fn test_mod_int_pow() {
+        let a = m(2, 1_000_000_007);
+        let p_minus_1 = BigInt::new(1_000_000_006);
+        // Fermat's little theorem
+        assert_eq!(a.pow(&p_minus_1).unwrap(), m(1, 1_000_000_007));
+        assert_eq!(a.pow(&BigInt::zero()).unwrap(), m(1, 1_000_000_007));
+        assert_eq!(
+            &a.pow(&BigInt::new(-5)).unwrap() * &a.pow(&BigInt::new(5)).unwrap(),
+            m(1, 1_000_000_007)
+        );
+        assert!(m(2, 8).pow(&BigInt::new(-1)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus mismatch")]
+    fn test_mod_int_mismatched_moduli() {
+        let _ = m(1, 5) + m(1, 7);
+    }
+
+    #[test]
+    fn test_mod_int_display() {
+        assert_eq!(m(-2, 9).to_string(), "7 (mod 9)");
+    }
+}
@@ -0,0 +1,245 @@
+//! Multi-prime number-theoretic transform for BigInt convolutions.
+//!
+//! Each input coefficient is reduced modulo several NTT-friendly primes
+//! `p = c·2^32 + 1` just above 2^61, the convolution is carried out exactly
+//! in each prime field, and Garner's algorithm recombines the residues into
+//! signed BigInt coefficients. Enough primes are used that their product
+//! exceeds twice the largest possible output coefficient.
+
+use crate::BigInt;
+use std::sync::OnceLock;
+
+/// Two-adicity of every prime in the table: transforms up to length 2^32.
+const TWO_ADICITY: u32 = 32;
+
+/// Size of the prime table, bounding coefficient products to about
+/// `PRIME_COUNT · 61` bits.
+const PRIME_COUNT: usize = 256;
+
+/// An NTT-friendly prime together with a root of unity of order 2^32.
+struct NttPrime {
+    p: u64,
+    root: u64,
+}
+
+fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    ((a as u128 * b as u128) % p as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, p: u64) -> u64 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exp >>= 1;
+    }
+    result
+}
+
+fn inv_mod(a: u64, p: u64) -> u64 {
+    pow_mod(a, p - 2, p)
+}
+
+/// Deterministic Miller-Rabin for 64-bit odd `n`.
+fn is_prime_u64(n: u64) -> bool {
+    let d = (n - 1) >> (n - 1).trailing_zeros();
+    'witness: for a in [2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        let mut x = pow_mod(a % n, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut e = d;
+        while e < n - 1 {
+            x = mul_mod(x, x, n);
+            e <<= 1;
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// The primes `c·2^32 + 1` for descending odd `c < 2^30`, each paired with a
+/// primitive 2^32-th root of unity.
+fn primes() -> &'static [NttPrime] {
+    static PRIMES: OnceLock<Vec<NttPrime>> = OnceLock::new();
+    PRIMES.get_or_init(|| {
+        let mut primes = Vec::with_capacity(PRIME_COUNT);
+        let mut c = (1u64 << 30) - 1;
+        while primes.len() < PRIME_COUNT {
+            let p = (c << TWO_ADICITY) + 1;
+            c -= 2;
+            if !is_prime_u64(p) {
+                continue;
+            }
+            // x^c has order exactly 2^32 iff x is a quadratic non-residue.
+            let x = (2..)
+                .find(|&x| pow_mod(x, (p - 1) / 2, p) == p - 1)
+                .expect("non-residue exists");
+            primes.push(NttPrime {
+                p,
+                root: pow_mod(x, p >> TWO_ADICITY, p),
+            });
+        }
+        primes
+    })
+}
+
+/// In-place iterative radix-2 transform of length `a.len()` (a power of two).
+fn transform(a: &mut [u64], prime: &NttPrime, invert: bool) {
+    let n = a.len();
+    let p = prime.p;
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = pow_mod(prime.root, (1u64 << TWO_ADICITY) / len as u64, p);
+        if invert {
+            w_len = inv_mod(w_len, p);
+        }
+        for start in (0..n).step_by(len) {
+            let mut w = 1;
+            for k in 0..len / 2 {
+                let u = a[start + k];
+                let v = mul_mod(a[start + k + len / 2], w, p);
+                a[start + k] = if u + v >= p { u + v - p } else { u + v };
+                a[start + k + len / 2] = if u >= v { u - v } else { u + p - v };
+                w = mul_mod(w, w_len, p);
+            }
+        }
+        len <<= 1;
+    }
+    if invert {
+        let n_inv = inv_mod(n as u64, p);
+        for x in a.iter_mut() {
+            *x = mul_mod(*x, n_inv, p);
+        }
+    }
+}
+
+/// Cyclic convolution of `a` and `b` modulo one prime, with length `size`.
+fn convolve_mod(a: &[BigInt], b: &[BigInt], size: usize, prime: &NttPrime) -> Vec<u64> {
+    let modulus = BigInt::new(prime.p as i64);
+    let reduce = |coeffs: &[BigInt]| {
+        let mut out = vec![0u64; size];
+        for (o, c) in out.iter_mut().zip(coeffs) {
+            *o = c.rem_euclid(&modulus).to_u64_lossy();
+        }
+        out
+    };
+    let mut fa = reduce(a);
+    let mut fb = reduce(b);
+    transform(&mut fa, prime, false);
+    transform(&mut fb, prime, false);
+    for (x, y) in fa.iter_mut().zip(&fb) {
+        *x = mul_mod(*x, *y, prime.p);
+    }
+    transform(&mut fa, prime, true);
+    fa
+}
+
+/// Multiplies two non-empty coefficient vectors.
+///
+/// Returns `None` if the output coefficients could exceed what the prime
+/// table can represent.
+pub(crate) fn multiply(a: &[BigInt], b: &[BigInt]) -> Option<Vec<BigInt>> {
+    let max_bits = |coeffs: &[BigInt]| coeffs.iter().map(BigInt::bits).max().unwrap_or(0);
+    let shorter = a.len().min(b.len()) as u64;
+    // |c_k| < 2^(bits(a) + bits(b)) · min(len): sign needs one more bit.
+    let bound_bits = max_bits(a) + max_bits(b) + (64 - shorter.leading_zeros() as u64) + 1;
+    let count = (bound_bits / 61 + 1) as usize;
+    let out_len = a.len() + b.len() - 1;
+    let size = out_len.next_power_of_two();
+    if count > PRIME_COUNT || size > 1 << TWO_ADICITY {
+        return None;
+    }
+    let primes = &primes()[..count];
+
+    let residues: Vec<Vec<u64>> = primes
+        .iter()
+        .map(|prime| convolve_mod(a, b, size, prime))
+        .collect();
+
+    // inverses[i][j] = p_j^{-1} mod p_i for j < i
+    let inverses: Vec<Vec<u64>> = primes
+        .iter()
+        .enumerate()
+        .map(|(i, pi)| {
+            primes[..i]
+                .iter()
+                .map(|pj| inv_mod(pj.p % pi.p, pi.p))
+                .collect()
+        })
+        .collect();
+    let product = primes.iter().fold(BigInt::new(1), |acc, prime| {
+        &acc * &BigInt::new(prime.p as i64)
+    });
+    let half = &product / &BigInt::new(2);
+
+    let mut digits = vec![0u64; count];
+    let out = (0..out_len)
+        .map(|k| {
+            // Garner: mixed-radix digits of the value in base (p_0, p_1, ...).
+            for (i, (prime, inv)) in primes.iter().zip(&inverses).enumerate() {
+                let p = prime.p;
+                let mut v = residues[i][k];
+                for (d, inv_j) in digits.iter().zip(inv) {
+                    let d = d % p;
+                    v = if v >= d { v - d } else { v + p - d };
+                    v = mul_mod(v, *inv_j, p);
+                }
+                digits[i] = v;
+            }
+            let mut value = BigInt::new(digits[count - 1] as i64);
+            for (prime, d) in primes.iter().zip(&digits).rev().skip(1) {
+                value = &(&value * &BigInt::new(prime.p as i64)) + &BigInt::new(*d as i64);
+            }
+            if value > half {
+                value = &value - &product;
+            }
+            value
+        })
+        .collect();
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prime_table() {
+        let table = primes();
+        assert_eq!(table.len(), PRIME_COUNT);
+        for prime in table.iter().take(8) {
+            assert!(BigInt::new(prime.p as i64).is_prime());
+            assert!(prime.p > 1 << 61);
+            let half = pow_mod(prime.root, 1 << (TWO_ADICITY - 1), prime.p);
+            assert_eq!(half, prime.p - 1);
+        }
+    }
+
+    #[test]
+    fn test_transform_round_trip() {
+        let prime = &primes()[0];
+        let original: Vec<u64> = (0..16).map(|i| i * i + 7).collect();
+        let mut a = original.clone();
+        transform(&mut a, prime, false);
+        transform(&mut a, prime, true);
+        assert_eq!(a, original);
+    }
+}
@@ -4,7 +4,9 @@
 //! and other number-theoretic utilities.
 
 use crate::{BigInt, GaussInt};
-use num_traits::{One, Zero};
+use num_bigint::BigInt as NumBigInt;
+use num_traits::{One, ToPrimitive, Zero};
+use std::sync::OnceLock;
 
 /// Deterministic primality test using the Baillie-PSW approach.
 ///
@@ -105,6 +107,214 @@ fn miller_rabin_test(n: &BigInt, a: &BigInt) -> bool {
     false
 }
 
+/// Returns the smallest prime strictly greater than `n`.
+///
+/// Candidates are generated on a 2·3·5·7 wheel and sieved against the
+/// primes below 2^13 using incrementally updated residues, so only
+/// survivors reach the Miller-Rabin test in [`is_prime`].
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::number_theory::next_prime;
+///
+/// assert_eq!(next_prime(&BigInt::new(0)), BigInt::new(2));
+/// assert_eq!(next_prime(&BigInt::new(13)), BigInt::new(17));
+/// assert_eq!(next_prime(&BigInt::new(1000)), BigInt::new(1009));
+/// ```
+pub fn next_prime(n: &BigInt) -> BigInt {
+    if n < &BigInt::new(WHEEL_START) {
+        let mut candidate = if n.is_negative() {
+            BigInt::zero()
+        } else {
+            n + &BigInt::one()
+        };
+        while !is_prime(&candidate) {
+            candidate = candidate + BigInt::one();
+        }
+        return candidate;
+    }
+
+    let mut candidates = WheelCandidates::new(&(n + &BigInt::one()));
+    loop {
+        if candidates.survives_sieve() && is_prime(&candidates.value()) {
+            return candidates.value();
+        }
+        candidates.advance();
+    }
+}
+
+/// Returns the largest prime strictly less than `n`, or `None` if `n <= 2`.
+///
+/// Uses the same wheel and sieve as [`next_prime`], walking downward.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::number_theory::prev_prime;
+///
+/// assert_eq!(prev_prime(&BigInt::new(3)), Some(BigInt::new(2)));
+/// assert_eq!(prev_prime(&BigInt::new(1000)), Some(BigInt::new(997)));
+/// assert_eq!(prev_prime(&BigInt::new(2)), None);
+/// ```
+pub fn prev_prime(n: &BigInt) -> Option<BigInt> {
+    if n <= &BigInt::new(2) {
+        return None;
+    }
+    if n <= &BigInt::new(WHEEL_START + WHEEL as i64) {
+        let mut candidate = n - &BigInt::one();
+        while !is_prime(&candidate) {
+            candidate = candidate - BigInt::one();
+        }
+        return Some(candidate);
+    }
+
+    let mut candidates = WheelCandidates::new_descending(&(n - &BigInt::one()));
+    loop {
+        if candidates.survives_sieve() && is_prime(&candidates.value()) {
+            return Some(candidates.value());
+        }
+        candidates.retreat();
+    }
+}
+
+/// Product of the primes 2, 3, 5, 7 used for the candidate wheel.
+const WHEEL: u64 = 210;
+
+/// Above this bound the sieve primes never divide a prime candidate trivially.
+const WHEEL_START: i64 = 1 << 13;
+
+/// Odd primes above 7 and below 2^13, for sieving wheel candidates.
+fn sieve_primes() -> &'static [u64] {
+    static PRIMES: OnceLock<Vec<u64>> = OnceLock::new();
+    PRIMES.get_or_init(|| {
+        let limit = WHEEL_START as usize;
+        let mut composite = vec![false; limit];
+        let mut primes = vec![];
+        for i in 2..limit {
+            if composite[i] {
+                continue;
+            }
+            if i > 7 {
+                primes.push(i as u64);
+            }
+            for j in (i * i..limit).step_by(i) {
+                composite[j] = true;
+            }
+        }
+        primes
+    })
+}
+
+/// Residues modulo 210 that are coprime to 210.
+fn wheel_residues() -> &'static [u64] {
+    static RESIDUES: OnceLock<Vec<u64>> = OnceLock::new();
+    RESIDUES.get_or_init(|| {
+        (1..WHEEL)
+            .filter(|r| [2, 3, 5, 7].iter().all(|p| r % p != 0))
+            .collect()
+    })
+}
+
+/// Walks the numbers coprime to 210, tracking their residues modulo the
+/// sieve primes so each step costs only machine-word arithmetic.
+struct WheelCandidates {
+    base: BigInt,
+    offset: i64,
+    spoke: usize,
+    residues: Vec<u64>,
+}
+
+impl WheelCandidates {
+    /// Starts at the first wheel number `>= start`.
+    fn new(start: &BigInt) -> Self {
+        let start_mod = start.rem_euclid(&BigInt::from(WHEEL as i64)).to_u64_lossy();
+        let spokes = wheel_residues();
+        let (spoke, offset) = match spokes.iter().position(|&r| r >= start_mod) {
+            Some(i) => (i, spokes[i] - start_mod),
+            None => (0, WHEEL + spokes[0] - start_mod),
+        };
+        Self::at(start + &BigInt::from(offset as i64), spoke)
+    }
+
+    /// Starts at the last wheel number `<= start`.
+    fn new_descending(start: &BigInt) -> Self {
+        let start_mod = start.rem_euclid(&BigInt::from(WHEEL as i64)).to_u64_lossy();
+        let spokes = wheel_residues();
+        let (spoke, offset) = match spokes.iter().rposition(|&r| r <= start_mod) {
+            Some(i) => (i, start_mod - spokes[i]),
+            None => (
+                spokes.len() - 1,
+                start_mod + WHEEL - spokes[spokes.len() - 1],
+            ),
+        };
+        Self::at(start - &BigInt::from(offset as i64), spoke)
+    }
+
+    fn at(base: BigInt, spoke: usize) -> Self {
+        let residues = sieve_primes()
+            .iter()
+            .map(|&p| base.rem_euclid(&BigInt::from(p as i64)).to_u64_lossy())
+            .collect();
+        WheelCandidates {
+            base,
+            offset: 0,
+            spoke,
+            residues,
+        }
+    }
+
+    fn value(&self) -> BigInt {
+        &self.base + &BigInt::from(self.offset)
+    }
+
+    fn survives_sieve(&self) -> bool {
+        self.residues.iter().all(|&r| r != 0)
+    }
+
+    fn advance(&mut self) {
+        let spokes = wheel_residues();
+        let next = (self.spoke + 1) % spokes.len();
+        let gap = if next == 0 {
+            WHEEL + spokes[0] - spokes[self.spoke]
+        } else {
+            spokes[next] - spokes[self.spoke]
+        };
+        self.spoke = next;
+        for (r, &p) in self.residues.iter_mut().zip(sieve_primes()) {
+            *r = (*r + gap) % p;
+        }
+        self.offset += gap as i64;
+        self.rebase();
+    }
+
+    fn retreat(&mut self) {
+        let spokes = wheel_residues();
+        let prev = (self.spoke + spokes.len() - 1) % spokes.len();
+        let gap = if self.spoke == 0 {
+            WHEEL + spokes[0] - spokes[prev]
+        } else {
+            spokes[self.spoke] - spokes[prev]
+        };
+        self.spoke = prev;
+        for (r, &p) in self.residues.iter_mut().zip(sieve_primes()) {
+            *r = (*r + p - gap % p) % p;
+        }
+        self.offset -= gap as i64;
+        self.rebase();
+    }
+
+    /// Folds the offset back into the base before it could overflow.
+    fn rebase(&mut self) {
+        if self.offset.unsigned_abs() > u64::from(u32::MAX) {
+            self.base = self.value();
+            self.offset = 0;
+        }
+    }
+}
+
 const SMALL_PRIMES: &[i64] = &[
     2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
 ];
@@ -201,13 +411,271 @@ pub fn euler_totient(n: &BigInt) -> BigInt {
     result
 }
 
+/// Carmichael's function λ(n) — the exponent of the multiplicative group
+/// mod n, i.e. the least `m > 0` with `a^m ≡ 1 (mod n)` for every `a`
+/// coprime to `n`.
+///
+/// λ(n) divides φ(n) and bounds every element order. Like
+/// [`euler_totient`], returns 1 for `n <= 1`.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::number_theory::{carmichael_lambda, euler_totient};
+///
+/// // 561 = 3·11·17 is a Carmichael number: λ(561) = 80 divides 560
+/// assert_eq!(carmichael_lambda(&BigInt::new(561)), BigInt::new(80));
+/// assert_eq!(euler_totient(&BigInt::new(561)), BigInt::new(320));
+/// ```
+pub fn carmichael_lambda(n: &BigInt) -> BigInt {
+    if *n <= BigInt::one() {
+        return BigInt::one();
+    }
+    let mut result = BigInt::one();
+    for (p, e) in &factorize(n) {
+        let term = if *p == BigInt::TWO && *e >= 3 {
+            // (Z/2^e)^× ≅ C2 × C(2^(e−2)) is not cyclic
+            BigInt::TWO.pow(*e - 2)
+        } else {
+            p.pow(*e) - p.pow(*e - 1)
+        };
+        result = result.lcm(&term);
+    }
+    result
+}
+
+/// Consecutive factors multiplied exactly before each reduction in
+/// [`factorial_mod`].
+const FACTORIAL_BLOCK: u64 = 256;
+
+/// Computes `n! mod m` without forming `n!`.
+///
+/// Factors are multiplied in blocks of consecutive integers by binary
+/// splitting, and the running product is reduced after each block. When
+/// `m` is prime and `n` lies in its upper half, Wilson's theorem reduces
+/// the work to `(m − 1 − n)!`. The cost is still linear in
+/// `min(n, m − 1 − n)`.
+///
+/// Returns `None` if `n` is negative, `m` is not positive, or more than
+/// `u64::MAX` factors would be needed.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::number_theory::factorial_mod;
+///
+/// let p = BigInt::new(1_000_000_007);
+/// assert_eq!(factorial_mod(&BigInt::new(10), &p), Some(BigInt::new(3_628_800)));
+/// // Uses Wilson's theorem: only 2! is computed
+/// assert_eq!(factorial_mod(&BigInt::new(1_000_000_004), &p), Some(BigInt::new(500_000_003)));
+/// ```
+pub fn factorial_mod(n: &BigInt, m: &BigInt) -> Option<BigInt> {
+    if n.is_negative() || !m.is_positive() {
+        return None;
+    }
+    if n >= m {
+        // m itself is one of the factors, or m divides them together
+        return Some(BigInt::zero());
+    }
+    let reflected = m - &(n + &BigInt::one());
+    if reflected < *n && is_prime(m) {
+        // (m−1)! = n!·(n+1)⋯(m−1) ≡ n!·(−1)^(m−1−n)·(m−1−n)! ≡ −1
+        let r = factorial_mod_direct(&reflected, m)?;
+        let mut inv = r.mod_inv(m)?;
+        if (&reflected % &BigInt::TWO).is_zero() {
+            inv = -inv;
+        }
+        return Some(inv.rem_euclid(m));
+    }
+    factorial_mod_direct(n, m)
+}
+
+/// `n! mod m` by blockwise products, for `0 <= n`.
+fn factorial_mod_direct(n: &BigInt, m: &BigInt) -> Option<BigInt> {
+    let n = NumBigInt::from(n.clone()).to_u64()?;
+    let mut acc = BigInt::one() % m.clone();
+    let mut start = 1;
+    while start <= n {
+        let len = FACTORIAL_BLOCK.min(n - start + 1);
+        let block = crate::big_int::product_range(&BigInt::from(NumBigInt::from(start)), len, 1);
+        acc = (&acc * &block) % m.clone();
+        start += len;
+    }
+    Some(acc)
+}
+
+/// Wilson's criterion: `p > 1` is prime exactly when `(p − 1)! ≡ −1 (mod p)`.
+///
+/// This evaluates the factorial directly, so it takes `O(p)` time; it is a
+/// cross-check for small `p`, not a practical primality test (see
+/// [`is_prime`]).
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::number_theory::wilson_check;
+///
+/// assert!(wilson_check(&BigInt::new(101)));
+/// assert!(!wilson_check(&BigInt::new(91)));
+/// ```
+pub fn wilson_check(p: &BigInt) -> bool {
+    if *p <= BigInt::one() {
+        return false;
+    }
+    let p_minus_1 = p - &BigInt::one();
+    factorial_mod_direct(&p_minus_1, p).is_some_and(|f| f == p_minus_1)
+}
+
+/// Largest prime power `p^e` with `e >= 2` for which [`binomial_mod`]
+/// builds its table of partial factorials, and the most factors it
+/// multiplies out for one base-`p` digit under Lucas' theorem.
+const BINOMIAL_TABLE_LIMIT: u64 = 1 << 24;
+
+/// Computes the binomial coefficient `C(n, k) mod m` without forming it.
+///
+/// `m` is factored and each prime-power part is handled separately:
+/// Lucas' theorem digit by digit in base `p` for `p¹`, and Granville's
+/// generalization, with Kummer's theorem for the power of `p`, for `p^e`.
+/// The parts are recombined with the CRT. `n` and `k` may have thousands
+/// of digits.
+///
+/// Returns `C(n, k) = 0` for `k < 0` or `k > n`. Returns `None` if `n` is
+/// negative, `m` is not positive, or a prime power `p^e` with `e >= 2`
+/// dividing `m` exceeds 2^24, since its table would not fit in memory.
+///
+/// A prime `p` dividing `m` only once costs `O(min(kᵢ, nᵢ − kᵢ))` per
+/// base-`p` digit, which is up to `O(p)`. Large primes are fine while the
+/// digits of `k` or `n − k` stay small; `None` is returned instead of
+/// multiplying out more than 2^24 factors for one digit.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::number_theory::binomial_mod;
+///
+/// // C(10, 3) = 120
+/// assert_eq!(binomial_mod(&BigInt::new(10), &BigInt::new(3), &BigInt::new(1000)), Some(BigInt::new(120)));
+/// let n = BigInt::new(10).pow(1000) + BigInt::new(3);
+/// assert_eq!(binomial_mod(&n, &BigInt::new(3), &BigInt::new(1001)), Some(BigInt::new(917)));
+/// ```
+pub fn binomial_mod(n: &BigInt, k: &BigInt, m: &BigInt) -> Option<BigInt> {
+    if n.is_negative() || !m.is_positive() {
+        return None;
+    }
+    if k.is_negative() || k > n || *m == BigInt::one() {
+        return Some(BigInt::zero());
+    }
+    let mut congruences = Vec::new();
+    for (p, e) in factorize(m) {
+        let residue = if e == 1 {
+            binomial_mod_prime(n, k, &p)?
+        } else {
+            binomial_mod_prime_power(n, k, &p, e)?
+        };
+        congruences.push((residue, p.pow(e)));
+    }
+    crt(&congruences)
+}
+
+/// `C(n, k) mod p` by Lucas' theorem: the product of `C(nᵢ, kᵢ)` over the
+/// base-`p` digits, or `None` if one digit needs more than
+/// [`BINOMIAL_TABLE_LIMIT`] factors.
+fn binomial_mod_prime(n: &BigInt, k: &BigInt, p: &BigInt) -> Option<BigInt> {
+    let (mut n, mut k) = (n.clone(), k.clone());
+    let mut result = BigInt::one();
+    while !k.is_zero() {
+        let (n_next, ni) = n.div_rem(p);
+        let (k_next, ki) = k.div_rem(p);
+        if ki > ni {
+            return Some(BigInt::zero());
+        }
+        // C(nᵢ, kᵢ) = nᵢ⋯(nᵢ − j + 1)/j! with j = min(kᵢ, nᵢ − kᵢ)
+        let j = ki.clone().min(&ni - &ki);
+        if j > BigInt::new(BINOMIAL_TABLE_LIMIT as i64) {
+            return None;
+        }
+        let (mut numer, mut denom) = (BigInt::one(), BigInt::one());
+        let mut i = BigInt::zero();
+        while i < j {
+            numer = (&numer * &(&ni - &i)) % p.clone();
+            i = &i + &BigInt::one();
+            denom = (&denom * &i) % p.clone();
+        }
+        let inv = denom.mod_inv(p).expect("j! is a unit below p");
+        result = (&(&result * &numer) * &inv) % p.clone();
+        (n, k) = (n_next, k_next);
+    }
+    Some(result)
+}
+
+/// `C(n, k) mod p^e` by Granville's theorem, or `None` if `p^e` is too
+/// large for the table of partial factorials.
+fn binomial_mod_prime_power(n: &BigInt, k: &BigInt, p: &BigInt, e: u32) -> Option<BigInt> {
+    let pe_big = p.pow(e);
+    let pe = NumBigInt::from(pe_big.clone()).to_u64()?;
+    if pe > BINOMIAL_TABLE_LIMIT {
+        return None;
+    }
+    let p_u = NumBigInt::from(p.clone()).to_u64()?;
+    let n_minus_k = n - k;
+    // Kummer: the power of p in C(n, k) is the number of carries in k + (n − k)
+    let v = legendre(n, p) - legendre(k, p) - legendre(&n_minus_k, p);
+    if v >= BigInt::new(i64::from(e)) {
+        return Some(BigInt::zero());
+    }
+    // prefix[r] = product of 1 ≤ j ≤ r with p ∤ j, mod p^e
+    let mut prefix = Vec::with_capacity(pe as usize);
+    let mut acc = 1u64;
+    prefix.push(acc);
+    for j in 1..pe {
+        if j % p_u != 0 {
+            acc = (u128::from(acc) * u128::from(j) % u128::from(pe)) as u64;
+        }
+        prefix.push(acc);
+    }
+    // The units of Z/p^e multiply to −1, except for 2^e with e ≥ 3
+    let period_sign = if p_u == 2 && e >= 3 { 1 } else { pe - 1 };
+    let unit_factorial = |m: &BigInt| -> BigInt {
+        let mut m = m.clone();
+        let mut product = BigInt::one();
+        while !m.is_zero() {
+            let (cycles, r) = m.div_rem(&pe_big);
+            let r = NumBigInt::from(r).to_u64().expect("below p^e") as usize;
+            let sign = BigInt::new(period_sign as i64).mod_pow(&cycles, &pe_big);
+            product = &(&product * &sign) * &BigInt::new(prefix[r] as i64) % pe_big.clone();
+            m = &m / p;
+        }
+        product
+    };
+    let numer = unit_factorial(n);
+    let denom = &unit_factorial(k) * &unit_factorial(&n_minus_k);
+    let inv = denom.mod_inv(&pe_big)?;
+    let v = NumBigInt::from(v).to_u32()?;
+    Some((&(&numer * &inv) * &p.pow(v)).rem_euclid(&pe_big))
+}
+
+/// The exponent of `p` in `n!`, `Σ ⌊n/pⁱ⌋`.
+fn legendre(n: &BigInt, p: &BigInt) -> BigInt {
+    let mut n = n / p;
+    let mut total = BigInt::zero();
+    while !n.is_zero() {
+        total = &total + &n;
+        n = &n / p;
+    }
+    total
+}
+
 /// Jacobi symbol (a/n), generalizing the Legendre symbol to odd positive moduli.
 pub fn jacobi_symbol(a: &BigInt, n: &BigInt) -> i32 {
     if (n % &BigInt::new(2)).is_zero() {
         panic!("Jacobi symbol requires an odd modulus");
     }
 
-    let mut a = a % n;
+    let mut a = a.rem_euclid(n);
     let mut n = n.clone();
     let mut t = 1i32;
 
@@ -255,7 +723,351 @@ pub fn crt(congruences: &[(BigInt, BigInt)]) -> Option<BigInt> {
         result = &result + &term;
     }
 
-    Some(&result % &product)
+    Some(result.rem_euclid(&product))
+}
+
+/// Square root modulo an odd prime (or 2) via Tonelli-Shanks.
+///
+/// Returns the smaller of the two roots `x` with `x² ≡ a (mod p)`, or `None`
+/// if `a` is a quadratic non-residue. `p` must be prime.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::number_theory::sqrt_mod_prime;
+///
+/// // 6² = 36 ≡ 10 (mod 13)
+/// assert_eq!(sqrt_mod_prime(&BigInt::new(10), &BigInt::new(13)), Some(BigInt::new(6)));
+/// assert_eq!(sqrt_mod_prime(&BigInt::new(5), &BigInt::new(13)), None);
+/// ```
+pub fn sqrt_mod_prime(a: &BigInt, p: &BigInt) -> Option<BigInt> {
+    let a = a.rem_euclid(p);
+    let two = BigInt::new(2);
+    if a.is_zero() || p == &two {
+        return Some(a);
+    }
+    if jacobi_symbol(&a, p) != 1 {
+        return None;
+    }
+
+    let one = BigInt::one();
+    let p_minus_1 = p - &one;
+    let root = if (p % &BigInt::new(4)) == BigInt::new(3) {
+        a.mod_pow(&((p + &one) / BigInt::new(4)), p)
+    } else {
+        // p - 1 = q·2^s with q odd
+        let mut q = p_minus_1.clone();
+        let mut s = 0u32;
+        while (&q % &two).is_zero() {
+            q = q / two.clone();
+            s += 1;
+        }
+        let mut z = two.clone();
+        while jacobi_symbol(&z, p) != -1 {
+            z = z + one.clone();
+        }
+
+        let mut m = s;
+        let mut c = z.mod_pow(&q, p);
+        let mut t = a.mod_pow(&q, p);
+        let mut r = a.mod_pow(&((&q + &one) / two.clone()), p);
+        while t != one {
+            // Least i with t^(2^i) = 1
+            let mut i = 0u32;
+            let mut t2i = t.clone();
+            while t2i != one {
+                t2i = (&t2i * &t2i) % p.clone();
+                i += 1;
+            }
+            let mut b = c;
+            for _ in 0..(m - i - 1) {
+                b = (&b * &b) % p.clone();
+            }
+            m = i;
+            c = (&b * &b) % p.clone();
+            t = (&t * &c) % p.clone();
+            r = (&r * &b) % p.clone();
+        }
+        r
+    };
+
+    let other = p - &root;
+    Some(if other < root { other } else { root })
+}
+
+/// All square roots of `a` modulo `n`, in increasing order.
+///
+/// `factorization` must be the prime factorization of `n`, as returned by
+/// [`factorize`]. Roots modulo each prime power come from Tonelli–Shanks
+/// and Hensel lifting and are combined with the CRT. Returns an empty
+/// vector if `a` is not a square modulo `n`.
+///
+/// When `a` shares a factor `p^v` with `n` there can be many roots, about
+/// `p^(v/2)` per root of the coprime part, and all of them are listed.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::number_theory::{factorize, sqrt_mod_composite};
+///
+/// let n = BigInt::new(77);
+/// let roots = sqrt_mod_composite(&BigInt::new(4), &n, &factorize(&n));
+/// assert_eq!(roots, [2, 9, 68, 75].map(BigInt::new));
+/// assert!(sqrt_mod_composite(&BigInt::new(3), &n, &factorize(&n)).is_empty());
+/// ```
+pub fn sqrt_mod_composite(a: &BigInt, n: &BigInt, factorization: &[(BigInt, u32)]) -> Vec<BigInt> {
+    debug_assert_eq!(
+        factorization
+            .iter()
+            .fold(BigInt::one(), |acc, (p, k)| acc * p.pow(*k)),
+        *n,
+        "factorization does not match n"
+    );
+    // Roots modulo n, built up one prime power at a time
+    let mut roots = vec![BigInt::zero()];
+    let mut modulus = BigInt::one();
+    for (p, k) in factorization {
+        let pk = p.pow(*k);
+        let local = sqrt_mod_prime_power(a, p, *k);
+        let mut combined = Vec::with_capacity(roots.len() * local.len());
+        for x in &roots {
+            for y in &local {
+                let pair = [(x.clone(), modulus.clone()), (y.clone(), pk.clone())];
+                combined.push(crt(&pair).expect("prime powers are coprime"));
+            }
+        }
+        roots = combined;
+        modulus = &modulus * &pk;
+    }
+    roots.sort();
+    roots
+}
+
+/// All square roots of `a` modulo `p^k`, unsorted.
+fn sqrt_mod_prime_power(a: &BigInt, p: &BigInt, k: u32) -> Vec<BigInt> {
+    let pk = p.pow(k);
+    let a = a.rem_euclid(&pk);
+    if a.is_zero() {
+        // x² ≡ 0 exactly when p^⌈k/2⌉ divides x
+        let step = p.pow(k.div_ceil(2));
+        return multiples_below(&step, &pk, &BigInt::zero());
+    }
+    let mut v = 0;
+    let mut b = a;
+    while (&b % p).is_zero() {
+        b = &b / p;
+        v += 1;
+    }
+    if v % 2 == 1 {
+        return Vec::new();
+    }
+    // x = p^(v/2)·y with y² ≡ b (mod p^m), and y is free modulo p^(k − v/2)
+    let m = k - v;
+    let pm = p.pow(m);
+    let scale = p.pow(v / 2);
+    let mut roots = Vec::new();
+    for y in sqrt_unit_mod_prime_power(&b, p, m) {
+        for y in multiples_below(&pm, &p.pow(k - v / 2), &y) {
+            roots.push(&scale * &y);
+        }
+    }
+    roots
+}
+
+/// `offset, offset + step, …` below `limit`.
+fn multiples_below(step: &BigInt, limit: &BigInt, offset: &BigInt) -> Vec<BigInt> {
+    let mut values = Vec::new();
+    let mut x = offset.clone();
+    while &x < limit {
+        values.push(x.clone());
+        x = &x + step;
+    }
+    values
+}
+
+/// All square roots modulo `p^m` of `b`, which is coprime to `p`.
+fn sqrt_unit_mod_prime_power(b: &BigInt, p: &BigInt, m: u32) -> Vec<BigInt> {
+    let pm = p.pow(m);
+    let b = b.rem_euclid(&pm);
+    if p == &BigInt::TWO {
+        return match m {
+            1 => vec![BigInt::one()],
+            2 => {
+                if b == BigInt::one() {
+                    vec![BigInt::one(), BigInt::new(3)]
+                } else {
+                    Vec::new()
+                }
+            }
+            _ => {
+                if &b % &BigInt::new(8) != BigInt::one() {
+                    return Vec::new();
+                }
+                // Fix one more bit at a time: if x² ≢ b (mod 2^(i+1)),
+                // then (x + 2^(i−1))² ≡ b
+                let mut x = BigInt::one();
+                for i in 3..m {
+                    if (&(&x * &x) - &b).rem_euclid(&BigInt::TWO.pow(i + 1)) != BigInt::zero() {
+                        x = &x + &BigInt::TWO.pow(i - 1);
+                    }
+                }
+                let half = BigInt::TWO.pow(m - 1);
+                [x.clone(), -&x, &x + &half, &half - &x]
+                    .iter()
+                    .map(|r| r.rem_euclid(&pm))
+                    .collect()
+            }
+        };
+    }
+    let Some(mut x) = sqrt_mod_prime(&b, p) else {
+        return Vec::new();
+    };
+    // Hensel: x ← x − (x² − b)/(2x), with 2x invertible modulo p
+    let mut modulus = p.clone();
+    for _ in 1..m {
+        modulus = &modulus * p;
+        let inv = (&x + &x).mod_inv(&modulus).expect("2x is a unit");
+        let correction = &(&(&x * &x) - &b) * &inv;
+        x = (&x - &correction).rem_euclid(&modulus);
+    }
+    vec![x.clone(), (&pm - &x).rem_euclid(&pm)]
+}
+
+/// Writes a prime `p = 2` or `p ≡ 1 (mod 4)` as `a² + b²`.
+///
+/// Takes `x` with `x² ≡ -1 (mod p)`; then `gcd(p, x + i)` in Z[i] has norm `p`.
+fn prime_as_sum_of_two_squares(p: &BigInt) -> (BigInt, BigInt) {
+    if p == &BigInt::new(2) {
+        return (BigInt::one(), BigInt::one());
+    }
+    let x = sqrt_mod_prime(&BigInt::new(-1), p).expect("p ≡ 1 (mod 4) has a root of -1");
+    let g = GaussInt::new(p.clone(), BigInt::zero()).gcd(&GaussInt::new(x, BigInt::one()));
+    (g.real().abs(), g.imag().abs())
+}
+
+/// Writes a non-negative integer as a sum of four squares (Lagrange).
+///
+/// Returns `None` for negative `n`. The components are non-negative and
+/// sorted in decreasing order.
+///
+/// After removing factors of 4, small values are searched directly. For
+/// larger values this follows Rabin and Shallit: pick `x`, `y`
+/// pseudo-randomly with the parity that makes `p = n − x² − y² ≡ 1 (mod 4)`,
+/// and retry until `p` is prime, which then splits as a sum of two squares
+/// through a Gaussian GCD.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::number_theory::four_squares;
+///
+/// let n = BigInt::new(310);
+/// let [a, b, c, d] = four_squares(&n).unwrap();
+/// assert_eq!(&(&a * &a + &b * &b) + &(&c * &c + &d * &d), n);
+/// ```
+pub fn four_squares(n: &BigInt) -> Option<[BigInt; 4]> {
+    if n.is_negative() {
+        return None;
+    }
+    if n.is_zero() {
+        return Some([
+            BigInt::zero(),
+            BigInt::zero(),
+            BigInt::zero(),
+            BigInt::zero(),
+        ]);
+    }
+
+    // n = 4^k·m with 4 ∤ m; a decomposition of m scales by 2^k.
+    let four = BigInt::new(4);
+    let mut m = n.clone();
+    let mut scale = BigInt::one();
+    while (&m % &four).is_zero() {
+        m = m / four.clone();
+        scale = scale * BigInt::new(2);
+    }
+
+    let mut squares = if m < BigInt::new(FOUR_SQUARES_SEARCH_LIMIT as i64) {
+        four_squares_search(m.to_u64_lossy())
+    } else {
+        four_squares_random(&m)
+    };
+    for x in squares.iter_mut() {
+        *x = &*x * &scale;
+    }
+    squares.sort_by(|a, b| b.cmp(a));
+    Some(squares)
+}
+
+/// Below this bound four-square decompositions are found by direct search.
+const FOUR_SQUARES_SEARCH_LIMIT: u64 = 1 << 20;
+
+/// Greedy search from the largest square down; terminates quickly in practice.
+fn four_squares_search(m: u64) -> [BigInt; 4] {
+    let isqrt = |x: u64| x.isqrt();
+    for a in (0..=isqrt(m)).rev() {
+        let r1 = m - a * a;
+        for b in (0..=isqrt(r1).min(a)).rev() {
+            let r2 = r1 - b * b;
+            for c in (0..=isqrt(r2).min(b)).rev() {
+                let r3 = r2 - c * c;
+                let d = isqrt(r3);
+                if d * d == r3 && d <= c {
+                    return [a, b, c, d].map(|x| BigInt::new(x as i64));
+                }
+            }
+        }
+    }
+    unreachable!("every non-negative integer is a sum of four squares")
+}
+
+/// Randomized search for `m = x² + y² + p` with `p` prime and `p ≡ 1 (mod 4)`.
+fn four_squares_random(m: &BigInt) -> [BigInt; 4] {
+    // x and y stay below sqrt(m/2), so p is always positive.
+    let bound = (m / &BigInt::new(2))
+        .sqrt()
+        .expect("m is positive")
+        .to_u64_lossy()
+        - 1;
+    let bound = bound.min(u64::from(u32::MAX));
+    let (x_odd, y_odd) = match m.rem_euclid(&BigInt::new(4)).to_u64_lossy() {
+        1 => (false, false),
+        2 => (true, false),
+        _ => (true, true),
+    };
+
+    // xorshift64*, seeded from m so the result is deterministic.
+    let mut state = m.rem_euclid(&BigInt::from(i64::MAX)).to_u64_lossy() | 1;
+    let mut next = move || {
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    };
+    let pick = |r: u64, odd: bool| {
+        let v = r % bound;
+        if (v % 2 == 1) == odd {
+            v
+        } else {
+            v + 1
+        }
+    };
+
+    loop {
+        let x = BigInt::new(pick(next(), x_odd) as i64);
+        let y = BigInt::new(pick(next(), y_odd) as i64);
+        let p = m - &(&x * &x + &y * &y);
+        if p == BigInt::one() {
+            return [x, y, BigInt::one(), BigInt::zero()];
+        }
+        if is_prime(&p) {
+            let (a, b) = prime_as_sum_of_two_squares(&p);
+            return [x, y, a, b];
+        }
+    }
 }
 
 /// Tests whether a Gaussian integer is prime in Z[i].
@@ -479,4 +1291,289 @@ mod tests {
         // 10 = (3+i)(3-i) → not prime
         assert!(!is_gaussian_prime(&GaussInt::from_i64(10, 0)));
     }
+
+    #[test]
+    fn test_jacobi_negative_numerator() {
+        // (-1/7) = -1 since 7 ≡ 3 (mod 4); (-1/5) = 1
+        assert_eq!(jacobi_symbol(&BigInt::new(-1), &BigInt::new(7)), -1);
+        assert_eq!(jacobi_symbol(&BigInt::new(-1), &BigInt::new(5)), 1);
+        assert_eq!(
+            jacobi_symbol(&BigInt::new(-5), &BigInt::new(7)),
+            -jacobi_symbol(&BigInt::new(5), &BigInt::new(7))
+        );
+    }
+
+    #[test]
+    fn test_crt_negative_residues() {
+        let congruences = vec![
+            (BigInt::new(-1), BigInt::new(3)),
+            (BigInt::new(-2), BigInt::new(5)),
+        ];
+        assert_eq!(crt(&congruences).unwrap(), BigInt::new(8));
+    }
+
+    #[test]
+    fn test_next_prime_small() {
+        let primes: Vec<i64> = (0..10_000).filter(|&n| is_prime(&BigInt::new(n))).collect();
+        // Crosses the switch from direct search to the wheel at 2^13.
+        for n in (-3..9_900i64).step_by(7) {
+            let expected = *primes.iter().find(|&&p| p > n).unwrap();
+            assert_eq!(
+                next_prime(&BigInt::new(n)),
+                BigInt::new(expected),
+                "n = {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_next_prime_large() {
+        let two = BigInt::new(2);
+        // 2^64 + 13 and 2^128 + 51 are the first primes past those powers.
+        assert_eq!(next_prime(&two.pow(64)), &two.pow(64) + &BigInt::new(13));
+        assert_eq!(next_prime(&two.pow(128)), &two.pow(128) + &BigInt::new(51));
+    }
+
+    #[test]
+    fn test_next_prime_512_bit() {
+        let start = BigInt::new(2).pow(511) + BigInt::new(12345);
+        let p = next_prime(&start);
+        assert!(p > start);
+        assert!(is_prime(&p));
+    }
+
+    #[test]
+    fn test_prev_prime_small() {
+        let primes: Vec<i64> = (0..10_000).filter(|&n| is_prime(&BigInt::new(n))).collect();
+        for n in (-3..10_000i64).step_by(7) {
+            let expected = primes
+                .iter()
+                .rev()
+                .find(|&&p| p < n)
+                .map(|&p| BigInt::new(p));
+            assert_eq!(prev_prime(&BigInt::new(n)), expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_prev_prime_large() {
+        let two = BigInt::new(2);
+        // 2^64 - 59 and 2^128 - 159 are the last primes below those powers.
+        assert_eq!(
+            prev_prime(&two.pow(64)),
+            Some(&two.pow(64) - &BigInt::new(59))
+        );
+        assert_eq!(
+            prev_prime(&two.pow(128)),
+            Some(&two.pow(128) - &BigInt::new(159))
+        );
+    }
+
+    #[test]
+    fn test_prev_next_prime_inverse() {
+        let p = next_prime(&BigInt::new(2).pow(100));
+        assert_eq!(prev_prime(&p), prev_prime(&BigInt::new(2).pow(100)));
+        assert_eq!(prev_prime(&next_prime(&p)), Some(p));
+    }
+
+    fn sum_of_squares(parts: &[BigInt]) -> BigInt {
+        parts.iter().fold(BigInt::zero(), |acc, x| acc + x * x)
+    }
+
+    #[test]
+    fn test_sqrt_mod_prime() {
+        for p in [2i64, 3, 5, 13, 17, 41, 97, 257, 65537] {
+            let p_big = BigInt::new(p);
+            for a in 0..p.min(300) {
+                let a_big = BigInt::new(a);
+                let is_residue = (0..p).any(|x| (x * x) % p == a);
+                match sqrt_mod_prime(&a_big, &p_big) {
+                    Some(x) => {
+                        assert!(is_residue);
+                        assert_eq!((&x * &x) % p_big.clone(), a_big, "p = {}", p);
+                    }
+                    None => assert!(!is_residue, "missed root of {} mod {}", a, p),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_prime_as_sum_of_two_squares() {
+        for p in [2i64, 5, 13, 17, 29, 37, 41, 1_000_000_009] {
+            let p = BigInt::new(p);
+            let (a, b) = prime_as_sum_of_two_squares(&p);
+            assert_eq!(sum_of_squares(&[a, b]), p);
+        }
+    }
+
+    #[test]
+    fn test_four_squares_small() {
+        for n in 0..3000i64 {
+            let parts = four_squares(&BigInt::new(n)).unwrap();
+            assert_eq!(sum_of_squares(&parts), BigInt::new(n));
+            assert!(parts.windows(2).all(|w| w[0] >= w[1]));
+        }
+        assert!(four_squares(&BigInt::new(-1)).is_none());
+    }
+
+    #[test]
+    fn test_four_squares_large() {
+        let cases = [
+            BigInt::new(2).pow(200) + BigInt::new(1_234_567),
+            BigInt::new(7).pow(90),
+            BigInt::new(4).pow(40) * BigInt::new(7_000_007),
+            BigInt::from_string("98765432109876543210987654321").unwrap(),
+        ];
+        for n in cases {
+            let parts = four_squares(&n).unwrap();
+            assert_eq!(sum_of_squares(&parts), n);
+        }
+    }
+
+    #[test]
+    fn test_sqrt_mod_composite_matches_brute_force() {
+        for n in 1..=200i64 {
+            let big_n = BigInt::new(n);
+            let factors = factorize(&big_n);
+            for a in 0..n {
+                let expected: Vec<BigInt> =
+                    (0..n).filter(|x| x * x % n == a).map(BigInt::new).collect();
+                let roots = sqrt_mod_composite(&BigInt::new(a), &big_n, &factors);
+                assert_eq!(roots, expected, "sqrt({}) mod {}", a, n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt_mod_composite_large() {
+        let p = BigInt::from_string("1000000007").unwrap();
+        let q = BigInt::from_string("998244353").unwrap();
+        let n = &(&p * &p) * &q;
+        let x = BigInt::from_string("123456789123456789").unwrap();
+        let a = (&x * &x).rem_euclid(&n);
+        let roots = sqrt_mod_composite(&a, &n, &factorize(&n));
+        assert_eq!(roots.len(), 4);
+        assert!(roots.contains(&x.rem_euclid(&n)));
+        assert!(roots
+            .iter()
+            .all(|r| (&(r * r) - &a).rem_euclid(&n).is_zero()));
+    }
+
+    #[test]
+    fn test_carmichael_lambda() {
+        // OEIS A002322
+        let expected = [
+            1, 1, 2, 2, 4, 2, 6, 2, 6, 4, 10, 2, 12, 6, 4, 4, 16, 6, 18, 4, 6, 10, 22, 2,
+        ];
+        for (n, l) in (1..).zip(expected) {
+            assert_eq!(
+                carmichael_lambda(&BigInt::new(n)),
+                BigInt::new(l),
+                "λ({})",
+                n
+            );
+        }
+        // Every unit's order divides λ(n)
+        let n = BigInt::new(2 * 2 * 2 * 2 * 9 * 25 * 7);
+        let lambda = carmichael_lambda(&n);
+        for a in [11i64, 13, 17, 101, 997] {
+            assert_eq!(BigInt::new(a).mod_pow(&lambda, &n), BigInt::one());
+        }
+        assert_eq!(
+            BigInt::new(1 << 20).carmichael_lambda(),
+            BigInt::new(1 << 18)
+        );
+    }
+
+    #[test]
+    fn test_factorial_mod() {
+        for m in 1..60i64 {
+            let modulus = BigInt::new(m);
+            let mut f = 1 % m;
+            for n in 0..70i64 {
+                let got = factorial_mod(&BigInt::new(n), &modulus);
+                assert_eq!(got, Some(BigInt::new(f)), "{}! mod {}", n, m);
+                f = f * (n + 1) % m;
+            }
+        }
+        assert_eq!(factorial_mod(&BigInt::new(-1), &BigInt::new(7)), None);
+        assert_eq!(factorial_mod(&BigInt::new(3), &BigInt::ZERO), None);
+        // Near the top of a large prime only the reflected factorial is formed
+        let p = BigInt::TWO.pow(89) - BigInt::ONE;
+        let n = &p - &BigInt::new(5);
+        assert_eq!(
+            factorial_mod(&(&p - &BigInt::ONE), &p),
+            Some(&p - &BigInt::ONE)
+        );
+        // n! ≡ −1/4! by Wilson's theorem
+        let expected = (-BigInt::new(24).mod_inv(&p).unwrap()).rem_euclid(&p);
+        assert_eq!(factorial_mod(&n, &p), Some(expected));
+    }
+
+    #[test]
+    fn test_wilson_check_agrees_with_is_prime() {
+        for n in 0..400 {
+            let n = BigInt::new(n);
+            assert_eq!(wilson_check(&n), is_prime(&n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_binomial_mod_matches_pascal() {
+        // Rows of Pascal's triangle up to 60, exactly
+        let mut row = vec![BigInt::one()];
+        for n in 0..=60i64 {
+            for m in [1i64, 2, 4, 8, 16, 27, 49, 97, 360, 1000, 1024, 3125] {
+                let modulus = BigInt::new(m);
+                for (k, c) in (0i64..).zip(&row) {
+                    let got = binomial_mod(&BigInt::new(n), &BigInt::new(k), &modulus);
+                    assert_eq!(
+                        got,
+                        Some(c.rem_euclid(&modulus)),
+                        "C({}, {}) mod {}",
+                        n,
+                        k,
+                        m
+                    );
+                }
+            }
+            let mut next = vec![BigInt::one()];
+            next.extend(row.windows(2).map(|w| &w[0] + &w[1]));
+            next.push(BigInt::one());
+            row = next;
+        }
+    }
+
+    #[test]
+    fn test_binomial_mod_edge_cases() {
+        let (n, m) = (BigInt::new(10), BigInt::new(7));
+        assert_eq!(binomial_mod(&n, &BigInt::new(11), &m), Some(BigInt::zero()));
+        assert_eq!(binomial_mod(&n, &BigInt::new(-1), &m), Some(BigInt::zero()));
+        assert_eq!(binomial_mod(&BigInt::new(-1), &n, &m), None);
+        assert_eq!(binomial_mod(&n, &n, &BigInt::zero()), None);
+        // 2^26 exceeds the table limit
+        assert_eq!(binomial_mod(&n, &n, &BigInt::TWO.pow(26)), None);
+        // A large prime modulus goes through Lucas' theorem
+        let p = BigInt::from_string("1000000000039").unwrap();
+        let big_n = &(&p * &p) + &BigInt::new(5);
+        let big_k = &p + &BigInt::new(2);
+        // Digits (1, 0, 5) and (0, 1, 2): C(1,0)·C(0,1)·C(5,2) = 0
+        assert_eq!(binomial_mod(&big_n, &big_k, &p), Some(BigInt::zero()));
+        let big_k = &(&p * &p) + &BigInt::new(2);
+        assert_eq!(binomial_mod(&big_n, &big_k, &p), Some(BigInt::new(10)));
+        // k ≈ p/2 would multiply out half a billion factors
+        let p = BigInt::new(1_000_000_007);
+        let p_minus_1 = &p - &BigInt::one();
+        let half = &p / &BigInt::TWO;
+        assert_eq!(binomial_mod(&p_minus_1, &half, &p), None);
+        // but a short product on either side is still cheap: C(p−1, k) ≡ (−1)^k
+        let k = &p - &BigInt::new(4);
+        assert_eq!(binomial_mod(&p_minus_1, &k, &p), Some(p_minus_1.clone()));
+        let k = &p - &BigInt::new(5);
+        assert_eq!(binomial_mod(&p_minus_1, &k, &p), Some(BigInt::one()));
+        let m = &p * &BigInt::new(9);
+        assert_eq!(binomial_mod(&p_minus_1, &half, &m), None);
+    }
 }
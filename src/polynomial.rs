@@ -0,0 +1,556 @@
+//! Dense univariate polynomials with BigInt coefficients.
+//!
+//! Coefficients are stored lowest degree first and kept normalized: the
+//! leading coefficient is never zero, and the zero polynomial has no
+//! coefficients at all.
+
+use crate::ntt;
+use crate::number_theory::factorize;
+use crate::rational::BigRational;
+use crate::BigInt;
+use num_traits::{One, Zero};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A polynomial `c₀ + c₁x + … + cₙxⁿ` over ℤ.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::polynomial::Polynomial;
+///
+/// // (x + 1)(x - 1) = x² - 1
+/// let p = Polynomial::from_i64(&[1, 1]);
+/// let q = Polynomial::from_i64(&[-1, 1]);
+/// let product = &p * &q;
+/// assert_eq!(product.to_string(), "x^2 - 1");
+/// assert_eq!(product.degree(), Some(2));
+/// assert_eq!(product.eval(&BigInt::new(5)), BigInt::new(24));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    coeffs: Vec<BigInt>,
+}
+
+impl Polynomial {
+    /// Creates a polynomial from coefficients, lowest degree first.
+    pub fn new(coeffs: Vec<BigInt>) -> Self {
+        let mut p = Polynomial { coeffs };
+        p.normalize();
+        p
+    }
+
+    /// Like [`new`](Self::new), from machine-integer coefficients.
+    pub fn from_i64(coeffs: &[i64]) -> Self {
+        Self::new(coeffs.iter().map(|&c| BigInt::new(c)).collect())
+    }
+
+    /// The monomial `c·xⁿ`.
+    pub fn monomial(c: BigInt, n: usize) -> Self {
+        let mut coeffs = vec![BigInt::zero(); n];
+        coeffs.push(c);
+        Self::new(coeffs)
+    }
+
+    /// Returns the coefficients, lowest degree first, without trailing zeros.
+    pub fn coeffs(&self) -> &[BigInt] {
+        &self.coeffs
+    }
+
+    /// Returns the coefficient of `xⁿ` (zero beyond the degree).
+    pub fn coeff(&self, n: usize) -> BigInt {
+        self.coeffs.get(n).cloned().unwrap_or_else(BigInt::zero)
+    }
+
+    /// Returns the degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Returns the coefficient of the highest power, or `None` for zero.
+    pub fn leading_coefficient(&self) -> Option<&BigInt> {
+        self.coeffs.last()
+    }
+
+    /// Returns `true` for the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Evaluates at `x` using Horner's rule.
+    pub fn eval(&self, x: &BigInt) -> BigInt {
+        crate::batch::evaluate_polynomial_big_int(&self.coeffs, x)
+    }
+
+    /// Returns the formal derivative.
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| c * &BigInt::new(i as i64))
+                .collect(),
+        )
+    }
+
+    /// Multiplies every coefficient by `k`.
+    pub fn scale(&self, k: &BigInt) -> Self {
+        Self::new(self.coeffs.iter().map(|c| c * k).collect())
+    }
+
+    /// Finds all rational roots, with multiplicity, by the rational root
+    /// theorem.
+    ///
+    /// Every root `a/b` in lowest terms has `a | c₀` and `b | cₙ`, so the
+    /// candidates are tested by synthetic division against `b·x - a`; each
+    /// hit deflates the polynomial, which stays integral by Gauss's lemma.
+    /// Returns the roots in ascending order together with the deflated
+    /// polynomial, which has no rational roots left, or `None` for the zero
+    /// polynomial.
+    ///
+    /// Candidate generation factors the constant and leading coefficients,
+    /// so very large coefficients can make this slow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::polynomial::Polynomial;
+    ///
+    /// // (2x - 1)(x + 3)²(x² + 1)
+    /// let p = Polynomial::from_i64(&[-9, 12, 2, 14, 11, 2]);
+    /// let (roots, rest) = p.rational_roots().unwrap();
+    /// let roots: Vec<String> = roots.iter().map(|r| r.to_string()).collect();
+    /// assert_eq!(roots, ["-3", "-3", "1/2"]);
+    /// assert_eq!(rest.to_string(), "x^2 + 1");
+    /// ```
+    pub fn rational_roots(&self) -> Option<(Vec<BigRational>, Polynomial)> {
+        if self.is_zero() {
+            return None;
+        }
+        let mut roots = Vec::new();
+        let zeros = self.coeffs.iter().take_while(|c| c.is_zero()).count();
+        roots.resize(zeros, BigRational::zero());
+        let mut rest = Polynomial {
+            coeffs: self.coeffs[zeros..].to_vec(),
+        };
+
+        if rest.degree() != Some(0) {
+            let numerators = positive_divisors(&rest.coeffs[0]);
+            let denominators = positive_divisors(rest.leading_coefficient().expect("non-zero"));
+            for b in &denominators {
+                for a in &numerators {
+                    if !a.gcd(b).is_one() {
+                        continue;
+                    }
+                    for a in [a.clone(), -a] {
+                        while rest.degree() > Some(0) {
+                            match rest.deflate(&a, b) {
+                                Some(quotient) => {
+                                    roots.push(
+                                        BigRational::new(a.clone(), b.clone()).expect("b > 0"),
+                                    );
+                                    rest = quotient;
+                                }
+                                None => break,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        roots.sort();
+        Some((roots, rest))
+    }
+
+    /// Divides by `b·x - a` with synthetic division, returning the quotient
+    /// if the division is exact over ℤ.
+    fn deflate(&self, a: &BigInt, b: &BigInt) -> Option<Self> {
+        let n = self.degree()?;
+        let mut quotient = vec![BigInt::zero(); n];
+        let mut carry = BigInt::zero();
+        for k in (1..=n).rev() {
+            let (q, r) = (&self.coeffs[k] + &carry).div_rem(b);
+            if !r.is_zero() {
+                return None;
+            }
+            carry = a * &q;
+            quotient[k - 1] = q;
+        }
+        (&self.coeffs[0] + &carry)
+            .is_zero()
+            .then(|| Self::new(quotient))
+    }
+
+    /// Drops trailing zero coefficients.
+    fn normalize(&mut self) {
+        while self.coeffs.last().is_some_and(BigInt::is_zero) {
+            self.coeffs.pop();
+        }
+    }
+}
+
+/// All positive divisors of a non-zero `n`.
+fn positive_divisors(n: &BigInt) -> Vec<BigInt> {
+    let mut divisors = vec![BigInt::one()];
+    for (p, e) in factorize(&n.abs()) {
+        let mut next = Vec::with_capacity(divisors.len() * (e as usize + 1));
+        for d in &divisors {
+            let mut power = d.clone();
+            next.push(power.clone());
+            for _ in 0..e {
+                power = &power * &p;
+                next.push(power.clone());
+            }
+        }
+        divisors = next;
+    }
+    divisors
+}
+
+impl Zero for Polynomial {
+    fn zero() -> Self {
+        Polynomial { coeffs: Vec::new() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+}
+
+impl One for Polynomial {
+    fn one() -> Self {
+        Polynomial {
+            coeffs: vec![BigInt::one()],
+        }
+    }
+}
+
+impl From<BigInt> for Polynomial {
+    fn from(c: BigInt) -> Self {
+        Self::new(vec![c])
+    }
+}
+
+impl fmt::Display for Polynomial {
+    /// Formats highest degree first, e.g. `3x^2 - x + 7`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (n, c) in self.coeffs.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+            let magnitude = c.abs();
+            if first {
+                if c.is_negative() {
+                    write!(f, "-")?;
+                }
+            } else if c.is_negative() {
+                write!(f, " - ")?;
+            } else {
+                write!(f, " + ")?;
+            }
+            first = false;
+            if n == 0 || !magnitude.is_one() {
+                write!(f, "{}", magnitude)?;
+            }
+            match n {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", n)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// --- Neg ---
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        Polynomial {
+            coeffs: self.coeffs.iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        -&self
+    }
+}
+
+// --- Add ---
+
+impl Add for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: &Polynomial) -> Polynomial {
+        let len = self.coeffs.len().max(other.coeffs.len());
+        Polynomial::new((0..len).map(|i| &self.coeff(i) + &other.coeff(i)).collect())
+    }
+}
+
+impl Add for Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: Polynomial) -> Polynomial {
+        &self + &other
+    }
+}
+
+// --- Sub ---
+
+impl Sub for &Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, other: &Polynomial) -> Polynomial {
+        let len = self.coeffs.len().max(other.coeffs.len());
+        Polynomial::new((0..len).map(|i| &self.coeff(i) - &other.coeff(i)).collect())
+    }
+}
+
+impl Sub for Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, other: Polynomial) -> Polynomial {
+        &self - &other
+    }
+}
+
+// --- Mul ---
+
+/// Shorter-operand length from which `Polynomial::mul` switches from the
+/// schoolbook product to the multi-prime NTT.
+const NTT_THRESHOLD: usize = 64;
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+
+    /// Multiplies two polynomials.
+    ///
+    /// Short operands use the schoolbook product; once both have at least
+    /// `NTT_THRESHOLD` coefficients the product goes through a multi-prime
+    /// number-theoretic transform with CRT recombination, which is
+    /// quasi-linear in the degree.
+    fn mul(self, other: &Polynomial) -> Polynomial {
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero();
+        }
+        if self.coeffs.len().min(other.coeffs.len()) >= NTT_THRESHOLD {
+            if let Some(coeffs) = ntt::multiply(&self.coeffs, &other.coeffs) {
+                return Polynomial::new(coeffs);
+            }
+        }
+        mul_schoolbook(&self.coeffs, &other.coeffs)
+    }
+}
+
+fn mul_schoolbook(a: &[BigInt], b: &[BigInt]) -> Polynomial {
+    let mut coeffs = vec![BigInt::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        if x.is_zero() {
+            continue;
+        }
+        for (j, y) in b.iter().enumerate() {
+            coeffs[i + j] = &coeffs[i + j] + &(x * y);
+        }
+    }
+    Polynomial::new(coeffs)
+}
+
+impl Mul for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: Polynomial) -> Polynomial {
+        &self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poly(coeffs: &[i64]) -> Polynomial {
+        Polynomial::from_i64(coeffs)
+    }
+
+    #[test]
+    fn test_polynomial_normalization_and_degree() {
+        assert_eq!(poly(&[1, 2, 0, 0]), poly(&[1, 2]));
+        assert_eq!(poly(&[0, 0]).degree(), None);
+        assert!(poly(&[0]).is_zero());
+        assert_eq!(poly(&[5]).degree(), Some(0));
+        assert_eq!(poly(&[1, 0, 3]).degree(), Some(2));
+        assert_eq!(
+            poly(&[1, 0, 3]).leading_coefficient(),
+            Some(&BigInt::new(3))
+        );
+        assert_eq!(Polynomial::monomial(BigInt::new(4), 3), poly(&[0, 0, 0, 4]));
+    }
+
+    #[test]
+    fn test_polynomial_add_sub_cancel_leading_terms() {
+        let a = poly(&[1, 2, 3]);
+        let b = poly(&[4, 5, -3]);
+        assert_eq!(&a + &b, poly(&[5, 7]));
+        assert_eq!((&a + &b).degree(), Some(1));
+        assert_eq!(&a - &a, Polynomial::zero());
+        assert_eq!(-&a, poly(&[-1, -2, -3]));
+    }
+
+    #[test]
+    fn test_polynomial_mul() {
+        // (1 + x)^3
+        let p = poly(&[1, 1]);
+        assert_eq!(&(&p * &p) * &p, poly(&[1, 3, 3, 1]));
+        assert_eq!(&p * &Polynomial::zero(), Polynomial::zero());
+        assert_eq!(&p * &Polynomial::one(), p);
+    }
+
+    /// Deterministic pseudo-random coefficients with the given bit size.
+    fn pseudo_random(len: usize, bits: u32, seed: u64) -> Polynomial {
+        let mut state = seed;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let coeffs = (0..len)
+            .map(|_| {
+                let mut c = BigInt::zero();
+                for _ in 0..bits.div_ceil(32) {
+                    c = &(&c * &BigInt::new(1 << 32)) + &BigInt::new((next() >> 32) as i64);
+                }
+                if next() & 1 == 1 {
+                    -c
+                } else {
+                    c
+                }
+            })
+            .collect();
+        Polynomial::new(coeffs)
+    }
+
+    #[test]
+    fn test_polynomial_mul_ntt_matches_schoolbook() {
+        for (len_a, len_b, bits, seed) in [
+            (64, 64, 8, 1),
+            (100, 70, 64, 2),
+            (130, 200, 300, 3),
+            (65, 513, 1000, 4),
+        ] {
+            let a = pseudo_random(len_a, bits, seed);
+            let b = pseudo_random(len_b, bits, seed + 100);
+            assert_eq!(&a * &b, mul_schoolbook(a.coeffs(), b.coeffs()));
+        }
+    }
+
+    #[test]
+    fn test_polynomial_mul_ntt_extreme_coefficients() {
+        // Every product term has the same sign, so the bound is tight.
+        let big = BigInt::new(2).pow(500);
+        let a = Polynomial::new(vec![&big - &BigInt::one(); 80]);
+        let b = Polynomial::new(vec![-&big; 90]);
+        let product = &a * &b;
+        assert_eq!(product, mul_schoolbook(a.coeffs(), b.coeffs()));
+        assert_eq!(
+            product.coeff(85),
+            &(&(&big - &BigInt::one()) * &-&big) * &BigInt::new(80)
+        );
+    }
+
+    #[test]
+    fn test_polynomial_eval_matches_product() {
+        let a = poly(&[3, -1, 0, 7]);
+        let b = poly(&[-2, 5, 1]);
+        let product = &a * &b;
+        for x in -5..=5 {
+            let x = BigInt::new(x);
+            assert_eq!(product.eval(&x), &a.eval(&x) * &b.eval(&x));
+        }
+    }
+
+    #[test]
+    fn test_polynomial_huge_coefficients() {
+        let big = BigInt::new(10).pow(60);
+        let p = Polynomial::new(vec![big.clone(), BigInt::one()]);
+        let sq = &p * &p;
+        assert_eq!(sq.coeff(0), &big * &big);
+        assert_eq!(sq.coeff(1), &big + &big);
+        assert_eq!(sq.eval(&-&big), BigInt::zero());
+    }
+
+    #[test]
+    fn test_polynomial_derivative() {
+        assert_eq!(poly(&[7, 3, 0, 2]).derivative(), poly(&[3, 0, 6]));
+        assert_eq!(poly(&[7]).derivative(), Polynomial::zero());
+        assert_eq!(Polynomial::zero().derivative(), Polynomial::zero());
+    }
+
+    fn rat(n: i64, d: i64) -> BigRational {
+        BigRational::new(BigInt::new(n), BigInt::new(d)).unwrap()
+    }
+
+    #[test]
+    fn test_rational_roots_with_multiplicity() {
+        // 6x³ - 5x² - 2x + 1 = (x - 1)(2x + 1)(3x - 1)
+        let (roots, rest) = poly(&[1, -2, -5, 6]).rational_roots().unwrap();
+        assert_eq!(roots, vec![rat(-1, 2), rat(1, 3), rat(1, 1)]);
+        assert_eq!(rest, Polynomial::one());
+
+        // x³(x - 2)²
+        let (roots, rest) = poly(&[0, 0, 0, 4, -4, 1]).rational_roots().unwrap();
+        assert_eq!(
+            roots,
+            vec![rat(0, 1), rat(0, 1), rat(0, 1), rat(2, 1), rat(2, 1)]
+        );
+        assert_eq!(rest, Polynomial::one());
+    }
+
+    #[test]
+    fn test_rational_roots_none_rational() {
+        // x² - 2 and x² + x + 1 have no rational roots
+        let p = &poly(&[-2, 0, 1]) * &poly(&[1, 1, 1]);
+        let (roots, rest) = p.rational_roots().unwrap();
+        assert!(roots.is_empty());
+        assert_eq!(rest, p);
+        assert!(Polynomial::zero().rational_roots().is_none());
+        let (roots, rest) = poly(&[-7]).rational_roots().unwrap();
+        assert!(roots.is_empty());
+        assert_eq!(rest, poly(&[-7]));
+    }
+
+    #[test]
+    fn test_rational_roots_rebuild_polynomial() {
+        let factors = [(3, 2), (-5, 7), (3, 2), (11, 1)];
+        let mut p = poly(&[1, 0, 1]);
+        for (a, b) in factors {
+            p = &p * &poly(&[-a, b]);
+        }
+        let (roots, rest) = p.rational_roots().unwrap();
+        assert_eq!(roots, vec![rat(-5, 7), rat(3, 2), rat(3, 2), rat(11, 1)]);
+        assert_eq!(rest, poly(&[1, 0, 1]));
+        for root in &roots {
+            let (a, b) = (root.numer(), root.denom());
+            assert!(p.deflate(&a, &b).is_some());
+        }
+    }
+
+    #[test]
+    fn test_polynomial_display() {
+        assert_eq!(poly(&[7, -1, 3]).to_string(), "3x^2 - x + 7");
+        assert_eq!(poly(&[0, 0, -1]).to_string(), "-x^2");
+        assert_eq!(poly(&[-4, 1]).to_string(), "x - 4");
+        assert_eq!(Polynomial::zero().to_string(), "0");
+    }
+}
@@ -0,0 +1,405 @@
+//! Python bindings, behind the `python` feature.
+//!
+//! Exposes [`BigInt`] and [`GaussInt`] as the Python classes `BigInt` and
+//! `GaussInt` in a `gauss_int` module, with the usual arithmetic operators.
+//! Wherever an operand is expected, a Python `int` is accepted as well, and
+//! `int(x)` converts a `BigInt` back losslessly.
+//!
+//! `maturin build` produces the extension module: `pyproject.toml` asks
+//! for a `cdylib` and enables `pyo3/extension-module`, so plain `cargo
+//! build` and `cargo test --all-features` stay ordinary Rust builds.
+
+use crate::{BigInt, GaussInt};
+use num_bigint::BigInt as NumBigInt;
+use num_traits::Zero;
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyComplex, PyTuple};
+
+/// Python wrapper for [`BigInt`].
+#[pyclass(name = "BigInt", module = "gauss_int", frozen)]
+#[derive(Clone)]
+pub struct PyBigInt(pub BigInt);
+
+/// Python wrapper for [`GaussInt`].
+#[pyclass(name = "GaussInt", module = "gauss_int", frozen)]
+#[derive(Clone)]
+pub struct PyGaussInt(pub GaussInt);
+
+/// Extracts a `BigInt` from a `BigInt` instance or a Python `int`.
+fn extract_big_int(obj: &Bound<'_, PyAny>) -> PyResult<BigInt> {
+    if let Ok(n) = obj.downcast::<PyBigInt>() {
+        return Ok(n.get().0.clone());
+    }
+    Ok(BigInt::from(obj.extract::<NumBigInt>()?))
+}
+
+/// Extracts a `GaussInt` from a `GaussInt`, a `BigInt` or a Python `int`.
+fn extract_gauss_int(obj: &Bound<'_, PyAny>) -> PyResult<GaussInt> {
+    if let Ok(z) = obj.downcast::<PyGaussInt>() {
+        return Ok(z.get().0.clone());
+    }
+    Ok(GaussInt::new(extract_big_int(obj)?, BigInt::zero()))
+}
+
+/// Converts a comparison outcome to a Python object, with `None` meaning
+/// `NotImplemented` so that Python can try the reflected operation.
+fn compare_result(py: Python<'_>, outcome: Option<bool>) -> PyObject {
+    match outcome {
+        Some(b) => PyBool::new(py, b).to_owned().into_any().unbind(),
+        None => py.NotImplemented(),
+    }
+}
+
+fn check_divisor(divisor: &BigInt) -> PyResult<()> {
+    if divisor.is_zero() {
+        return Err(PyZeroDivisionError::new_err("division by zero"));
+    }
+    Ok(())
+}
+
+/// Floor division and the matching remainder, as Python's `divmod`.
+fn floor_div_mod(a: &BigInt, b: &BigInt) -> PyResult<(BigInt, BigInt)> {
+    check_divisor(b)?;
+    let q = a.div_floor(b);
+    let r = a - &(&q * b);
+    Ok((q, r))
+}
+
+#[pymethods]
+impl PyBigInt {
+    #[new]
+    fn new(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(s) = value.extract::<&str>() {
+            return BigInt::from_string(s.trim())
+                .map(PyBigInt)
+                .ok_or_else(|| PyValueError::new_err(format!("invalid integer: {s:?}")));
+        }
+        extract_big_int(value).map(PyBigInt)
+    }
+
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyObject {
+        let outcome = extract_big_int(other)
+            .ok()
+            .map(|other| op.matches(self.0.cmp(&other)));
+        compare_result(other.py(), outcome)
+    }
+
+    fn __int__(&self) -> NumBigInt {
+        NumBigInt::from(self.0.clone())
+    }
+
+    fn __index__(&self) -> NumBigInt {
+        self.__int__()
+    }
+
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        self.__int__().into_pyobject(py)?.hash()
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("BigInt({})", self.0)
+    }
+
+    fn __add__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyBigInt(&self.0 + &extract_big_int(other)?))
+    }
+
+    fn __radd__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.__add__(other)
+    }
+
+    fn __sub__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyBigInt(&self.0 - &extract_big_int(other)?))
+    }
+
+    fn __rsub__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyBigInt(&extract_big_int(other)? - &self.0))
+    }
+
+    fn __mul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyBigInt(&self.0 * &extract_big_int(other)?))
+    }
+
+    fn __rmul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.__mul__(other)
+    }
+
+    fn __floordiv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyBigInt(
+            floor_div_mod(&self.0, &extract_big_int(other)?)?.0,
+        ))
+    }
+
+    fn __rfloordiv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyBigInt(
+            floor_div_mod(&extract_big_int(other)?, &self.0)?.0,
+        ))
+    }
+
+    fn __mod__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyBigInt(
+            floor_div_mod(&self.0, &extract_big_int(other)?)?.1,
+        ))
+    }
+
+    fn __rmod__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyBigInt(
+            floor_div_mod(&extract_big_int(other)?, &self.0)?.1,
+        ))
+    }
+
+    fn __divmod__(&self, other: &Bound<'_, PyAny>) -> PyResult<(Self, Self)> {
+        let (q, r) = floor_div_mod(&self.0, &extract_big_int(other)?)?;
+        Ok((PyBigInt(q), PyBigInt(r)))
+    }
+
+    /// `pow(x, e)` for non-negative `e`, or `pow(x, e, m)` via [`BigInt::mod_pow`].
+    /// A negative `e` with a modulus raises the inverse of `x` to `-e`, as
+    /// Python's `int` does; `ValueError` if `x` is not invertible mod `m`.
+    fn __pow__(
+        &self,
+        exp: &Bound<'_, PyAny>,
+        modulus: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Self> {
+        let exp = extract_big_int(exp)?;
+        if let Some(modulus) = modulus.filter(|m| !m.is_none()) {
+            let modulus = extract_big_int(modulus)?;
+            check_divisor(&modulus)?;
+            if exp.is_negative() {
+                let inverse = self.0.mod_inv(&modulus).ok_or_else(|| {
+                    PyValueError::new_err("base is not invertible for the given modulus")
+                })?;
+                return Ok(PyBigInt(inverse.mod_pow(&-exp, &modulus)));
+            }
+            return Ok(PyBigInt(self.0.mod_pow(&exp, &modulus)));
+        }
+        let exp = u32::try_from(NumBigInt::from(exp))
+            .map_err(|_| PyValueError::new_err("exponent must be in 0..2**32"))?;
+        Ok(PyBigInt(self.0.pow(exp)))
+    }
+
+    fn __neg__(&self) -> Self {
+        PyBigInt(-&self.0)
+    }
+
+    fn __pos__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __abs__(&self) -> Self {
+        PyBigInt(self.0.abs())
+    }
+
+    fn gcd(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyBigInt(self.0.gcd(&extract_big_int(other)?)))
+    }
+
+    fn bits(&self) -> u64 {
+        self.0.bits()
+    }
+}
+
+#[pymethods]
+impl PyGaussInt {
+    #[new]
+    #[pyo3(signature = (real, imag=None))]
+    fn new(real: &Bound<'_, PyAny>, imag: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let imag = match imag {
+            Some(imag) => extract_big_int(imag)?,
+            None => BigInt::zero(),
+        };
+        Ok(PyGaussInt(GaussInt::new(extract_big_int(real)?, imag)))
+    }
+
+    #[getter]
+    fn real(&self) -> PyBigInt {
+        PyBigInt(self.0.real().clone())
+    }
+
+    #[getter]
+    fn imag(&self) -> PyBigInt {
+        PyBigInt(self.0.imag().clone())
+    }
+
+    fn norm(&self) -> PyBigInt {
+        PyBigInt(self.0.norm())
+    }
+
+    fn conjugate(&self) -> Self {
+        PyGaussInt(self.0.conjugate())
+    }
+
+    fn gcd(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyGaussInt(self.0.gcd(&extract_gauss_int(other)?)))
+    }
+
+    /// Lossy conversion to a Python `complex`.
+    fn __complex__<'py>(&self, py: Python<'py>) -> Bound<'py, PyComplex> {
+        let (z, _) = self.0.to_complex_f64();
+        PyComplex::from_doubles(py, z.re, z.im)
+    }
+
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyObject {
+        let outcome = match (extract_gauss_int(other), op) {
+            (Ok(other), CompareOp::Eq) => Some(self.0 == other),
+            (Ok(other), CompareOp::Ne) => Some(self.0 != other),
+            _ => None,
+        };
+        compare_result(other.py(), outcome)
+    }
+
+    /// Hashes like `int` when the imaginary part is zero, since such values
+    /// compare equal to the integer.
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        if self.0.imag().is_zero() {
+            return NumBigInt::from(self.0.real().clone())
+                .into_pyobject(py)?
+                .hash();
+        }
+        let parts = (
+            NumBigInt::from(self.0.real().clone()),
+            NumBigInt::from(self.0.imag().clone()),
+        );
+        PyTuple::new(py, [parts.0, parts.1])?.hash()
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GaussInt({}, {})", self.0.real(), self.0.imag())
+    }
+
+    fn __add__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyGaussInt(&self.0 + &extract_gauss_int(other)?))
+    }
+
+    fn __radd__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.__add__(other)
+    }
+
+    fn __sub__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyGaussInt(&self.0 - &extract_gauss_int(other)?))
+    }
+
+    fn __rsub__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyGaussInt(&extract_gauss_int(other)? - &self.0))
+    }
+
+    fn __mul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyGaussInt(&self.0 * &extract_gauss_int(other)?))
+    }
+
+    fn __rmul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.__mul__(other)
+    }
+
+    /// Gaussian division with remainder, as [`GaussInt::div_rem`].
+    fn __divmod__(&self, other: &Bound<'_, PyAny>) -> PyResult<(Self, Self)> {
+        let (q, r) = self
+            .0
+            .div_rem(&extract_gauss_int(other)?)
+            .ok_or_else(|| PyZeroDivisionError::new_err("division by zero"))?;
+        Ok((PyGaussInt(q), PyGaussInt(r)))
+    }
+
+    fn __floordiv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(self.__divmod__(other)?.0)
+    }
+
+    fn __mod__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(self.__divmod__(other)?.1)
+    }
+
+    fn __pow__(&self, exp: u32, modulus: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        if modulus.is_some_and(|m| !m.is_none()) {
+            return Err(PyValueError::new_err("modular pow is not supported"));
+        }
+        Ok(PyGaussInt(self.0.pow_u32(exp)))
+    }
+
+    fn __neg__(&self) -> Self {
+        PyGaussInt(-&self.0)
+    }
+
+    fn __pos__(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// The `gauss_int` Python module.
+#[pymodule]
+fn gauss_int(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyBigInt>()?;
+    m.add_class::<PyGaussInt>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::ffi::c_str;
+
+    #[test]
+    fn test_python_operators() {
+        pyo3::append_to_inittab!(gauss_int);
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            py.run(
+                c_str!(
+                    r#"
+from gauss_int import BigInt, GaussInt
+
+n = BigInt(2) ** 100 + 1
+assert int(n) == 2**100 + 1
+assert BigInt("-12345678901234567890") == BigInt(-12345678901234567890)
+assert (BigInt(-7) // 2, BigInt(-7) % 2) == (-4, 1)
+assert divmod(BigInt(7), -2) == (BigInt(-4), BigInt(-1))
+assert 10 - BigInt(3) == BigInt(7) and pow(BigInt(3), 4, 5) == BigInt(1)
+assert BigInt(5) < 6 and hash(BigInt(2**70)) == hash(2**70)
+assert pow(BigInt(3), -1, 11) == 4 and pow(BigInt(3), -2, 11) == pow(3, -2, 11)
+try:
+    pow(BigInt(2), -1, 4)
+except ValueError:
+    pass
+else:
+    raise AssertionError
+
+z = GaussInt(3, 4)
+assert z * z.conjugate() == 25 and z.norm() == 25
+assert (z ** 2).real == -7 and str(z) == "3+4i"
+q, r = divmod(z, GaussInt(1, 1))
+assert q * GaussInt(1, 1) + r == z
+assert 2 * z == GaussInt(6, 8) and complex(z) == 3 + 4j
+assert hash(GaussInt(2**70)) == hash(2**70) and hash(GaussInt(-1, 0)) == hash(-1)
+assert len({GaussInt(5), BigInt(5), 5}) == 1
+try:
+    BigInt(1) // 0
+except ZeroDivisionError:
+    pass
+else:
+    raise AssertionError
+"#
+                ),
+                None,
+                None,
+            )
+            .unwrap();
+        });
+    }
+}
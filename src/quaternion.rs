@@ -0,0 +1,348 @@
+//! Lipschitz quaternions: `a + bi + cj + dk` with `a, b, c, d ∈ ℤ`.
+//!
+//! The multiplication is Hamilton's (`i² = j² = k² = ijk = -1`), which is
+//! not commutative. The norm `a² + b² + c² + d²` is multiplicative, which is
+//! Euler's four-square identity.
+
+use crate::gauss_int::round_div;
+use crate::{BigInt, GaussInt};
+use num_traits::{One, Zero};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A quaternion with integer components.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::quaternion::Quaternion;
+///
+/// let i = Quaternion::from_i64(0, 1, 0, 0);
+/// let j = Quaternion::from_i64(0, 0, 1, 0);
+/// assert_eq!((&i * &j).to_string(), "k");
+/// assert_eq!((&j * &i).to_string(), "-k");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quaternion {
+    a: BigInt,
+    b: BigInt,
+    c: BigInt,
+    d: BigInt,
+}
+
+impl Quaternion {
+    /// Creates `a + bi + cj + dk`.
+    pub fn new(a: BigInt, b: BigInt, c: BigInt, d: BigInt) -> Self {
+        Quaternion { a, b, c, d }
+    }
+
+    /// Like [`new`](Self::new), from machine integers.
+    pub fn from_i64(a: i64, b: i64, c: i64, d: i64) -> Self {
+        Quaternion {
+            a: BigInt::new(a),
+            b: BigInt::new(b),
+            c: BigInt::new(c),
+            d: BigInt::new(d),
+        }
+    }
+
+    /// Returns the components `[a, b, c, d]` of `a + bi + cj + dk`.
+    pub fn components(&self) -> [&BigInt; 4] {
+        [&self.a, &self.b, &self.c, &self.d]
+    }
+
+    /// Returns `true` if all four components are zero.
+    pub fn is_zero(&self) -> bool {
+        self.a.is_zero() && self.b.is_zero() && self.c.is_zero() && self.d.is_zero()
+    }
+
+    /// Returns `a - bi - cj - dk`.
+    pub fn conjugate(&self) -> Self {
+        Quaternion {
+            a: self.a.clone(),
+            b: -&self.b,
+            c: -&self.c,
+            d: -&self.d,
+        }
+    }
+
+    /// The norm `a² + b² + c² + d²`, which is multiplicative.
+    pub fn norm(&self) -> BigInt {
+        &(&(&self.a * &self.a) + &(&self.b * &self.b))
+            + &(&(&self.c * &self.c) + &(&self.d * &self.d))
+    }
+
+    /// Returns true for the eight Lipschitz units ±1, ±i, ±j, ±k.
+    pub fn is_unit(&self) -> bool {
+        self.norm() == BigInt::new(1)
+    }
+
+    /// Raises to a non-negative integer power using exponentiation by squaring.
+    pub fn pow_u32(&self, exp: u32) -> Self {
+        let mut result = Quaternion::one();
+        let mut base = self.clone();
+        let mut e = exp;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = &result * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Right division with remainder: `self = q·other + r`.
+    ///
+    /// The quotient rounds each component of `self·other⁻¹` to the nearest
+    /// integer. Lipschitz quaternions are not Euclidean, so this only
+    /// guarantees `N(r) <= N(other)`; equality happens when all four
+    /// components of `self·other⁻¹` are exact halves.
+    ///
+    /// Returns `None` if `other` is zero.
+    pub fn div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        if other.is_zero() {
+            return None;
+        }
+        let n = other.norm();
+        let numerator = self * &other.conjugate();
+        let q = Quaternion {
+            a: round_div(&numerator.a, &n),
+            b: round_div(&numerator.b, &n),
+            c: round_div(&numerator.c, &n),
+            d: round_div(&numerator.d, &n),
+        };
+        let r = self - &(&q * other);
+        Some((q, r))
+    }
+}
+
+impl From<GaussInt> for Quaternion {
+    /// Embeds `a + bi` as `a + bi + 0j + 0k`.
+    fn from(z: GaussInt) -> Self {
+        Quaternion {
+            a: z.real().clone(),
+            b: z.imag().clone(),
+            c: BigInt::zero(),
+            d: BigInt::zero(),
+        }
+    }
+}
+
+impl Zero for Quaternion {
+    fn zero() -> Self {
+        Quaternion::from_i64(0, 0, 0, 0)
+    }
+
+    fn is_zero(&self) -> bool {
+        Quaternion::is_zero(self)
+    }
+}
+
+impl One for Quaternion {
+    fn one() -> Self {
+        Quaternion::from_i64(1, 0, 0, 0)
+    }
+}
+
+impl fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (value, unit) in [
+            (&self.a, ""),
+            (&self.b, "i"),
+            (&self.c, "j"),
+            (&self.d, "k"),
+        ] {
+            if value.is_zero() {
+                continue;
+            }
+            if value.is_positive() && !first {
+                write!(f, "+")?;
+            }
+            first = false;
+            match (unit, value.abs() == BigInt::one()) {
+                ("", _) | (_, false) => write!(f, "{}{}", value, unit)?,
+                (_, true) if value.is_negative() => write!(f, "-{}", unit)?,
+                _ => write!(f, "{}", unit)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// --- Neg ---
+
+impl Neg for &Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Quaternion {
+        Quaternion {
+            a: -&self.a,
+            b: -&self.b,
+            c: -&self.c,
+            d: -&self.d,
+        }
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Quaternion {
+        -&self
+    }
+}
+
+// --- Add ---
+
+impl Add for &Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, other: &Quaternion) -> Quaternion {
+        Quaternion {
+            a: &self.a + &other.a,
+            b: &self.b + &other.b,
+            c: &self.c + &other.c,
+            d: &self.d + &other.d,
+        }
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, other: Quaternion) -> Quaternion {
+        &self + &other
+    }
+}
+
+// --- Sub ---
+
+impl Sub for &Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, other: &Quaternion) -> Quaternion {
+        Quaternion {
+            a: &self.a - &other.a,
+            b: &self.b - &other.b,
+            c: &self.c - &other.c,
+            d: &self.d - &other.d,
+        }
+    }
+}
+
+impl Sub for Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, other: Quaternion) -> Quaternion {
+        &self - &other
+    }
+}
+
+// --- Mul ---
+
+impl Mul for &Quaternion {
+    type Output = Quaternion;
+
+    /// The Hamilton product.
+    fn mul(self, other: &Quaternion) -> Quaternion {
+        let (a1, b1, c1, d1) = (&self.a, &self.b, &self.c, &self.d);
+        let (a2, b2, c2, d2) = (&other.a, &other.b, &other.c, &other.d);
+        Quaternion {
+            a: &(&(a1 * a2) - &(b1 * b2)) - &(&(c1 * c2) + &(d1 * d2)),
+            b: &(&(a1 * b2) + &(b1 * a2)) + &(&(c1 * d2) - &(d1 * c2)),
+            c: &(&(a1 * c2) - &(b1 * d2)) + &(&(c1 * a2) + &(d1 * b2)),
+            d: &(&(a1 * d2) + &(b1 * c2)) - &(&(c1 * b2) - &(d1 * a2)),
+        }
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, other: Quaternion) -> Quaternion {
+        &self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(a: i64, b: i64, c: i64, d: i64) -> Quaternion {
+        Quaternion::from_i64(a, b, c, d)
+    }
+
+    #[test]
+    fn test_quaternion_units() {
+        let (i, j, k) = (q(0, 1, 0, 0), q(0, 0, 1, 0), q(0, 0, 0, 1));
+        let minus_one = q(-1, 0, 0, 0);
+        assert_eq!(&i * &i, minus_one);
+        assert_eq!(&j * &j, minus_one);
+        assert_eq!(&k * &k, minus_one);
+        assert_eq!(&(&i * &j) * &k, minus_one);
+        assert_eq!(&j * &k, i);
+        assert_eq!(&k * &i, j);
+        assert_eq!(&k * &j, -i);
+        assert!(k.is_unit());
+        assert!(!q(1, 1, 0, 0).is_unit());
+    }
+
+    #[test]
+    fn test_quaternion_norm_multiplicative() {
+        let x = q(1, -2, 3, 7);
+        let y = q(-5, 4, 0, 2);
+        assert_eq!((&x * &y).norm(), &x.norm() * &y.norm());
+        assert_eq!(
+            &x * &x.conjugate(),
+            Quaternion::from(GaussInt::new(x.norm(), BigInt::zero()))
+        );
+        // conj(xy) = conj(y) conj(x)
+        assert_eq!((&x * &y).conjugate(), &y.conjugate() * &x.conjugate());
+        assert_eq!(x.pow_u32(3), &(&x * &x) * &x);
+    }
+
+    #[test]
+    fn test_quaternion_div_rem() {
+        let cases = [
+            (q(17, -3, 8, 11), q(2, 1, -1, 3)),
+            (q(100, 0, -50, 7), q(-3, 4, 0, 0)),
+            (q(5, 5, 5, 5), q(2, 0, 0, 0)),
+        ];
+        for (x, y) in cases {
+            let (quot, rem) = x.div_rem(&y).unwrap();
+            assert_eq!(&(&quot * &y) + &rem, x);
+            assert!(rem.norm() <= y.norm());
+        }
+        // Exact division
+        let x = &q(3, 1, -2, 5) * &q(1, 2, 3, 4);
+        let (quot, rem) = x.div_rem(&q(1, 2, 3, 4)).unwrap();
+        assert_eq!(quot, q(3, 1, -2, 5));
+        assert!(rem.is_zero());
+        assert!(x.div_rem(&Quaternion::zero()).is_none());
+    }
+
+    #[test]
+    fn test_quaternion_embeds_gaussian_integers() {
+        let a = GaussInt::from_i64(3, 4);
+        let b = GaussInt::from_i64(-1, 2);
+        assert_eq!(
+            &Quaternion::from(a.clone()) * &Quaternion::from(b.clone()),
+            Quaternion::from(&a * &b)
+        );
+    }
+
+    #[test]
+    fn test_quaternion_display() {
+        assert_eq!(q(1, -2, 3, -1).to_string(), "1-2i+3j-k");
+        assert_eq!(q(0, 0, 1, 5).to_string(), "j+5k");
+        assert_eq!(q(-1, 0, 0, 0).to_string(), "-1");
+        assert_eq!(q(0, 0, 0, 0).to_string(), "0");
+    }
+}
@@ -0,0 +1,132 @@
+//! Random values and shrinking for quickcheck.
+//!
+//! With the `quickcheck` feature, [`BigInt`] and [`GaussInt`] implement
+//! `quickcheck::Arbitrary`. The generator's size bounds the number of
+//! decimal digits of each integer; failing cases shrink toward zero, first
+//! by dropping digits and then by halving the distance to zero.
+//!
+//! ```
+//! use gauss_int::GaussInt;
+//!
+//! fn norm_is_multiplicative(a: GaussInt, b: GaussInt) -> bool {
+//!     (&a * &b).norm() == &a.norm() * &b.norm()
+//! }
+//! quickcheck::quickcheck(norm_is_multiplicative as fn(GaussInt, GaussInt) -> bool);
+//! ```
+
+use crate::{BigInt, GaussInt};
+use ::quickcheck::{Arbitrary, Gen};
+
+impl Arbitrary for BigInt {
+    /// An integer of either sign with at most `g.size()` decimal digits.
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = usize::arbitrary(g) % (g.size() + 1);
+        let text: String = (0..len)
+            .map(|_| char::from(b'0' + u8::arbitrary(g) % 10))
+            .collect();
+        let n = BigInt::from_string(&text).unwrap_or(BigInt::ZERO);
+        if bool::arbitrary(g) {
+            -n
+        } else {
+            n
+        }
+    }
+
+    /// Candidates closer to zero: zero itself, the absolute value, one
+    /// digit fewer, then `self − d` for `d = self/2, self/4, …, ±1`.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        if self.is_zero() {
+            return Box::new(std::iter::empty());
+        }
+        let mut candidates = vec![BigInt::ZERO];
+        if self.is_negative() {
+            candidates.push(self.abs());
+        }
+        let ten = BigInt::new(10);
+        let fewer_digits = self / &ten;
+        if !fewer_digits.is_zero() {
+            candidates.push(fewer_digits);
+        }
+        let mut step = self / &BigInt::TWO;
+        while !step.is_zero() {
+            candidates.push(self - &step);
+            step = &step / &BigInt::TWO;
+        }
+        let unit = if self.is_negative() {
+            BigInt::new(-1)
+        } else {
+            BigInt::ONE
+        };
+        candidates.push(self - &unit);
+        candidates.dedup();
+        Box::new(candidates.into_iter())
+    }
+}
+
+impl Arbitrary for GaussInt {
+    /// Both components drawn independently as by [`BigInt`]'s impl.
+    fn arbitrary(g: &mut Gen) -> Self {
+        GaussInt::new(BigInt::arbitrary(g), BigInt::arbitrary(g))
+    }
+
+    /// Shrinks the real part with the imaginary part fixed, then the
+    /// imaginary part with the real part fixed.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let (real, imag) = (self.real().clone(), self.imag().clone());
+        let real_shrunk = {
+            let imag = imag.clone();
+            real.shrink().map(move |r| GaussInt::new(r, imag.clone()))
+        };
+        let imag_shrunk = imag.shrink().map(move |i| GaussInt::new(real.clone(), i));
+        Box::new(real_shrunk.chain(imag_shrunk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Repeatedly takes the first shrink candidate that still fails, as
+    /// quickcheck does, and returns the final value.
+    fn minimize<T: Arbitrary>(mut value: T, fails: impl Fn(&T) -> bool) -> T {
+        while let Some(next) = value.shrink().find(|c| fails(c)) {
+            value = next;
+        }
+        value
+    }
+
+    #[test]
+    fn test_digit_limit() {
+        let mut g = Gen::new(7);
+        for _ in 0..200 {
+            assert!(BigInt::arbitrary(&mut g).abs() < BigInt::new(10_000_000));
+        }
+    }
+
+    #[test]
+    fn test_shrinks_to_boundary() {
+        let start = BigInt::from_string("98765432109876543210").unwrap();
+        assert_eq!(
+            minimize(start, |n| *n >= BigInt::new(1000)),
+            BigInt::new(1000)
+        );
+        let start = BigInt::new(-987_654);
+        assert_eq!(minimize(start, |n| *n < BigInt::new(-42)), BigInt::new(-43));
+        assert_eq!(BigInt::ZERO.shrink().count(), 0);
+
+        let z = GaussInt::from_i64(-5000, 777);
+        // The real part goes to zero first, then 11 is the least imaginary part left
+        let shrunk = minimize(z, |z| z.norm() > BigInt::new(100));
+        assert_eq!(shrunk, GaussInt::from_i64(0, 11));
+    }
+
+    #[test]
+    fn test_property_runs() {
+        fn conjugate_norm(z: GaussInt) -> bool {
+            z.conjugate().norm() == z.norm()
+        }
+        ::quickcheck::QuickCheck::new()
+            .tests(200)
+            .quickcheck(conjugate_norm as fn(GaussInt) -> bool);
+    }
+}
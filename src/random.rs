@@ -0,0 +1,160 @@
+//! Random Gaussian integers, for randomized testing (`rand` feature).
+//!
+//! Every lattice point of the target region is equally likely. Discs and
+//! annuli are sampled by drawing from a bounding square and rejecting
+//! candidates outside the region.
+
+use crate::{BigInt, GaussInt};
+use num_bigint::RandBigInt;
+use rand::Rng;
+
+/// A uniform integer in `low..=high`.
+fn uniform_inclusive<R: Rng + ?Sized>(low: &BigInt, high: &BigInt, rng: &mut R) -> BigInt {
+    let high = num_bigint::BigInt::from(high + &BigInt::ONE);
+    BigInt::from(rng.gen_bigint_range(&low.clone().into(), &high))
+}
+
+impl GaussInt {
+    /// A uniformly random Gaussian integer in the rectangle with corners
+    /// `min` and `max`, bounds included, each component drawn independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component of `min` exceeds that of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{BigInt, GaussInt};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let big = BigInt::new(10).pow(40);
+    /// let min = GaussInt::new(-&big, BigInt::ZERO);
+    /// let max = GaussInt::new(big.clone(), BigInt::new(3));
+    /// let z = GaussInt::random_in_rect(&min, &max, &mut rng);
+    /// assert!(z.real().abs() <= big && z.imag() <= &BigInt::new(3));
+    /// ```
+    pub fn random_in_rect<R: Rng + ?Sized>(min: &GaussInt, max: &GaussInt, rng: &mut R) -> Self {
+        assert!(
+            min.real() <= max.real() && min.imag() <= max.imag(),
+            "random_in_rect: min exceeds max"
+        );
+        GaussInt::new(
+            uniform_inclusive(min.real(), max.real(), rng),
+            uniform_inclusive(min.imag(), max.imag(), rng),
+        )
+    }
+
+    /// A uniformly random Gaussian integer with `|z| <= radius`, that is
+    /// `N(z) <= radius²`.
+    ///
+    /// About π/4 of the candidates are accepted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::{BigInt, GaussInt};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let radius = BigInt::new(10).pow(30);
+    /// let z = GaussInt::random_in_disc(&radius, &mut rng);
+    /// assert!(z.norm() <= &radius * &radius);
+    /// ```
+    pub fn random_in_disc<R: Rng + ?Sized>(radius: &BigInt, rng: &mut R) -> Self {
+        GaussInt::random_in_annulus(&BigInt::ZERO, radius, rng)
+    }
+
+    /// A uniformly random Gaussian integer with `inner <= |z| <= outer`.
+    ///
+    /// The expected number of candidates is the area of the bounding
+    /// square over the area of the annulus, so very thin annuli are slow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inner` is negative or greater than `outer`.
+    pub fn random_in_annulus<R: Rng + ?Sized>(inner: &BigInt, outer: &BigInt, rng: &mut R) -> Self {
+        assert!(
+            !inner.is_negative() && inner <= outer,
+            "random_in_annulus: need 0 <= inner <= outer"
+        );
+        let (min_norm, max_norm) = (inner * inner, outer * outer);
+        let low = -outer;
+        loop {
+            let z = GaussInt::new(
+                uniform_inclusive(&low, outer, rng),
+                uniform_inclusive(&low, outer, rng),
+            );
+            let norm = z.norm();
+            if min_norm <= norm && norm <= max_norm {
+                return z;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_disc_covers_all_points() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let radius = BigInt::new(2);
+        let mut seen = std::collections::BTreeSet::new();
+        for _ in 0..2000 {
+            let z = GaussInt::random_in_disc(&radius, &mut rng);
+            assert!(z.norm() <= BigInt::new(4));
+            seen.insert(z);
+        }
+        // Lattice points with a² + b² <= 4
+        assert_eq!(seen.len(), 13);
+        assert_eq!(
+            GaussInt::random_in_disc(&BigInt::ZERO, &mut rng),
+            GaussInt::ZERO
+        );
+    }
+
+    #[test]
+    fn test_annulus_bounds() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let outer = BigInt::new(10).pow(25);
+        let inner = &outer - &BigInt::new(10).pow(24);
+        for _ in 0..50 {
+            let norm = GaussInt::random_in_annulus(&inner, &outer, &mut rng).norm();
+            assert!(&inner * &inner <= norm && norm <= &outer * &outer);
+        }
+        // A circle through twelve lattice points, such as (3, 4) and (5, 0)
+        let five = BigInt::new(5);
+        let z = GaussInt::random_in_annulus(&five, &five, &mut rng);
+        assert_eq!(z.norm(), BigInt::new(25));
+    }
+
+    #[test]
+    fn test_rect_hits_corners() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let (min, max) = (GaussInt::from_i64(-1, 5), GaussInt::from_i64(1, 6));
+        let mut seen = std::collections::BTreeSet::new();
+        for _ in 0..500 {
+            let z = GaussInt::random_in_rect(&min, &max, &mut rng);
+            assert!(z.real().abs() <= BigInt::ONE);
+            assert!(z.imag() >= &BigInt::new(5) && z.imag() <= &BigInt::new(6));
+            seen.insert(z);
+        }
+        assert_eq!(seen.len(), 6);
+        let p = GaussInt::from_i64(4, -4);
+        assert_eq!(GaussInt::random_in_rect(&p, &p, &mut rng), p);
+    }
+
+    #[test]
+    #[should_panic(expected = "inner <= outer")]
+    fn test_annulus_rejects_inverted_radii() {
+        let mut rng = StdRng::seed_from_u64(0);
+        GaussInt::random_in_annulus(&BigInt::new(3), &BigInt::new(2), &mut rng);
+    }
+}
@@ -0,0 +1,327 @@
+//! Exact rational numbers with BigInt numerator and denominator.
+//!
+//! `BigRational` wraps `num_rational::BigRational` the same way [`BigInt`]
+//! wraps `num_bigint::BigInt`: values are always in lowest terms with a
+//! positive denominator.
+
+use crate::{BigInt, GaussInt};
+use num_rational::BigRational as NumBigRational;
+use num_traits::{One, Signed, Zero};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An exact fraction `numer / denom` in lowest terms.
+///
+/// # Examples
+///
+/// ```
+/// use gauss_int::BigInt;
+/// use gauss_int::rational::BigRational;
+///
+/// let a = BigRational::new(BigInt::new(6), BigInt::new(-4)).unwrap();
+/// assert_eq!(a.to_string(), "-3/2");
+/// let b = BigRational::from_integer(BigInt::new(2));
+/// assert_eq!((&a + &b).to_string(), "1/2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BigRational {
+    inner: NumBigRational,
+}
+
+impl BigRational {
+    /// Creates `numer / denom` reduced to lowest terms.
+    ///
+    /// Returns `None` if `denom` is zero.
+    pub fn new(numer: BigInt, denom: BigInt) -> Option<Self> {
+        if denom.is_zero() {
+            return None;
+        }
+        Some(BigRational {
+            inner: NumBigRational::new(numer.into(), denom.into()),
+        })
+    }
+
+    pub fn from_integer(n: BigInt) -> Self {
+        BigRational {
+            inner: NumBigRational::from_integer(n.into()),
+        }
+    }
+
+    pub fn numer(&self) -> BigInt {
+        BigInt::from(self.inner.numer().clone())
+    }
+
+    /// Returns the denominator, which is always positive.
+    pub fn denom(&self) -> BigInt {
+        BigInt::from(self.inner.denom().clone())
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.inner.is_integer()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.inner.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.inner.is_negative()
+    }
+
+    pub fn abs(&self) -> Self {
+        BigRational {
+            inner: self.inner.abs(),
+        }
+    }
+
+    /// Returns the reciprocal, or `None` for zero.
+    pub fn recip(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(BigRational {
+            inner: self.inner.recip(),
+        })
+    }
+}
+
+impl From<BigInt> for BigRational {
+    fn from(n: BigInt) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl Zero for BigRational {
+    fn zero() -> Self {
+        BigRational {
+            inner: NumBigRational::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.inner.is_zero()
+    }
+}
+
+impl One for BigRational {
+    fn one() -> Self {
+        BigRational {
+            inner: NumBigRational::one(),
+        }
+    }
+}
+
+impl fmt::Display for BigRational {
+    /// Formats as `numer/denom`, or just `numer` for integers.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.inner.numer())
+        } else {
+            write!(f, "{}/{}", self.inner.numer(), self.inner.denom())
+        }
+    }
+}
+
+impl From<NumBigRational> for BigRational {
+    fn from(inner: NumBigRational) -> Self {
+        BigRational { inner }
+    }
+}
+
+impl Add for BigRational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        BigRational {
+            inner: self.inner + other.inner,
+        }
+    }
+}
+
+impl Add for &BigRational {
+    type Output = BigRational;
+
+    fn add(self, other: &BigRational) -> BigRational {
+        BigRational {
+            inner: &self.inner + &other.inner,
+        }
+    }
+}
+
+impl Sub for BigRational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        BigRational {
+            inner: self.inner - other.inner,
+        }
+    }
+}
+
+impl Sub for &BigRational {
+    type Output = BigRational;
+
+    fn sub(self, other: &BigRational) -> BigRational {
+        BigRational {
+            inner: &self.inner - &other.inner,
+        }
+    }
+}
+
+impl Mul for BigRational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        BigRational {
+            inner: self.inner * other.inner,
+        }
+    }
+}
+
+impl Mul for &BigRational {
+    type Output = BigRational;
+
+    fn mul(self, other: &BigRational) -> BigRational {
+        BigRational {
+            inner: &self.inner * &other.inner,
+        }
+    }
+}
+
+impl Div for BigRational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn div(self, other: Self) -> Self {
+        BigRational {
+            inner: self.inner / other.inner,
+        }
+    }
+}
+
+impl Div for &BigRational {
+    type Output = BigRational;
+
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn div(self, other: &BigRational) -> BigRational {
+        BigRational {
+            inner: &self.inner / &other.inner,
+        }
+    }
+}
+
+impl Neg for BigRational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        BigRational { inner: -self.inner }
+    }
+}
+
+impl Neg for &BigRational {
+    type Output = BigRational;
+
+    fn neg(self) -> BigRational {
+        BigRational {
+            inner: -&self.inner,
+        }
+    }
+}
+
+/// A complex number `re + im·i` with exact rational components, such as the
+/// exact quotient of two Gaussian integers.
+///
+/// [`GaussInt::round_from_rational`] rounds it to the nearest Gaussian
+/// integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplexRational {
+    re: BigRational,
+    im: BigRational,
+}
+
+impl ComplexRational {
+    /// Creates `re + im·i` from its rational parts.
+    pub fn new(re: BigRational, im: BigRational) -> Self {
+        ComplexRational { re, im }
+    }
+
+    /// The exact quotient `a / b`, or `None` if `b` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gauss_int::rational::ComplexRational;
+    /// use gauss_int::GaussInt;
+    ///
+    /// let q = ComplexRational::quotient(&GaussInt::from_i64(1, 0), &GaussInt::from_i64(1, 1)).unwrap();
+    /// assert_eq!((q.re().to_string(), q.im().to_string()), ("1/2".into(), "-1/2".into()));
+    /// ```
+    pub fn quotient(a: &GaussInt, b: &GaussInt) -> Option<Self> {
+        let norm = b.norm();
+        let numerator = a * &b.conjugate();
+        Some(ComplexRational {
+            re: BigRational::new(numerator.real().clone(), norm.clone())?,
+            im: BigRational::new(numerator.imag().clone(), norm)?,
+        })
+    }
+
+    /// The real part.
+    pub fn re(&self) -> &BigRational {
+        &self.re
+    }
+
+    /// The imaginary part.
+    pub fn im(&self) -> &BigRational {
+        &self.im
+    }
+}
+
+impl From<GaussInt> for ComplexRational {
+    fn from(z: GaussInt) -> Self {
+        ComplexRational {
+            re: BigRational::from_integer(z.real().clone()),
+            im: BigRational::from_integer(z.imag().clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(n: i64, d: i64) -> BigRational {
+        BigRational::new(BigInt::new(n), BigInt::new(d)).unwrap()
+    }
+
+    #[test]
+    fn test_rational_lowest_terms() {
+        let a = q(10, -4);
+        assert_eq!(a.numer(), BigInt::new(-5));
+        assert_eq!(a.denom(), BigInt::new(2));
+        assert_eq!(q(0, 7), BigRational::zero());
+        assert!(BigRational::new(BigInt::new(1), BigInt::new(0)).is_none());
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        assert_eq!(&q(1, 2) + &q(1, 3), q(5, 6));
+        assert_eq!(&q(1, 2) - &q(1, 3), q(1, 6));
+        assert_eq!(&q(2, 3) * &q(9, 4), q(3, 2));
+        assert_eq!(&q(2, 3) / &q(4, 9), q(3, 2));
+        assert_eq!(-q(2, 3), q(-2, 3));
+        assert_eq!(q(-2, 3).recip().unwrap(), q(-3, 2));
+        assert!(BigRational::zero().recip().is_none());
+    }
+
+    #[test]
+    fn test_rational_ordering_and_display() {
+        assert!(q(-1, 2) < q(1, 3));
+        assert!(q(1, 3) < q(1, 2));
+        assert_eq!(q(4, 2).to_string(), "2");
+        assert_eq!(q(-3, 9).to_string(), "-1/3");
+    }
+}
@@ -0,0 +1,115 @@
+//! Interactive calculator behind `gauss repl`, built on
+//! [`gauss_int::expr`].
+//!
+//! `name = expr` binds a variable and `_` refers to the previous result.
+
+use gauss_int::expr::{evaluate_with, Value};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+/// Results longer than this many characters also get an approximate form.
+const APPROX_THRESHOLD: usize = 60;
+
+fn approx(value: &Value) -> String {
+    match value {
+        Value::Int(n) => format!("≈{}", n.to_scientific(6)),
+        Value::Gauss(z) => z.to_approx_string(6),
+    }
+}
+
+/// Splits `name = expr` into the target name and the expression.
+fn split_assignment(line: &str) -> Option<(&str, &str)> {
+    let (name, expr) = line.split_once('=')?;
+    let name = name.trim();
+    let mut chars = name.chars();
+    let first = chars.next()?;
+    let is_ident =
+        (first.is_alphabetic() || first == '_') && chars.all(|c| c.is_alphanumeric() || c == '_');
+    is_ident.then_some((name, expr))
+}
+
+/// Calculator state: variable bindings and the previous result.
+#[derive(Default)]
+pub struct Session {
+    vars: HashMap<String, Value>,
+}
+
+impl Session {
+    /// Evaluates one line, binding it to `_` and, for `name = expr`, to
+    /// `name` as well.
+    pub fn eval_line(&mut self, line: &str) -> Result<Value, String> {
+        let (target, expr) = match split_assignment(line) {
+            Some((name, expr)) => (Some(name), expr),
+            None => (None, line),
+        };
+        if target == Some("i") {
+            return Err("cannot assign to 'i'".into());
+        }
+        let value = evaluate_with(expr, &self.vars).map_err(|e| e.to_string())?;
+        if let Some(name) = target {
+            self.vars.insert(name.to_string(), value.clone());
+        }
+        self.vars.insert("_".into(), value.clone());
+        Ok(value)
+    }
+}
+
+const HELP: &str = "\
+Enter an expression such as (3+4i)^5 * conj(2-7i) + 10!
+  operators  + - * / % ^ !   (/ and % use Gaussian division when i is involved)
+  functions  conj norm re im abs gcd
+  variables  x = expr, and _ for the previous result
+  commands   :vars  :help  :quit";
+
+/// Runs the read-eval-print loop until end of input or `:quit`.
+pub fn run() -> io::Result<()> {
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    let mut session = Session::default();
+    println!("gauss calculator; :help for help");
+    loop {
+        print!("> ");
+        stdout.flush()?;
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        match line.trim() {
+            "" => {}
+            ":quit" | ":q" => return Ok(()),
+            ":help" => println!("{}", HELP),
+            ":vars" => {
+                let mut names: Vec<_> = session.vars.iter().collect();
+                names.sort_by(|a, b| a.0.cmp(b.0));
+                for (name, value) in names {
+                    println!("{} = {}", name, value);
+                }
+            }
+            input => match session.eval_line(input) {
+                Ok(value) => {
+                    let text = value.to_string();
+                    println!("{}", text);
+                    if text.len() > APPROX_THRESHOLD {
+                        println!("  {}", approx(&value));
+                    }
+                }
+                Err(e) => println!("Error: {}", e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_variables() {
+        let mut session = Session::default();
+        session.eval_line("x = 2 + i").unwrap();
+        assert_eq!(session.eval_line("x * conj(x)").unwrap().to_string(), "5");
+        assert_eq!(session.eval_line("_ + 1").unwrap().to_string(), "6");
+        assert!(session.eval_line("i = 3").is_err());
+        assert!(session.eval_line("1 = 3").is_err());
+    }
+}